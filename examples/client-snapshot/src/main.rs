@@ -145,6 +145,24 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                     progress_bar.set_position(downloaded_bytes);
                 }
             }
+            MithrilEvent::SnapshotDownloadLocationSelected {
+                download_id: _,
+                location,
+            } => {
+                let _ = self
+                    .progress_bar
+                    .println(format!("Downloading snapshot from '{location}'"));
+            }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id: _,
+                downloaded_bytes,
+                size: _,
+            } => {
+                let download_pb = self.download_pb.read().await;
+                if let Some(progress_bar) = download_pb.as_ref() {
+                    progress_bar.set_position(downloaded_bytes);
+                }
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id: _ } => {
                 let mut download_pb = self.download_pb.write().await;
                 if let Some(progress_bar) = download_pb.as_ref() {
@@ -3,11 +3,12 @@ use async_trait::async_trait;
 use semver::Version;
 use slog_scope::{debug, warn};
 use std::sync::Arc;
+use std::time::Instant;
 use thiserror::Error;
 
 use crate::{
-    snapshot_uploaders::SnapshotLocation, snapshotter::OngoingSnapshot, SnapshotUploader,
-    Snapshotter,
+    snapshot_uploaders::SnapshotLocation, snapshotter::OngoingSnapshot, MetricsService,
+    SnapshotUploader, Snapshotter,
 };
 
 use super::ArtifactBuilder;
@@ -33,6 +34,7 @@ pub struct CardanoImmutableFilesFullArtifactBuilder {
     snapshotter: Arc<dyn Snapshotter>,
     snapshot_uploader: Arc<dyn SnapshotUploader>,
     compression_algorithm: CompressionAlgorithm,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl CardanoImmutableFilesFullArtifactBuilder {
@@ -42,12 +44,14 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         snapshotter: Arc<dyn Snapshotter>,
         snapshot_uploader: Arc<dyn SnapshotUploader>,
         compression_algorithm: CompressionAlgorithm,
+        metrics_service: Arc<MetricsService>,
     ) -> Self {
         Self {
             cardano_node_version: cardano_node_version.clone(),
             snapshotter,
             snapshot_uploader,
             compression_algorithm,
+            metrics_service,
         }
     }
 
@@ -75,6 +79,8 @@ impl CardanoImmutableFilesFullArtifactBuilder {
             .await??;
 
         debug!(" > snapshot created: '{:?}'", ongoing_snapshot);
+        self.metrics_service
+            .snapshot_archive_size_bytes_gauge_set(*ongoing_snapshot.get_file_size());
 
         Ok(ongoing_snapshot)
     }
@@ -84,10 +90,15 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         ongoing_snapshot: &OngoingSnapshot,
     ) -> StdResult<Vec<SnapshotLocation>> {
         debug!("CardanoImmutableFilesFullArtifactBuilder: upload snapshot archive");
+        let upload_start = Instant::now();
         let location = self
             .snapshot_uploader
             .upload_snapshot(ongoing_snapshot.get_file_path())
             .await;
+        if location.is_ok() {
+            self.metrics_service
+                .snapshot_upload_duration_seconds_histogram_observe(upload_start.elapsed());
+        }
 
         if let Err(error) = tokio::fs::remove_file(ongoing_snapshot.get_file_path()).await {
             warn!(
@@ -187,6 +198,7 @@ mod tests {
                 dumb_snapshotter.clone(),
                 dumb_snapshot_uploader.clone(),
                 CompressionAlgorithm::Zstandard,
+                Arc::new(MetricsService::new().unwrap()),
             );
         let artifact = cardano_immutable_files_full_artifact_builder
             .compute_artifact(beacon.clone(), &certificate)
@@ -212,6 +224,36 @@ mod tests {
         assert_eq!(artifact_expected, artifact);
     }
 
+    #[tokio::test]
+    async fn should_record_snapshot_archive_metrics() {
+        let certificate = fake_data::certificate("certificate-123".to_string());
+        let dumb_snapshotter = Arc::new(DumbSnapshotter::new());
+        let metrics_service = Arc::new(MetricsService::new().unwrap());
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                &Version::parse("1.0.0").unwrap(),
+                dumb_snapshotter.clone(),
+                Arc::new(DumbSnapshotUploader::new()),
+                CompressionAlgorithm::Zstandard,
+                metrics_service.clone(),
+            );
+        cardano_immutable_files_full_artifact_builder
+            .compute_artifact(fake_data::beacon(), &certificate)
+            .await
+            .unwrap();
+        let last_ongoing_snapshot = dumb_snapshotter.get_last_snapshot().unwrap().unwrap();
+
+        assert_eq!(
+            *last_ongoing_snapshot.get_file_size(),
+            metrics_service.snapshot_archive_size_bytes_gauge_get()
+        );
+        assert_eq!(
+            1,
+            metrics_service.snapshot_upload_duration_seconds_histogram_count_get()
+        );
+    }
+
     #[tokio::test]
     async fn remove_snapshot_archive_after_upload() {
         let file = NamedTempFile::new().unwrap();
@@ -224,6 +266,7 @@ mod tests {
                 Arc::new(DumbSnapshotter::new()),
                 Arc::new(DumbSnapshotUploader::new()),
                 CompressionAlgorithm::default(),
+                Arc::new(MetricsService::new().unwrap()),
             );
 
         cardano_immutable_files_full_artifact_builder
@@ -248,6 +291,7 @@ mod tests {
                 Arc::new(DumbSnapshotter::new()),
                 Arc::new(DumbSnapshotUploader::new()),
                 CompressionAlgorithm::Gzip,
+                Arc::new(MetricsService::new().unwrap()),
             );
 
         let ongoing_snapshot = cardano_immutable_files_full_artifact_builder
@@ -275,6 +319,7 @@ mod tests {
                     Arc::new(DumbSnapshotter::new()),
                     Arc::new(DumbSnapshotUploader::new()),
                     algorithm,
+                    Arc::new(MetricsService::new().unwrap()),
                 );
 
             let ongoing_snapshot = cardano_immutable_files_full_artifact_builder
@@ -316,6 +361,7 @@ mod tests {
                 Arc::new(DumbSnapshotter::new()),
                 Arc::new(snapshot_uploader),
                 CompressionAlgorithm::default(),
+                Arc::new(MetricsService::new().unwrap()),
             );
 
         cardano_immutable_files_full_artifact_builder
@@ -7,9 +7,7 @@ use std::time::Duration;
 use std::{net::IpAddr, path::PathBuf};
 use tokio::{sync::oneshot, task::JoinSet};
 
-use crate::{dependency_injection::DependenciesBuilder, Configuration};
-
-const SQLITE_MONITORING_FILE: &str = "monitoring.sqlite3";
+use crate::{dependency_injection::DependenciesBuilder, Configuration, MetricsServer};
 
 /// Server runtime mode
 #[derive(Parser, Debug, Clone)]
@@ -42,6 +40,18 @@ pub struct ServeCommand {
     /// Will be ignored on (pre)production networks.
     #[clap(long)]
     allow_unparsable_block: bool,
+
+    /// Enable metrics HTTP server (Prometheus endpoint on /metrics).
+    #[clap(long)]
+    enable_metrics_server: bool,
+
+    /// Metrics HTTP server IP.
+    #[clap(long)]
+    metrics_server_ip: Option<String>,
+
+    /// Metrics HTTP server listening port.
+    #[clap(long)]
+    metrics_server_port: Option<u16>,
 }
 
 impl Source for ServeCommand {
@@ -75,6 +85,25 @@ impl Source for ServeCommand {
             );
         }
 
+        if self.enable_metrics_server {
+            result.insert(
+                "enable_metrics_server".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(true)),
+            );
+        }
+        if let Some(metrics_server_ip) = self.metrics_server_ip.clone() {
+            result.insert(
+                "metrics_server_ip".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(metrics_server_ip)),
+            );
+        }
+        if let Some(metrics_server_port) = self.metrics_server_port {
+            result.insert(
+                "metrics_server_port".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(metrics_server_port)),
+            );
+        }
+
         Ok(result)
     }
 }
@@ -99,17 +128,7 @@ impl ServeCommand {
             .create_event_store()
             .await
             .with_context(|| "Dependencies Builder can not create event store")?;
-        let event_store_config = config.clone();
-        let event_store_thread = tokio::spawn(async move {
-            event_store
-                .run(Some(
-                    event_store_config
-                        .get_sqlite_dir()
-                        .join(SQLITE_MONITORING_FILE),
-                ))
-                .await
-                .unwrap()
-        });
+        let event_store_thread = tokio::spawn(async move { event_store.run().await.unwrap() });
 
         // start the aggregator runtime
         let mut runtime = dependencies_builder
@@ -150,6 +169,23 @@ impl ServeCommand {
             Ok(())
         });
 
+        // start the metrics server
+        let (metrics_server_shutdown_tx, metrics_server_shutdown_rx) = oneshot::channel();
+        if config.enable_metrics_server {
+            let metrics_service = dependencies_builder
+                .get_metrics_service()
+                .await
+                .with_context(|| "Dependencies Builder can not create metrics service")?;
+            let metrics_server_ip = config.metrics_server_ip.clone();
+            let metrics_server_port = config.metrics_server_port;
+            join_set.spawn(async move {
+                MetricsServer::new(&metrics_server_ip, metrics_server_port, metrics_service)
+                    .start(metrics_server_shutdown_rx)
+                    .await
+                    .map_err(|e| e.to_string())
+            });
+        }
+
         // Create a SignersImporter only if the `cexplorer_pools_url` is provided in the config.
         if let Some(cexplorer_pools_url) = config.cexplorer_pools_url {
             match dependencies_builder
@@ -189,6 +225,7 @@ impl ServeCommand {
         // stop servers
         join_set.shutdown().await;
         let _ = shutdown_tx.send(());
+        let _ = metrics_server_shutdown_tx.send(());
 
         if !preload_task.is_finished() {
             preload_task.abort();
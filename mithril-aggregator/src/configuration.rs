@@ -16,6 +16,8 @@ use mithril_common::entities::{
 };
 use mithril_common::{CardanoNetwork, StdResult};
 
+use crate::event_store::EventRetentionPolicy;
+
 /// Different kinds of execution environments
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum ExecutionEnvironment {
@@ -81,15 +83,32 @@ pub struct Configuration {
     pub protocol_parameters: ProtocolParameters,
 
     /// Type of snapshot uploader to use
-    #[example = "`gcp` or `local`"]
+    #[example = "`gcp`, `s3` or `local`"]
     pub snapshot_uploader_type: SnapshotUploaderType,
 
-    /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp
+    /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp or S3
     pub snapshot_bucket_name: Option<String>,
 
-    /// Use CDN domain to construct snapshot urls if snapshot_uploader_type is Gcp
+    /// Use CDN domain to construct snapshot urls if snapshot_uploader_type is Gcp or S3
     pub snapshot_use_cdn_domain: bool,
 
+    /// Url of the S3 API endpoint if snapshot_uploader_type is S3, default to the AWS S3
+    /// endpoint of the [region][Self::snapshot_s3_region]
+    #[example = "`http://localhost:9000`"]
+    pub snapshot_s3_endpoint: Option<String>,
+
+    /// Region of the bucket if snapshot_uploader_type is S3, default to `us-east-1`
+    #[example = "`eu-west-3`"]
+    pub snapshot_s3_region: Option<String>,
+
+    /// Address objects as `{endpoint}/{bucket}/{key}` instead of `{bucket}.{endpoint}/{key}`
+    /// if snapshot_uploader_type is S3 (needed by most self-hosted S3 compatible stores)
+    pub snapshot_s3_force_path_style: bool,
+
+    /// Size in bytes of the parts sent when uploading a snapshot if snapshot_uploader_type
+    /// is S3, must be at least 5 MiB, default to 64 MiB
+    pub snapshot_s3_multipart_part_size: Option<usize>,
+
     /// Server listening IP
     pub server_ip: String,
 
@@ -174,6 +193,34 @@ pub struct Configuration {
 
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: usize,
+
+    /// Enable metrics server (Prometheus endpoint on /metrics).
+    pub enable_metrics_server: bool,
+
+    /// Metrics HTTP Server IP.
+    pub metrics_server_ip: String,
+
+    /// Metrics HTTP Server listening port.
+    pub metrics_server_port: u16,
+
+    /// Token expected in the `Authorization: Bearer <token>` header of the requests to the admin
+    /// routes, the admin routes are disabled if not set.
+    pub admin_api_token: Option<String>,
+
+    /// Maximum age, in days, of the events kept in the event store, older events are pruned by
+    /// the upkeep service. If not set, the events are not pruned by age.
+    pub event_store_retention_max_age_days: Option<u64>,
+
+    /// Maximum number of events kept in the event store, the oldest events are pruned by the
+    /// upkeep service. If not set, the events are not pruned by number.
+    pub event_store_retention_max_rows: Option<u64>,
+
+    /// Path of a file to which the events are additionally exported, one JSON document per line.
+    pub event_store_export_file_path: Option<PathBuf>,
+
+    /// Url of a webhook to which the events are additionally forwarded as JSON with a `POST`
+    /// request.
+    pub event_store_export_webhook_url: Option<String>,
 }
 
 /// Uploader needed to copy the snapshot once computed.
@@ -182,6 +229,8 @@ pub struct Configuration {
 pub enum SnapshotUploaderType {
     /// Uploader to GCP storage.
     Gcp,
+    /// Uploader to a S3 compatible storage.
+    S3,
     /// Uploader to local storage.
     Local,
 }
@@ -228,6 +277,10 @@ impl Configuration {
             snapshot_uploader_type: SnapshotUploaderType::Local,
             snapshot_bucket_name: None,
             snapshot_use_cdn_domain: false,
+            snapshot_s3_endpoint: None,
+            snapshot_s3_region: None,
+            snapshot_s3_force_path_style: false,
+            snapshot_s3_multipart_part_size: None,
             server_ip: "0.0.0.0".to_string(),
             server_port: 8000,
             run_interval: 5000,
@@ -254,6 +307,14 @@ impl Configuration {
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
+            enable_metrics_server: true,
+            metrics_server_ip: "0.0.0.0".to_string(),
+            metrics_server_port: 9095,
+            admin_api_token: None,
+            event_store_retention_max_age_days: None,
+            event_store_retention_max_rows: None,
+            event_store_export_file_path: None,
+            event_store_export_webhook_url: None,
         }
     }
 
@@ -279,6 +340,30 @@ impl Configuration {
         self.data_stores_directory.clone()
     }
 
+    /// Retention policy of the event store built from the
+    /// [max age][Configuration::event_store_retention_max_age_days] and
+    /// [max rows][Configuration::event_store_retention_max_rows] settings.
+    pub fn event_retention_policy(&self) -> StdResult<EventRetentionPolicy> {
+        let max_age = self
+            .event_store_retention_max_age_days
+            .map(|days| {
+                i64::try_from(days)
+                    .ok()
+                    .and_then(chrono::Duration::try_days)
+                    .ok_or_else(|| {
+                        anyhow!(ConfigError::Message(format!(
+                            "Invalid 'event_store_retention_max_age_days' configuration: {days} days is out of range"
+                        )))
+                    })
+            })
+            .transpose()?;
+
+        Ok(EventRetentionPolicy {
+            max_age,
+            max_rows: self.event_store_retention_max_rows,
+        })
+    }
+
     /// Same as the [store retention limit][Configuration::store_retention_limit] but will never
     /// yield a value lower than 3.
     ///
@@ -348,9 +433,12 @@ pub struct DefaultConfiguration {
     /// Snapshot compression algorithm default setting
     pub snapshot_compression_algorithm: String,
 
-    /// Use CDN domain to construct snapshot urls default setting (if snapshot_uploader_type is Gcp)
+    /// Use CDN domain to construct snapshot urls default setting (if snapshot_uploader_type is Gcp or S3)
     pub snapshot_use_cdn_domain: String,
 
+    /// Force path style addressing of the S3 objects default setting (if snapshot_uploader_type is S3)
+    pub snapshot_s3_force_path_style: String,
+
     /// Signer importer run interval default setting
     pub signer_importer_run_interval: u64,
 
@@ -373,6 +461,15 @@ pub struct DefaultConfiguration {
 
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
+
+    /// Enable metrics server (Prometheus endpoint on /metrics).
+    pub enable_metrics_server: String,
+
+    /// Metrics HTTP server IP.
+    pub metrics_server_ip: String,
+
+    /// Metrics HTTP server listening port.
+    pub metrics_server_port: u16,
 }
 
 impl Default for DefaultConfiguration {
@@ -391,6 +488,7 @@ impl Default for DefaultConfiguration {
             disable_digests_cache: "false".to_string(),
             snapshot_compression_algorithm: "zstandard".to_string(),
             snapshot_use_cdn_domain: "false".to_string(),
+            snapshot_s3_force_path_style: "false".to_string(),
             signer_importer_run_interval: 720,
             allow_unparsable_block: "false".to_string(),
             cardano_transactions_prover_cache_pool_size: 10,
@@ -401,6 +499,9 @@ impl Default for DefaultConfiguration {
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
+            enable_metrics_server: "false".to_string(),
+            metrics_server_ip: "0.0.0.0".to_string(),
+            metrics_server_port: 9095,
         }
     }
 }
@@ -453,6 +554,7 @@ impl Source for DefaultConfiguration {
         insert_default_configuration!(result, myself.disable_digests_cache);
         insert_default_configuration!(result, myself.snapshot_compression_algorithm);
         insert_default_configuration!(result, myself.snapshot_use_cdn_domain);
+        insert_default_configuration!(result, myself.snapshot_s3_force_path_style);
         insert_default_configuration!(result, myself.signer_importer_run_interval);
         insert_default_configuration!(result, myself.allow_unparsable_block);
         insert_default_configuration!(result, myself.cardano_transactions_prover_cache_pool_size);
@@ -468,6 +570,9 @@ impl Source for DefaultConfiguration {
             result,
             myself.cardano_transactions_block_streamer_max_roll_forwards_per_poll
         );
+        insert_default_configuration!(result, myself.enable_metrics_server);
+        insert_default_configuration!(result, myself.metrics_server_ip);
+        insert_default_configuration!(result, myself.metrics_server_port);
         result.insert(
             "cardano_transactions_signing_config".to_string(),
             into_value(HashMap::from([
@@ -505,6 +610,46 @@ mod test {
         }
     }
 
+    #[test]
+    fn event_retention_policy_from_configuration() {
+        let configuration = Configuration {
+            event_store_retention_max_age_days: Some(30),
+            event_store_retention_max_rows: Some(1000),
+            ..Configuration::new_sample()
+        };
+
+        assert_eq!(
+            EventRetentionPolicy {
+                max_age: Some(chrono::Duration::days(30)),
+                max_rows: Some(1000),
+            },
+            configuration.event_retention_policy().unwrap()
+        );
+        assert!(Configuration::new_sample()
+            .event_retention_policy()
+            .unwrap()
+            .is_disabled());
+    }
+
+    #[test]
+    fn event_retention_policy_fails_if_max_age_is_out_of_range() {
+        let configuration = Configuration {
+            event_store_retention_max_age_days: Some(u64::MAX),
+            ..Configuration::new_sample()
+        };
+        configuration
+            .event_retention_policy()
+            .expect_err("Should fail with a max age overflowing a duration");
+
+        let configuration = Configuration {
+            event_store_retention_max_age_days: Some(i64::MAX as u64),
+            ..Configuration::new_sample()
+        };
+        configuration
+            .event_retention_policy()
+            .expect_err("Should fail with a max age overflowing a duration");
+    }
+
     #[test]
     fn safe_epoch_retention_limit_wont_change_a_none_value() {
         let configuration = Configuration {
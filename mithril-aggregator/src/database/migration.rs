@@ -758,6 +758,39 @@ pragma foreign_keys=true;
             26,
             r#"
 create unique index signed_entity_unique_index on signed_entity(signed_entity_type_id, beacon);
+"#,
+        ),
+        // Migration 27
+        // Add `signer_participation` table to keep the participation of the signers after their
+        // open messages and single signatures are cleaned
+        SqlMigration::new(
+            27,
+            r#"
+create table signer_participation (
+    signer_id               text        not null,
+    epoch_setting_id        integer     not null,
+    signatures_sent         integer     not null default 0,
+    signatures_included     integer     not null default 0,
+    lottery_wins            integer     not null default 0,
+    primary key (signer_id, epoch_setting_id)
+);
+
+insert into signer_participation (signer_id, epoch_setting_id, signatures_sent, signatures_included, lottery_wins)
+select
+    single_signature.signer_id,
+    open_message.epoch_setting_id,
+    count(*),
+    sum(exists(
+        select 1 from certificate, json_each(certificate.signers) as certificate_signer
+        where certificate.epoch = open_message.epoch_setting_id
+            and certificate.signed_entity_type_id = open_message.signed_entity_type_id
+            and certificate.signed_entity_beacon = open_message.beacon
+            and json_extract(certificate_signer.value, '$.party_id') = single_signature.signer_id
+    )),
+    sum(json_array_length(single_signature.lottery_indexes))
+from single_signature
+    inner join open_message on open_message.open_message_id = single_signature.open_message_id
+group by single_signature.signer_id, open_message.epoch_setting_id;
 "#,
         ),
     ]
@@ -2,21 +2,24 @@ use sqlite::Value;
 
 #[cfg(test)]
 use mithril_common::entities::Epoch;
-#[cfg(test)]
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
 use crate::database::record::CertificateRecord;
+use crate::entities::ListFilters;
 
 /// Simple queries to retrieve [CertificateRecord] from the sqlite database.
 pub struct GetCertificateRecordQuery {
     condition: WhereCondition,
+    /// Maximum number of records to return and number of records to skip
+    pagination: Option<(usize, usize)>,
 }
 
 impl GetCertificateRecordQuery {
     pub fn all() -> Self {
         Self {
             condition: WhereCondition::default(),
+            pagination: None,
         }
     }
 
@@ -26,13 +29,42 @@ impl GetCertificateRecordQuery {
                 "certificate_id = ?*",
                 vec![Value::String(certificate_id.to_owned())],
             ),
+            pagination: None,
+        }
+    }
+
+    pub fn with_filters(filters: &ListFilters) -> StdResult<Self> {
+        let mut condition = WhereCondition::default();
+        if let Some(epoch_from) = filters.epoch_from {
+            condition = condition.and_where(WhereCondition::new(
+                "epoch >= ?*",
+                vec![Value::Integer(epoch_from.try_into()?)],
+            ));
         }
+        if let Some(epoch_to) = filters.epoch_to {
+            condition = condition.and_where(WhereCondition::new(
+                "epoch <= ?*",
+                vec![Value::Integer(epoch_to.try_into()?)],
+            ));
+        }
+        if let Some(signed_entity_type) = filters.signed_entity_type {
+            condition = condition.and_where(WhereCondition::new(
+                "signed_entity_type_id = ?*",
+                vec![Value::Integer(signed_entity_type.index() as i64)],
+            ));
+        }
+
+        Ok(Self {
+            condition,
+            pagination: Some((filters.limit(), filters.offset())),
+        })
     }
 
     #[cfg(test)]
     pub fn by_epoch(epoch: Epoch) -> StdResult<Self> {
         Ok(Self {
             condition: WhereCondition::new("epoch = ?*", vec![Value::Integer(epoch.try_into()?)]),
+            pagination: None,
         })
     }
 }
@@ -47,13 +79,20 @@ impl Query for GetCertificateRecordQuery {
     fn get_definition(&self, condition: &str) -> String {
         let aliases = SourceAlias::new(&[("{:certificate:}", "c")]);
         let projection = Self::Entity::get_projection().expand(aliases);
-        format!("select {projection} from certificate as c where {condition} order by ROWID desc")
+        let pagination = self
+            .pagination
+            .map(|(limit, offset)| format!(" limit {limit} offset {offset}"))
+            .unwrap_or_default();
+        format!(
+            "select {projection} from certificate as c where {condition} order by ROWID desc{pagination}"
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
+    use mithril_common::entities::SignedEntityTypeDiscriminants;
     use mithril_persistence::sqlite::ConnectionExtensions;
 
     use crate::database::test_helper::{insert_certificate_records, main_db_connection};
@@ -93,6 +132,47 @@ mod tests {
         assert_eq!(0, cursor.count());
     }
 
+    #[test]
+    fn test_get_certificate_records_with_filters() {
+        let (certificates, _) = setup_certificate_chain(20, 7);
+
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(&connection, certificates.clone());
+
+        let filters = ListFilters {
+            epoch_from: Some(Epoch(2)),
+            epoch_to: Some(Epoch(3)),
+            ..ListFilters::default()
+        };
+        let certificate_records: Vec<CertificateRecord> = connection
+            .fetch_collect(GetCertificateRecordQuery::with_filters(&filters).unwrap())
+            .unwrap();
+        let expected_certificate_records: Vec<CertificateRecord> = certificates
+            .iter()
+            .filter_map(|c| {
+                (Epoch(2) <= c.epoch && c.epoch <= Epoch(3)).then_some(c.to_owned().into())
+            })
+            .rev()
+            .collect();
+        assert_eq!(expected_certificate_records, certificate_records);
+
+        let filters = ListFilters {
+            signed_entity_type: Some(SignedEntityTypeDiscriminants::CardanoTransactions),
+            ..ListFilters::default()
+        };
+        let cursor = connection
+            .fetch(GetCertificateRecordQuery::with_filters(&filters).unwrap())
+            .unwrap();
+        assert_eq!(0, cursor.count());
+
+        let certificate_records: Vec<CertificateRecord> = connection
+            .fetch_collect(
+                GetCertificateRecordQuery::with_filters(&ListFilters::default()).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(certificates.len(), certificate_records.len());
+    }
+
     #[test]
     fn test_get_all_certificate_records() {
         let (certificates, _) = setup_certificate_chain(5, 2);
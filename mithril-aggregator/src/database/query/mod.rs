@@ -4,6 +4,7 @@ mod epoch_setting;
 mod open_message;
 mod signed_entity;
 mod signer;
+mod signer_participation;
 mod signer_registration;
 mod single_signature;
 mod stake_pool;
@@ -13,6 +14,7 @@ pub use epoch_setting::*;
 pub use open_message::*;
 pub use signed_entity::*;
 pub use signer::*;
+pub use signer_participation::*;
 pub use signer_registration::*;
 pub use single_signature::*;
 pub use stake_pool::*;
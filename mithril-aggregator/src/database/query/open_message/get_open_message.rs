@@ -35,6 +35,15 @@ impl GetOpenMessageQuery {
         Ok(Self { condition })
     }
 
+    pub fn signing_opportunities(now: DateTime<Utc>) -> Self {
+        let condition = WhereCondition::new(
+            "is_certified = false and is_expired = false and (expires_at is null or expires_at >= ?*)",
+            vec![Value::String(now.to_rfc3339())],
+        );
+
+        Self { condition }
+    }
+
     fn get_epoch_condition(epoch: Epoch) -> WhereCondition {
         WhereCondition::new("epoch_setting_id = ?*", vec![Value::Integer(*epoch as i64)])
     }
@@ -5,10 +5,13 @@ use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
 use crate::database::record::SignedEntityRecord;
+use crate::entities::ListFilters;
 
 /// Simple queries to retrieve [SignedEntityRecord] from the sqlite database.
 pub struct GetSignedEntityRecordQuery {
     condition: WhereCondition,
+    /// Maximum number of records to return and number of records to skip
+    pagination: Option<(usize, usize)>,
 }
 
 impl GetSignedEntityRecordQuery {
@@ -16,6 +19,7 @@ impl GetSignedEntityRecordQuery {
     pub fn all() -> Self {
         Self {
             condition: WhereCondition::default(),
+            pagination: None,
         }
     }
 
@@ -25,6 +29,7 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_id = ?*",
                 vec![Value::String(signed_entity_id.to_owned())],
             ),
+            pagination: None,
         }
     }
 
@@ -34,6 +39,7 @@ impl GetSignedEntityRecordQuery {
                 "certificate_id = ?*",
                 vec![Value::String(certificate_id.to_owned())],
             ),
+            pagination: None,
         }
     }
 
@@ -45,6 +51,7 @@ impl GetSignedEntityRecordQuery {
 
         Self {
             condition: WhereCondition::where_in("certificate_id", ids_values),
+            pagination: None,
         }
     }
 
@@ -58,6 +65,34 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_type_id = ?*",
                 vec![Value::Integer(signed_entity_type_id)],
             ),
+            pagination: None,
+        })
+    }
+
+    pub fn by_signed_entity_type_with_filters(
+        signed_entity_type: &SignedEntityTypeDiscriminants,
+        filters: &ListFilters,
+    ) -> StdResult<Self> {
+        // The epoch is the beacon itself for the epoch based signed entity types, and the `epoch`
+        // field of the beacon for the others.
+        let beacon_epoch = "coalesce(json_extract(beacon, '$.epoch'), beacon)";
+        let mut condition = Self::by_signed_entity_type(signed_entity_type)?.condition;
+        if let Some(epoch_from) = filters.epoch_from {
+            condition = condition.and_where(WhereCondition::new(
+                &format!("{beacon_epoch} >= ?*"),
+                vec![Value::Integer(epoch_from.try_into()?)],
+            ));
+        }
+        if let Some(epoch_to) = filters.epoch_to {
+            condition = condition.and_where(WhereCondition::new(
+                &format!("{beacon_epoch} <= ?*"),
+                vec![Value::Integer(epoch_to.try_into()?)],
+            ));
+        }
+
+        Ok(Self {
+            condition,
+            pagination: Some((filters.limit(), filters.offset())),
         })
     }
 
@@ -71,6 +106,7 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_type_id = ?* and beacon = ?*",
                 vec![Value::Integer(signed_entity_type_id), Value::Integer(epoch)],
             ),
+            pagination: None,
         }
     }
 }
@@ -85,8 +121,12 @@ impl Query for GetSignedEntityRecordQuery {
     fn get_definition(&self, condition: &str) -> String {
         let aliases = SourceAlias::new(&[("{:signed_entity:}", "se")]);
         let projection = Self::Entity::get_projection().expand(aliases);
+        let pagination = self
+            .pagination
+            .map(|(limit, offset)| format!(" limit {limit} offset {offset}"))
+            .unwrap_or_default();
         format!(
-            "select {projection} from signed_entity as se where {condition} order by ROWID desc"
+            "select {projection} from signed_entity as se where {condition} order by ROWID desc{pagination}"
         )
     }
 }
@@ -183,6 +223,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn by_signed_entity_type_with_filters_on_epoch_beacon() {
+        let records: Vec<SignedEntityRecord> = fake_data::mithril_stake_distributions(5)
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut mithril_stake_distribution)| {
+                mithril_stake_distribution.epoch = Epoch(index as u64 + 1);
+                mithril_stake_distribution.into()
+            })
+            .collect();
+        let connection = create_database(&records);
+        let filters = ListFilters {
+            epoch_from: Some(Epoch(2)),
+            epoch_to: Some(Epoch(4)),
+            ..ListFilters::default()
+        };
+
+        let records_retrieved: Vec<SignedEntityRecord> = connection
+            .fetch_collect(
+                GetSignedEntityRecordQuery::by_signed_entity_type_with_filters(
+                    &SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                    &filters,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![records[3].clone(), records[2].clone(), records[1].clone()],
+            records_retrieved
+        );
+    }
+
+    #[test]
+    fn by_signed_entity_type_with_filters_on_json_beacon() {
+        let records: Vec<SignedEntityRecord> = fake_data::snapshots(5)
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut snapshot)| {
+                snapshot.beacon.epoch = Epoch(index as u64 + 1);
+                SignedEntityRecord::from_snapshot(
+                    snapshot,
+                    "whatever".to_string(),
+                    DateTime::default(),
+                )
+            })
+            .collect();
+        let connection = create_database(&records);
+        let filters = ListFilters {
+            epoch_from: Some(Epoch(4)),
+            ..ListFilters::default()
+        };
+
+        let records_retrieved: Vec<SignedEntityRecord> = connection
+            .fetch_collect(
+                GetSignedEntityRecordQuery::by_signed_entity_type_with_filters(
+                    &SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                    &filters,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![records[4].clone(), records[3].clone()],
+            records_retrieved
+        );
+    }
+
     #[test]
     fn test_get_signed_entity_records() {
         let signed_entity_records = SignedEntityRecord::fake_records(5);
@@ -0,0 +1,153 @@
+use sqlite::Value;
+
+use mithril_common::entities::PartyId;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SignerParticipationRecord;
+
+/// Query to read the [SignerParticipationRecord] of a signer from the sqlite database
+pub struct GetSignerParticipationQuery {
+    condition: WhereCondition,
+}
+
+impl GetSignerParticipationQuery {
+    pub fn by_party_id(party_id: &PartyId) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "signer_id = ?*",
+                vec![Value::String(party_id.to_owned())],
+            ),
+        }
+    }
+}
+
+impl Query for GetSignerParticipationQuery {
+    type Entity = SignerParticipationRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:signer_participation:}", "signer_participation")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from signer_participation where {condition} order by epoch_setting_id desc"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::{
+        DeleteOpenMessageQuery, IncrementSignerParticipationQuery, InsertOpenMessageQuery,
+    };
+    use crate::database::record::{OpenMessageRecord, SingleSignatureRecord};
+    use crate::database::test_helper::{
+        insert_single_signatures_in_db, main_db_connection, setup_single_signature_records,
+    };
+
+    use super::*;
+
+    #[test]
+    fn get_signer_participation_per_epoch_after_their_open_messages_are_cleaned() {
+        let connection = main_db_connection().unwrap();
+        let party_id = "signer-1".to_string();
+        for (epoch, signed_entity_type) in [
+            (
+                Epoch(1),
+                SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+            ),
+            (
+                Epoch(2),
+                SignedEntityType::MithrilStakeDistribution(Epoch(2)),
+            ),
+            (
+                Epoch(2),
+                SignedEntityType::CardanoStakeDistribution(Epoch(2)),
+            ),
+        ] {
+            let open_message = connection
+                .fetch_first(
+                    InsertOpenMessageQuery::one(
+                        epoch,
+                        &signed_entity_type,
+                        &ProtocolMessage::new(),
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+                .unwrap();
+            let single_signature = SingleSignatureRecord {
+                open_message_id: open_message.open_message_id,
+                signer_id: party_id.clone(),
+                lottery_indexes: vec![1, 2, 3],
+                ..setup_single_signature_records(1, 1, 1).pop().unwrap()
+            };
+            connection
+                .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                    epoch,
+                    &single_signature,
+                ))
+                .unwrap();
+            insert_single_signatures_in_db(&connection, vec![single_signature]).unwrap();
+        }
+        let _: Vec<SignerParticipationRecord> = connection
+            .fetch_collect(
+                IncrementSignerParticipationQuery::single_signatures_included(
+                    Epoch(2),
+                    &[party_id.clone()],
+                ),
+            )
+            .unwrap();
+        let _: Vec<OpenMessageRecord> = connection
+            .fetch_collect(DeleteOpenMessageQuery::below_epoch_threshold(Epoch(3)))
+            .unwrap();
+
+        let participation: Vec<SignerParticipationRecord> = connection
+            .fetch_collect(GetSignerParticipationQuery::by_party_id(&party_id))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                SignerParticipationRecord {
+                    epoch: Epoch(2),
+                    signatures_sent: 2,
+                    signatures_included: 1,
+                    lottery_wins: 6,
+                },
+                SignerParticipationRecord {
+                    epoch: Epoch(1),
+                    signatures_sent: 1,
+                    signatures_included: 0,
+                    lottery_wins: 3,
+                },
+            ],
+            participation
+        );
+    }
+
+    #[test]
+    fn get_signer_participation_of_unknown_signer_is_empty() {
+        let connection = main_db_connection().unwrap();
+        let single_signature = setup_single_signature_records(1, 1, 1).pop().unwrap();
+        connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                Epoch(1),
+                &single_signature,
+            ))
+            .unwrap();
+
+        let participation: Vec<SignerParticipationRecord> = connection
+            .fetch_collect(GetSignerParticipationQuery::by_party_id(
+                &"unknown".to_string(),
+            ))
+            .unwrap();
+
+        assert!(participation.is_empty());
+    }
+}
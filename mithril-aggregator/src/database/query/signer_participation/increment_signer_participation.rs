@@ -0,0 +1,195 @@
+use sqlite::Value;
+
+use mithril_common::entities::{Epoch, PartyId};
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::{SignerParticipationRecord, SingleSignatureRecord};
+
+/// Query to increment the counters of [SignerParticipationRecord] in the sqlite database
+pub struct IncrementSignerParticipationQuery {
+    condition: WhereCondition,
+}
+
+impl IncrementSignerParticipationQuery {
+    /// Count a single signature sent by a signer for an open message of the given epoch.
+    ///
+    /// This query must be run before the single signature is stored: if the signer already
+    /// sent a single signature for the same open message, the stored one is replaced by the
+    /// new one and only the difference of lottery wins is counted.
+    pub fn single_signature_sent(
+        epoch: Epoch,
+        single_signature_record: &SingleSignatureRecord,
+    ) -> Self {
+        let condition = WhereCondition::new(
+            "select ?*, ?*, 1 - count(*), 0, ?* - coalesce(sum(json_array_length(lottery_indexes)), 0) \
+from single_signature where open_message_id = ?* and signer_id = ?*",
+            vec![
+                Value::String(single_signature_record.signer_id.clone()),
+                Value::Integer(epoch.try_into().unwrap()),
+                Value::Integer(single_signature_record.lottery_indexes.len() as i64),
+                Value::String(single_signature_record.open_message_id.to_string()),
+                Value::String(single_signature_record.signer_id.clone()),
+            ],
+        );
+
+        Self { condition }
+    }
+
+    /// Count the single signatures of the given signers as included in a certificate of the
+    /// given epoch.
+    pub fn single_signatures_included(epoch: Epoch, party_ids: &[PartyId]) -> Self {
+        let values_columns = vec!["(?*, ?*, 0, 1, 0)"; party_ids.len()].join(", ");
+        let values = party_ids
+            .iter()
+            .flat_map(|party_id| {
+                vec![
+                    Value::String(party_id.to_owned()),
+                    Value::Integer(epoch.try_into().unwrap()),
+                ]
+            })
+            .collect();
+        let condition = WhereCondition::new(&format!("values {values_columns}"), values);
+
+        Self { condition }
+    }
+}
+
+impl Query for IncrementSignerParticipationQuery {
+    type Entity = SignerParticipationRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:signer_participation:}",
+            "signer_participation",
+        )]));
+
+        format!(
+            r#"
+insert into signer_participation (signer_id, epoch_setting_id, signatures_sent, signatures_included, lottery_wins)
+{condition}
+on conflict (signer_id, epoch_setting_id) do update set
+    signatures_sent = signatures_sent + excluded.signatures_sent,
+    signatures_included = signatures_included + excluded.signatures_included,
+    lottery_wins = lottery_wins + excluded.lottery_wins
+returning {projection}
+"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::{
+        insert_single_signatures_in_db, main_db_connection, setup_single_signature_records,
+    };
+
+    use super::*;
+
+    #[test]
+    fn increment_signer_participation_when_single_signatures_are_sent_and_included() {
+        let connection = main_db_connection().unwrap();
+        let single_signature = SingleSignatureRecord {
+            lottery_indexes: vec![1, 2, 3],
+            ..setup_single_signature_records(1, 1, 1).pop().unwrap()
+        };
+        let other_single_signature = SingleSignatureRecord {
+            open_message_id: uuid::Uuid::new_v4(),
+            lottery_indexes: vec![4],
+            ..single_signature.clone()
+        };
+
+        let participation = connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                Epoch(3),
+                &single_signature,
+            ))
+            .unwrap();
+        assert_eq!(
+            Some(SignerParticipationRecord {
+                epoch: Epoch(3),
+                signatures_sent: 1,
+                signatures_included: 0,
+                lottery_wins: 3,
+            }),
+            participation
+        );
+
+        let participation = connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                Epoch(3),
+                &other_single_signature,
+            ))
+            .unwrap();
+        assert_eq!(
+            Some(SignerParticipationRecord {
+                epoch: Epoch(3),
+                signatures_sent: 2,
+                signatures_included: 0,
+                lottery_wins: 4,
+            }),
+            participation
+        );
+
+        let participation: Vec<SignerParticipationRecord> = connection
+            .fetch_collect(
+                IncrementSignerParticipationQuery::single_signatures_included(
+                    Epoch(3),
+                    &[single_signature.signer_id.clone()],
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            vec![SignerParticipationRecord {
+                epoch: Epoch(3),
+                signatures_sent: 2,
+                signatures_included: 1,
+                lottery_wins: 4,
+            }],
+            participation
+        );
+    }
+
+    #[test]
+    fn single_signature_sent_again_for_the_same_open_message_is_counted_once() {
+        let connection = main_db_connection().unwrap();
+        let single_signature = SingleSignatureRecord {
+            lottery_indexes: vec![1, 2, 3],
+            ..setup_single_signature_records(1, 1, 1).pop().unwrap()
+        };
+        connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                Epoch(3),
+                &single_signature,
+            ))
+            .unwrap();
+        insert_single_signatures_in_db(&connection, vec![single_signature.clone()]).unwrap();
+
+        let participation = connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                Epoch(3),
+                &SingleSignatureRecord {
+                    lottery_indexes: vec![1, 2],
+                    ..single_signature.clone()
+                },
+            ))
+            .unwrap();
+
+        assert_eq!(
+            Some(SignerParticipationRecord {
+                epoch: Epoch(3),
+                signatures_sent: 1,
+                signatures_included: 0,
+                lottery_wins: 2,
+            }),
+            participation
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod get_signer_participation;
+mod increment_signer_participation;
+
+pub use get_signer_participation::*;
+pub use increment_signer_participation::*;
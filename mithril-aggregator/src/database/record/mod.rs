@@ -6,6 +6,7 @@ mod open_message;
 mod open_message_with_single_signatures;
 mod signed_entity;
 mod signer;
+mod signer_participation;
 mod signer_registration;
 mod single_signature;
 mod stake_pool;
@@ -16,6 +17,7 @@ pub use open_message::*;
 pub use open_message_with_single_signatures::*;
 pub use signed_entity::*;
 pub use signer::*;
+pub use signer_participation::*;
 pub use signer_registration::*;
 pub use single_signature::*;
 pub use stake_pool::*;
@@ -3,6 +3,7 @@ use sqlite::Row;
 use uuid::Uuid;
 
 use mithril_common::entities::{Epoch, ProtocolMessage, SignedEntityType};
+use mithril_common::messages::SigningOpportunityListItemMessage;
 use mithril_persistence::database::Hydrator;
 use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
 
@@ -59,6 +60,17 @@ impl OpenMessageRecord {
     }
 }
 
+impl From<OpenMessageRecord> for SigningOpportunityListItemMessage {
+    fn from(value: OpenMessageRecord) -> Self {
+        Self {
+            epoch: value.epoch,
+            signed_entity_type: value.signed_entity_type,
+            protocol_message: value.protocol_message,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
 impl SqLiteEntity for OpenMessageRecord {
     fn hydrate(row: Row) -> Result<Self, HydrationError>
     where
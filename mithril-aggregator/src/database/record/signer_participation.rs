@@ -0,0 +1,74 @@
+use mithril_common::entities::Epoch;
+use mithril_common::messages::SignerParticipationEpochMessage;
+use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Participation of a signer during one epoch.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SignerParticipationRecord {
+    /// Epoch of the open messages signed by the signer
+    pub epoch: Epoch,
+
+    /// Number of single signatures sent by the signer
+    pub signatures_sent: u64,
+
+    /// Number of single signatures of the signer included in a certificate
+    pub signatures_included: u64,
+
+    /// Number of lottery wins of the signer
+    pub lottery_wins: u64,
+}
+
+impl From<SignerParticipationRecord> for SignerParticipationEpochMessage {
+    fn from(value: SignerParticipationRecord) -> Self {
+        Self {
+            epoch: value.epoch,
+            signatures_sent: value.signatures_sent,
+            signatures_included: value.signatures_included,
+            lottery_wins: value.lottery_wins,
+        }
+    }
+}
+
+impl SqLiteEntity for SignerParticipationRecord {
+    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let read_u64 = |index: usize, field: &str| -> Result<u64, HydrationError> {
+            let value = row.read::<i64, _>(index);
+            u64::try_from(value).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not cast i64 ({value}) to u64 for field '{field}'. Error: '{e}'"
+                ))
+            })
+        };
+
+        Ok(Self {
+            epoch: Epoch(read_u64(0, "epoch")?),
+            signatures_sent: read_u64(1, "signatures_sent")?,
+            signatures_included: read_u64(2, "signatures_included")?,
+            lottery_wins: read_u64(3, "lottery_wins")?,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            ("epoch", "{:signer_participation:}.epoch_setting_id", "int"),
+            (
+                "signatures_sent",
+                "{:signer_participation:}.signatures_sent",
+                "int",
+            ),
+            (
+                "signatures_included",
+                "{:signer_participation:}.signatures_included",
+                "int",
+            ),
+            (
+                "lottery_wins",
+                "{:signer_participation:}.lottery_wins",
+                "int",
+            ),
+        ])
+    }
+}
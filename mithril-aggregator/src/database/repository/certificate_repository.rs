@@ -14,6 +14,7 @@ use crate::database::query::{
     MasterCertificateQuery,
 };
 use crate::database::record::CertificateRecord;
+use crate::entities::ListFilters;
 
 /// Database frontend API for Certificate queries.
 pub struct CertificateRepository {
@@ -48,6 +49,18 @@ impl CertificateRepository {
         Ok(cursor.take(last_n).map(|v| v.into()).collect())
     }
 
+    /// Return a page of the latest certificates matching the given filters.
+    pub async fn get_certificates_with_filters<T>(&self, filters: &ListFilters) -> StdResult<Vec<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let cursor = self
+            .connection
+            .fetch(GetCertificateRecordQuery::with_filters(filters)?)?;
+
+        Ok(cursor.map(|v| v.into()).collect())
+    }
+
     /// Return the first certificate signed per epoch as the reference
     /// certificate for this Epoch. This will be the parent certificate for all
     /// other certificates issued within this Epoch.
@@ -276,6 +289,30 @@ mod tests {
         assert_eq!(expected, latest_certificates);
     }
 
+    #[tokio::test]
+    async fn repository_get_certificates_with_filters_paginates() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_certificate_records(&connection, certificates.clone());
+        let repository = CertificateRepository::new(connection);
+        let expected: Vec<Certificate> = certificates.into_iter().rev().collect();
+
+        let first_page: Vec<Certificate> = repository
+            .get_certificates_with_filters(&ListFilters::with_limit(2))
+            .await
+            .unwrap();
+        let second_page: Vec<Certificate> = repository
+            .get_certificates_with_filters(&ListFilters {
+                offset: Some(2),
+                ..ListFilters::with_limit(2)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(expected[..2].to_vec(), first_page);
+        assert_eq!(expected[2..4].to_vec(), second_page);
+    }
+
     #[tokio::test]
     async fn get_master_certificate_no_certificate_recorded_returns_none() {
         let mut deps = DependenciesBuilder::new(Configuration::new_sample());
@@ -63,6 +63,13 @@ impl OpenMessageRepository {
             )?)
     }
 
+    /// Return the [OpenMessageRecord] that are neither certified nor expired, which are the
+    /// messages that the signers can still sign.
+    pub async fn get_signing_opportunities(&self) -> StdResult<Vec<OpenMessageRecord>> {
+        self.connection
+            .fetch_collect(GetOpenMessageQuery::signing_opportunities(Utc::now()))
+    }
+
     /// Create a new [OpenMessageRecord] in the database.
     pub async fn create_open_message(
         &self,
@@ -226,6 +233,56 @@ mod tests {
         assert!(open_message_result.is_some());
     }
 
+    #[tokio::test]
+    async fn repository_get_signing_opportunities() {
+        let connection = get_connection().await;
+        let repository = OpenMessageRepository::new(connection.clone());
+        let epoch = Epoch(1);
+        let mut open_messages = vec![];
+        for signed_entity_type in [
+            SignedEntityType::MithrilStakeDistribution(epoch),
+            SignedEntityType::CardanoStakeDistribution(epoch),
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::default()),
+            SignedEntityType::CardanoTransactions(epoch, BlockNumber(100)),
+        ] {
+            open_messages.push(
+                repository
+                    .create_open_message(epoch, &signed_entity_type, &ProtocolMessage::new())
+                    .await
+                    .unwrap(),
+            );
+        }
+        let (certified, expired, expiring_later) = (
+            OpenMessageRecord {
+                is_certified: true,
+                ..open_messages[0].clone()
+            },
+            OpenMessageRecord {
+                expires_at: Some(Utc::now() - chrono::Days::new(1)),
+                ..open_messages[1].clone()
+            },
+            OpenMessageRecord {
+                expires_at: Some(Utc::now() + chrono::Days::new(1)),
+                ..open_messages[2].clone()
+            },
+        );
+        for open_message in [&certified, &expired, &expiring_later] {
+            repository.update_open_message(open_message).await.unwrap();
+        }
+
+        let signing_opportunities: Vec<SignedEntityType> = repository
+            .get_signing_opportunities()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|open_message| open_message.signed_entity_type)
+            .collect();
+
+        assert_eq!(2, signing_opportunities.len());
+        assert!(signing_opportunities.contains(&expiring_later.signed_entity_type));
+        assert!(signing_opportunities.contains(&open_messages[3].signed_entity_type));
+    }
+
     #[tokio::test]
     async fn repository_create_open_message() {
         let connection = get_connection().await;
@@ -11,6 +11,7 @@ use crate::database::query::{
     GetSignedEntityRecordQuery, InsertSignedEntityRecordQuery, UpdateSignedEntityQuery,
 };
 use crate::database::record::SignedEntityRecord;
+use crate::entities::ListFilters;
 
 /// Signed entity storer trait
 #[cfg_attr(test, mockall::automock)]
@@ -44,6 +45,13 @@ pub trait SignedEntityStorer: Sync + Send {
         total: usize,
     ) -> StdResult<Vec<SignedEntityRecord>>;
 
+    /// Get a page of the last signed entities by signed entity type matching the given filters
+    async fn get_signed_entities_by_type_with_filters(
+        &self,
+        signed_entity_type_id: &SignedEntityTypeDiscriminants,
+        filters: &ListFilters,
+    ) -> StdResult<Vec<SignedEntityRecord>>;
+
     /// Get Cardano stake distribution signed entity by epoch
     async fn get_cardano_stake_distribution_signed_entity_by_epoch(
         &self,
@@ -133,6 +141,25 @@ impl SignedEntityStorer for SignedEntityStore {
         Ok(signed_entities)
     }
 
+    async fn get_signed_entities_by_type_with_filters(
+        &self,
+        signed_entity_type_id: &SignedEntityTypeDiscriminants,
+        filters: &ListFilters,
+    ) -> StdResult<Vec<SignedEntityRecord>> {
+        let cursor = self
+            .connection
+            .fetch(GetSignedEntityRecordQuery::by_signed_entity_type_with_filters(
+                signed_entity_type_id,
+                filters,
+            )?)
+            .with_context(|| {
+                format!("get signed entities by type with filters failure, type: {signed_entity_type_id:?}, filters: {filters:?}")
+            })?;
+        let signed_entities: Vec<SignedEntityRecord> = cursor.collect();
+
+        Ok(signed_entities)
+    }
+
     async fn get_cardano_stake_distribution_signed_entity_by_epoch(
         &self,
         epoch: Epoch,
@@ -290,6 +317,36 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_signed_entities_by_type_with_filters_paginates() {
+        let records = SignedEntityRecord::fake_records(5);
+        let connection = main_db_connection().unwrap();
+        insert_signed_entities(&connection, records.clone()).unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+
+        let queried_records = store
+            .get_signed_entities_by_type_with_filters(
+                &SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                &ListFilters {
+                    offset: Some(1),
+                    ..ListFilters::with_limit(2)
+                },
+            )
+            .await
+            .expect("querying signed entities with filters should not fail");
+
+        assert_eq!(
+            // Records are inserted older to earlier and queried the other way round
+            records
+                .into_iter()
+                .rev()
+                .skip(1)
+                .take(2)
+                .collect::<Vec<_>>(),
+            queried_records
+        );
+    }
+
     #[tokio::test]
     async fn update_only_given_entities() {
         let mut signed_entity_records = SignedEntityRecord::fake_records(5);
@@ -1,11 +1,17 @@
+use anyhow::Context;
 use std::sync::Arc;
 
-use mithril_common::entities::SingleSignatures;
+use mithril_common::entities::{Epoch, PartyId, SingleSignatures};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
 
-use crate::database::query::UpdateSingleSignatureRecordQuery;
-use crate::database::record::{OpenMessageRecord, SingleSignatureRecord};
+use crate::database::query::{
+    GetSignerParticipationQuery, IncrementSignerParticipationQuery,
+    UpdateSingleSignatureRecordQuery,
+};
+use crate::database::record::{
+    OpenMessageRecord, SignerParticipationRecord, SingleSignatureRecord,
+};
 
 /// Service to deal with single_signature (read & write).
 pub struct SingleSignatureRepository {
@@ -29,6 +35,17 @@ impl SingleSignatureRepository {
             &open_message.open_message_id,
             open_message.epoch.offset_to_signer_retrieval_epoch()?,
         )?;
+        self.connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                open_message.epoch,
+                &single_signature,
+            ))
+            .with_context(|| {
+                format!(
+                    "Can not update the participation of signer '{}'",
+                    single_signature.signer_id
+                )
+            })?;
         let record = self.connection.fetch_first(UpdateSingleSignatureRecordQuery::one(single_signature.clone()))?
             .unwrap_or_else(|| {
                 panic!(
@@ -38,4 +55,32 @@ impl SingleSignatureRepository {
 
         Ok(record)
     }
+
+    /// Count the single signatures of the given signers as included in a certificate of the
+    /// given epoch.
+    pub async fn record_single_signatures_included(
+        &self,
+        epoch: Epoch,
+        party_ids: &[PartyId],
+    ) -> StdResult<()> {
+        if party_ids.is_empty() {
+            return Ok(());
+        }
+
+        let _: Vec<SignerParticipationRecord> = self.connection.fetch_collect(
+            IncrementSignerParticipationQuery::single_signatures_included(epoch, party_ids),
+        )?;
+
+        Ok(())
+    }
+
+    /// Return the participation of the given signer for each epoch it sent single signatures,
+    /// most recent first.
+    pub async fn get_signer_participation(
+        &self,
+        party_id: &PartyId,
+    ) -> StdResult<Vec<SignerParticipationRecord>> {
+        self.connection
+            .fetch_collect(GetSignerParticipationQuery::by_party_id(party_id))
+    }
 }
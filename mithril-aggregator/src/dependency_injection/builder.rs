@@ -61,26 +61,35 @@ use crate::{
         SignedEntityStorer, SignerRegistrationStore, SignerStore, SingleSignatureRepository,
         StakePoolStore,
     },
-    event_store::{EventMessage, EventStore, TransmitterService},
+    event_store::{
+        EventMessage, EventRepository, EventSink, EventStore, JsonLinesFileEventSink,
+        TransmitterService, WebhookEventSink,
+    },
     http_server::routes::router,
     services::{
-        AggregatorUpkeepService, CardanoTransactionsImporter, CertifierService, MessageService,
-        MithrilCertifierService, MithrilEpochService, MithrilMessageService, MithrilProverService,
-        MithrilSignedEntityService, MithrilStakeDistributionService, ProverService,
-        SignedEntityService, StakeDistributionService, UpkeepService,
+        AggregatorEventBroadcaster, AggregatorUpkeepService, CardanoTransactionsImporter,
+        CertifierService, MessageService, MithrilCertifierService, MithrilEpochService,
+        MithrilMessageService, MithrilProverService, MithrilSignedEntityService,
+        MithrilStakeDistributionService, ProverService, SignedEntityService,
+        StakeDistributionService, UpkeepService,
+    },
+    tools::{
+        CExplorerSignerRetriever, GcpFileUploader, GenesisToolsDependency, S3Bucket, S3Credentials,
+        S3FileUploader, SignersImporter, S3_DEFAULT_MULTIPART_PART_SIZE, S3_DEFAULT_REGION,
     },
-    tools::{CExplorerSignerRetriever, GcpFileUploader, GenesisToolsDependency, SignersImporter},
     AggregatorConfig, AggregatorRunner, AggregatorRuntime, CertificatePendingStore,
     CompressedArchiveSnapshotter, Configuration, DependencyContainer, DumbSnapshotUploader,
-    DumbSnapshotter, LocalSnapshotUploader, MithrilSignerRegisterer, MultiSigner, MultiSignerImpl,
-    ProtocolParametersStorer, RemoteSnapshotUploader, SnapshotUploader, SnapshotUploaderType,
-    Snapshotter, SnapshotterCompressionAlgorithm, VerificationKeyStorer,
+    DumbSnapshotter, LocalSnapshotUploader, MetricsService, MithrilSignerRegisterer, MultiSigner,
+    MultiSignerImpl, ProtocolParametersStorer, RemoteSnapshotUploader, S3SnapshotUploader,
+    SnapshotUploader, SnapshotUploaderType, Snapshotter, SnapshotterCompressionAlgorithm,
+    VerificationKeyStorer,
 };
 
 use super::{DependenciesBuilderError, EpochServiceWrapper, Result};
 
 const SQLITE_FILE: &str = "aggregator.sqlite3";
 const SQLITE_FILE_CARDANO_TRANSACTION: &str = "cardano-transaction.sqlite3";
+const SQLITE_MONITORING_FILE: &str = "monitoring.sqlite3";
 
 /// ## Dependencies container builder
 ///
@@ -106,6 +115,9 @@ pub struct DependenciesBuilder {
     /// Cardano transactions SQLite database connection pool
     pub sqlite_connection_cardano_transaction_pool: Option<Arc<SqliteConnectionPool>>,
 
+    /// Event store SQLite database connection
+    pub sqlite_connection_event_store: Option<Arc<SqliteConnection>>,
+
     /// Stake Store used by the StakeDistributionService
     /// It shall be a private dependency.
     pub stake_store: Option<Arc<StakePoolStore>>,
@@ -229,6 +241,15 @@ pub struct DependenciesBuilder {
 
     /// Upkeep service
     pub upkeep_service: Option<Arc<dyn UpkeepService>>,
+
+    /// Aggregator events broadcaster
+    pub aggregator_event_broadcaster: Option<Arc<AggregatorEventBroadcaster>>,
+
+    /// Metrics service
+    pub metrics_service: Option<Arc<MetricsService>>,
+
+    /// Event store repository
+    pub event_repository: Option<Arc<EventRepository>>,
 }
 
 impl DependenciesBuilder {
@@ -239,6 +260,7 @@ impl DependenciesBuilder {
             signed_entity_config: None,
             sqlite_connection: None,
             sqlite_connection_cardano_transaction_pool: None,
+            sqlite_connection_event_store: None,
             stake_store: None,
             snapshot_uploader: None,
             multi_signer: None,
@@ -279,6 +301,9 @@ impl DependenciesBuilder {
             signed_entity_type_lock: None,
             transactions_importer: None,
             upkeep_service: None,
+            aggregator_event_broadcaster: None,
+            metrics_service: None,
+            event_repository: None,
         }
     }
 
@@ -391,6 +416,22 @@ impl DependenciesBuilder {
             .unwrap())
     }
 
+    /// Get SQLite connection for the event store
+    pub async fn get_sqlite_connection_event_store(&mut self) -> Result<Arc<SqliteConnection>> {
+        if self.sqlite_connection_event_store.is_none() {
+            self.sqlite_connection_event_store = Some(Arc::new(self.build_sqlite_connection(
+                SQLITE_MONITORING_FILE,
+                crate::event_store::migration::get_migrations(),
+            )?));
+        }
+
+        Ok(self
+            .sqlite_connection_event_store
+            .as_ref()
+            .cloned()
+            .unwrap())
+    }
+
     async fn build_stake_store(&mut self) -> Result<Arc<StakePoolStore>> {
         let stake_pool_store = Arc::new(StakePoolStore::new(
             self.get_sqlite_connection().await?,
@@ -429,6 +470,64 @@ impl DependenciesBuilder {
                         self.configuration.snapshot_use_cdn_domain,
                     )))
                 }
+                SnapshotUploaderType::S3 => {
+                    let bucket_name = self
+                        .configuration
+                        .snapshot_bucket_name
+                        .to_owned()
+                        .ok_or_else(|| {
+                            DependenciesBuilderError::MissingConfiguration(
+                                "snapshot_bucket_name".to_string(),
+                            )
+                        })?;
+                    let region = self
+                        .configuration
+                        .snapshot_s3_region
+                        .to_owned()
+                        .unwrap_or(S3_DEFAULT_REGION.to_string());
+                    let endpoint = self
+                        .configuration
+                        .snapshot_s3_endpoint
+                        .to_owned()
+                        .unwrap_or(S3Bucket::aws_endpoint(&region));
+                    let bucket = S3Bucket::new(
+                        &endpoint,
+                        &bucket_name,
+                        self.configuration.snapshot_s3_force_path_style,
+                    )
+                    .map_err(|e| DependenciesBuilderError::Initialization {
+                        message: "Invalid S3 snapshot bucket configuration.".to_string(),
+                        error: Some(e),
+                    })?;
+                    let credentials = S3Credentials::from_env().map_err(|e| {
+                        DependenciesBuilderError::Initialization {
+                            message: "Missing S3 credentials.".to_string(),
+                            error: Some(e),
+                        }
+                    })?;
+                    let file_uploader = S3FileUploader::new(
+                        bucket.clone(),
+                        &region,
+                        credentials,
+                        self.configuration
+                            .snapshot_s3_multipart_part_size
+                            .unwrap_or(S3_DEFAULT_MULTIPART_PART_SIZE),
+                    )
+                    .map_err(|e| DependenciesBuilderError::Initialization {
+                        message: "Cannot create S3 file uploader.".to_string(),
+                        error: Some(e),
+                    })?;
+                    let cdn_domain = self
+                        .configuration
+                        .snapshot_use_cdn_domain
+                        .then_some(bucket_name);
+
+                    Ok(Arc::new(S3SnapshotUploader::new(
+                        Box::new(file_uploader),
+                        bucket,
+                        cdn_domain,
+                    )))
+                }
                 SnapshotUploaderType::Local => Ok(Arc::new(LocalSnapshotUploader::new(
                     self.configuration.get_server_url(),
                     &self.configuration.snapshot_directory,
@@ -1129,6 +1228,7 @@ impl DependenciesBuilder {
                 snapshotter,
                 snapshot_uploader,
                 self.configuration.snapshot_compression_algorithm,
+                self.get_metrics_service().await?,
             ));
         let prover_service = self.get_prover_service().await?;
         let cardano_transactions_artifact_builder = Arc::new(
@@ -1144,6 +1244,7 @@ impl DependenciesBuilder {
             cardano_transactions_artifact_builder,
             self.get_signed_entity_lock().await?,
             cardano_stake_distribution_artifact_builder,
+            self.get_metrics_service().await?,
         ));
 
         // Compute the cache pool for prover service
@@ -1245,6 +1346,8 @@ impl DependenciesBuilder {
             self.get_sqlite_connection_cardano_transaction_pool()
                 .await?,
             self.get_signed_entity_lock().await?,
+            self.get_event_repository().await?,
+            self.configuration.event_retention_policy()?,
             self.get_logger()?,
         ));
 
@@ -1259,6 +1362,55 @@ impl DependenciesBuilder {
         Ok(self.upkeep_service.as_ref().cloned().unwrap())
     }
 
+    async fn build_aggregator_event_broadcaster(
+        &mut self,
+    ) -> Result<Arc<AggregatorEventBroadcaster>> {
+        Ok(Arc::new(AggregatorEventBroadcaster::default()))
+    }
+
+    /// [AggregatorEventBroadcaster] service
+    pub async fn get_aggregator_event_broadcaster(
+        &mut self,
+    ) -> Result<Arc<AggregatorEventBroadcaster>> {
+        if self.aggregator_event_broadcaster.is_none() {
+            self.aggregator_event_broadcaster =
+                Some(self.build_aggregator_event_broadcaster().await?);
+        }
+
+        Ok(self.aggregator_event_broadcaster.as_ref().cloned().unwrap())
+    }
+
+    async fn build_metrics_service(&mut self) -> Result<Arc<MetricsService>> {
+        let metrics_service = MetricsService::new()?;
+
+        Ok(Arc::new(metrics_service))
+    }
+
+    /// [MetricsService] service
+    pub async fn get_metrics_service(&mut self) -> Result<Arc<MetricsService>> {
+        if self.metrics_service.is_none() {
+            self.metrics_service = Some(self.build_metrics_service().await?);
+        }
+
+        Ok(self.metrics_service.as_ref().cloned().unwrap())
+    }
+
+    async fn build_event_repository(&mut self) -> Result<Arc<EventRepository>> {
+        let event_repository =
+            EventRepository::new(self.get_sqlite_connection_event_store().await?);
+
+        Ok(Arc::new(event_repository))
+    }
+
+    /// [EventRepository] service
+    pub async fn get_event_repository(&mut self) -> Result<Arc<EventRepository>> {
+        if self.event_repository.is_none() {
+            self.event_repository = Some(self.build_event_repository().await?);
+        }
+
+        Ok(self.event_repository.as_ref().cloned().unwrap())
+    }
+
     /// Return an unconfigured [DependencyContainer]
     pub async fn build_dependency_container(&mut self) -> Result<DependencyContainer> {
         let dependency_manager = DependencyContainer {
@@ -1303,14 +1455,39 @@ impl DependenciesBuilder {
             prover_service: self.get_prover_service().await?,
             signed_entity_type_lock: self.get_signed_entity_lock().await?,
             upkeep_service: self.get_upkeep_service().await?,
+            aggregator_event_broadcaster: self.get_aggregator_event_broadcaster().await?,
+            metrics_service: self.get_metrics_service().await?,
+            event_repository: self.get_event_repository().await?,
         };
 
         Ok(dependency_manager)
     }
 
+    fn build_event_sinks(&self) -> Result<Vec<Arc<dyn EventSink>>> {
+        let mut sinks: Vec<Arc<dyn EventSink>> = vec![];
+        if let Some(file_path) = &self.configuration.event_store_export_file_path {
+            sinks.push(Arc::new(JsonLinesFileEventSink::new(file_path)));
+        }
+        if let Some(url) = &self.configuration.event_store_export_webhook_url {
+            let sink = WebhookEventSink::new(url).map_err(|e| {
+                DependenciesBuilderError::Initialization {
+                    message: "Could not create the events webhook sink".to_string(),
+                    error: Some(e),
+                }
+            })?;
+            sinks.push(Arc::new(sink));
+        }
+
+        Ok(sinks)
+    }
+
     /// Create dependencies for the [EventStore] task.
     pub async fn create_event_store(&mut self) -> Result<EventStore> {
-        let event_store = EventStore::new(self.get_event_transmitter_receiver().await?);
+        let event_store = EventStore::new(
+            self.get_event_transmitter_receiver().await?,
+            self.get_sqlite_connection_event_store().await?,
+            self.build_event_sinks()?,
+        );
 
         Ok(event_store)
     }
@@ -1327,6 +1504,7 @@ impl DependenciesBuilder {
             config,
             None,
             Arc::new(AggregatorRunner::new(dependency_container)),
+            self.get_metrics_service().await?,
         )
         .await
         .map_err(|e| DependenciesBuilderError::Initialization {
@@ -1434,6 +1612,7 @@ impl DependenciesBuilder {
         let multi_signer = self.get_multi_signer().await?;
         let ticker_service = self.get_ticker_service().await?;
         let epoch_service = self.get_epoch_service().await?;
+        let metrics_service = self.get_metrics_service().await?;
         let logger = self.get_logger()?;
 
         Ok(Arc::new(MithrilCertifierService::new(
@@ -1446,6 +1625,7 @@ impl DependenciesBuilder {
             multi_signer,
             ticker_service,
             epoch_service,
+            metrics_service,
             logger,
         )))
     }
@@ -1465,7 +1645,16 @@ impl DependenciesBuilder {
             self.get_sqlite_connection().await?,
         ));
         let signed_entity_storer = self.get_signed_entity_storer().await?;
-        let service = MithrilMessageService::new(certificate_repository, signed_entity_storer);
+        let open_message_repository = self.get_open_message_repository().await?;
+        let single_signature_repository = Arc::new(SingleSignatureRepository::new(
+            self.get_sqlite_connection().await?,
+        ));
+        let service = MithrilMessageService::new(
+            certificate_repository,
+            signed_entity_storer,
+            open_message_repository,
+            single_signature_repository,
+        );
 
         Ok(Arc::new(service))
     }
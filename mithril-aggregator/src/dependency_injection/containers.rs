@@ -24,15 +24,15 @@ use crate::{
         CertificateRepository, OpenMessageRepository, SignedEntityStorer, SignerGetter,
         StakePoolStore,
     },
-    event_store::{EventMessage, TransmitterService},
+    event_store::{EventMessage, EventRepository, TransmitterService},
     multi_signer::MultiSigner,
     services::{
-        CertifierService, EpochService, MessageService, ProverService, SignedEntityService,
-        StakeDistributionService, TransactionStore, UpkeepService,
+        AggregatorEventBroadcaster, CertifierService, EpochService, MessageService, ProverService,
+        SignedEntityService, StakeDistributionService, TransactionStore, UpkeepService,
     },
     signer_registerer::SignerRecorder,
     snapshot_uploaders::SnapshotUploader,
-    CertificatePendingStore, ProtocolParametersStorer, SignerRegisterer,
+    CertificatePendingStore, MetricsService, ProtocolParametersStorer, SignerRegisterer,
     SignerRegistrationRoundOpener, Snapshotter, VerificationKeyStorer,
 };
 
@@ -164,6 +164,15 @@ pub struct DependencyContainer {
 
     /// Upkeep service
     pub upkeep_service: Arc<dyn UpkeepService>,
+
+    /// Aggregator events broadcaster
+    pub aggregator_event_broadcaster: Arc<AggregatorEventBroadcaster>,
+
+    /// Metrics service
+    pub metrics_service: Arc<MetricsService>,
+
+    /// Event store repository
+    pub event_repository: Arc<EventRepository>,
 }
 
 #[doc(hidden)]
@@ -0,0 +1,91 @@
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use mithril_common::StdError;
+
+use crate::event_store::Event;
+
+/// Message structure of an event read from the event store
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventListItemMessage {
+    /// Sequential number of the event
+    pub event_id: i64,
+
+    /// Date and time at which the event was persisted
+    pub created_at: DateTime<Utc>,
+
+    /// Source of the event
+    pub source: String,
+
+    /// Action of the event, it declares the type of the content
+    pub action: String,
+
+    /// Headers of the event
+    pub headers: HashMap<String, String>,
+
+    /// JSON content of the event
+    pub content: serde_json::Value,
+}
+
+/// Format of the content persisted for each event
+#[derive(Deserialize)]
+struct PersistedEventContent {
+    headers: HashMap<String, String>,
+    content: serde_json::Value,
+}
+
+impl TryFrom<Event> for EventListItemMessage {
+    type Error = StdError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        let persisted_content: PersistedEventContent = serde_json::from_str(&event.content)
+            .with_context(|| {
+                format!(
+                    "Could not parse the content of the event with id: '{}'",
+                    event.event_id
+                )
+            })?;
+
+        Ok(Self {
+            event_id: event.event_id,
+            created_at: event.created_at,
+            source: event.source,
+            action: event.action,
+            headers: persisted_content.headers,
+            content: persisted_content.content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_event_split_its_headers_and_content() {
+        let created_at = Utc::now();
+        let event = Event {
+            event_id: 12,
+            created_at,
+            source: "source".to_string(),
+            action: "action".to_string(),
+            content: r#"{"headers": {"epoch": "3"}, "content": {"digest": "abc"}}"#.to_string(),
+        };
+
+        let message: EventListItemMessage = event.try_into().unwrap();
+
+        assert_eq!(
+            EventListItemMessage {
+                event_id: 12,
+                created_at,
+                source: "source".to_string(),
+                action: "action".to_string(),
+                headers: HashMap::from([("epoch".to_string(), "3".to_string())]),
+                content: serde_json::json!({"digest": "abc"}),
+            },
+            message
+        );
+    }
+}
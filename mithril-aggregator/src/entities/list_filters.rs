@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+
+/// Default number of items returned by the list routes.
+pub const LIST_DEFAULT_LIMIT: usize = 20;
+
+/// Maximum number of items that can be returned by the list routes in a single page.
+pub const LIST_MAX_LIMIT: usize = 100;
+
+/// Pagination and filters applied when listing certificates or artifacts.
+///
+/// It is deserialized from the query string of the list routes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListFilters {
+    /// Maximum number of items to return (default to [LIST_DEFAULT_LIMIT], capped to [LIST_MAX_LIMIT])
+    pub limit: Option<usize>,
+
+    /// Number of the most recent items to skip
+    pub offset: Option<usize>,
+
+    /// Only return the items from this epoch (inclusive)
+    pub epoch_from: Option<Epoch>,
+
+    /// Only return the items up to this epoch (inclusive)
+    pub epoch_to: Option<Epoch>,
+
+    /// Only return the items of this signed entity type
+    pub signed_entity_type: Option<SignedEntityTypeDiscriminants>,
+}
+
+impl ListFilters {
+    /// Create filters returning at most the given number of items.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Number of items to return, capped to [LIST_MAX_LIMIT].
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(LIST_DEFAULT_LIMIT).min(LIST_MAX_LIMIT)
+    }
+
+    /// Number of the most recent items to skip.
+    pub fn offset(&self) -> usize {
+        self.offset.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_is_defaulted_and_capped() {
+        assert_eq!(LIST_DEFAULT_LIMIT, ListFilters::default().limit());
+        assert_eq!(5, ListFilters::with_limit(5).limit());
+        assert_eq!(LIST_MAX_LIMIT, ListFilters::with_limit(10_000).limit());
+    }
+
+    async fn parse_query(query: &str) -> ListFilters {
+        warp::test::request()
+            .path(&format!("/?{query}"))
+            .filter(&warp::query::<ListFilters>())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn deserialize_from_query_string() {
+        let filters = parse_query(
+            "limit=5&offset=10&epoch_from=3&epoch_to=7&signed_entity_type=CardanoImmutableFilesFull",
+        )
+        .await;
+
+        assert_eq!(
+            ListFilters {
+                limit: Some(5),
+                offset: Some(10),
+                epoch_from: Some(Epoch(3)),
+                epoch_to: Some(Epoch(7)),
+                signed_entity_type: Some(SignedEntityTypeDiscriminants::CardanoImmutableFilesFull),
+            },
+            filters
+        );
+        assert_eq!(ListFilters::default(), parse_query("").await);
+    }
+}
@@ -1,10 +1,14 @@
 //! Entities module
 //!
 //! This module provide domain entities for the services & state machine.
+mod event_list_item_message;
+mod list_filters;
 mod open_message;
 mod signer_registration_message;
 mod signer_ticker_message;
 
+pub use event_list_item_message::EventListItemMessage;
+pub use list_filters::{ListFilters, LIST_DEFAULT_LIMIT, LIST_MAX_LIMIT};
 pub use open_message::OpenMessage;
 pub use signer_registration_message::{
     SignerRegistrationsListItemMessage, SignerRegistrationsMessage,
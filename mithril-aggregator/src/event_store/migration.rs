@@ -0,0 +1,35 @@
+//! Migration module for the event store
+//!
+use mithril_persistence::database::SqlMigration;
+
+/// Get all the migrations required by this version of the software.
+/// There shall be one migration per database version. There could be several
+/// statements per migration.
+pub fn get_migrations() -> Vec<SqlMigration> {
+    vec![
+        // Migration 1
+        // Add the `event` table, it may already exist since it was previously created on the
+        // fly by the event persister.
+        SqlMigration::new(
+            1,
+            r#"
+create table if not exists event (
+    event_id integer primary key asc autoincrement,
+    created_at text not null,
+    source text not null,
+    action text not null,
+    content text nul null
+);
+"#,
+        ),
+        // Migration 2
+        // Add indexes to query the events by source, action and creation date.
+        SqlMigration::new(
+            2,
+            r#"
+create index event_source_action_index on event(source, action);
+create index event_created_at_index on event(created_at);
+"#,
+        ),
+    ]
+}
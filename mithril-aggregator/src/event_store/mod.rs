@@ -1,10 +1,22 @@
 //! Event Store module
 //! This module proposes tools to send messages between processes and how to
-//! persist them in a separate database.
+//! persist them in a separate database, optionally exporting them to external sinks.
 mod event;
+pub mod migration;
+mod query;
+mod repository;
 mod runner;
+mod sink;
+#[cfg(test)]
+pub(crate) mod test_helper;
 mod transmitter_service;
 
 pub use event::{Event, EventMessage, EventPersister};
+pub use repository::{
+    EventFilters, EventRepository, EventRetentionPolicy, SnapshotDownloadStatistics,
+    CLIENT_TYPE_EVENT_HEADER, SNAPSHOT_DOWNLOADED_EVENT_ACTION, SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+    UNKNOWN_CLIENT_TYPE,
+};
 pub use runner::EventStore;
+pub use sink::{EventSink, JsonLinesFileEventSink, WebhookEventSink};
 pub use transmitter_service::TransmitterService;
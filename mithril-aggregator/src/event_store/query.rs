@@ -0,0 +1,455 @@
+use chrono::{DateTime, Utc};
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{
+    HydrationError, Projection, Query, SourceAlias, SqLiteEntity, WhereCondition,
+};
+
+use super::Event;
+
+/// Query to read [Event] from the event store database, most recent first.
+pub struct GetEventQuery {
+    condition: WhereCondition,
+    limit: usize,
+    offset: usize,
+}
+
+impl GetEventQuery {
+    /// Query all the events, paginated
+    pub fn all(limit: usize, offset: usize) -> Self {
+        Self {
+            condition: WhereCondition::default(),
+            limit,
+            offset,
+        }
+    }
+
+    /// Only select the events sent by the given source
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.condition = self.condition.and_where(WhereCondition::new(
+            "source = ?*",
+            vec![Value::String(source.to_string())],
+        ));
+        self
+    }
+
+    /// Only select the events of the given action
+    pub fn with_action(mut self, action: &str) -> Self {
+        self.condition = self.condition.and_where(WhereCondition::new(
+            "action = ?*",
+            vec![Value::String(action.to_string())],
+        ));
+        self
+    }
+
+    /// Only select the events created at or after the given date
+    pub fn with_created_from(mut self, from: DateTime<Utc>) -> Self {
+        self.condition = self.condition.and_where(WhereCondition::new(
+            "created_at >= ?*",
+            vec![Value::String(from.to_rfc3339())],
+        ));
+        self
+    }
+
+    /// Only select the events created at or before the given date
+    pub fn with_created_to(mut self, to: DateTime<Utc>) -> Self {
+        self.condition = self.condition.and_where(WhereCondition::new(
+            "created_at <= ?*",
+            vec![Value::String(to.to_rfc3339())],
+        ));
+        self
+    }
+
+    /// Only select the events that have a header with the given name and value
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.condition = self.condition.and_where(WhereCondition::new(
+            "json_extract(content, ?*) = ?*",
+            vec![
+                Value::String(header_json_path(name)),
+                Value::String(value.to_string()),
+            ],
+        ));
+        self
+    }
+}
+
+impl Query for GetEventQuery {
+    type Entity = Event;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let projection = Self::Entity::get_projection().expand(SourceAlias::default());
+
+        format!(
+            "select {projection} from event where {condition} order by event_id desc limit {} offset {}",
+            self.limit, self.offset
+        )
+    }
+}
+
+/// Number of events sharing the same value for a given key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventCountRecord {
+    /// Value of the key the events are grouped by
+    pub key: String,
+
+    /// Number of events for this key
+    pub count: u64,
+}
+
+impl SqLiteEntity for EventCountRecord {
+    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let count = row.read::<i64, _>("count");
+
+        Ok(Self {
+            key: row.read::<&str, _>("key").to_string(),
+            count: u64::try_from(count).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not cast the event count ({count}) to u64. Error: '{e}'"
+                ))
+            })?,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        let mut projection = Projection::default();
+        projection.add_field("key", "{:event:}.key", "text");
+        projection.add_field("count", "{:event:}.count", "int");
+
+        projection
+    }
+}
+
+/// Query to count the events of a source and an action, grouped by a value read in their
+/// content or headers.
+pub struct GetEventCountQuery {
+    condition: WhereCondition,
+    key_expression: String,
+}
+
+impl GetEventCountQuery {
+    /// Count the events grouped by the value of the given field of their content
+    pub fn by_content_field(source: &str, action: &str, field: &str) -> Self {
+        Self {
+            condition: Self::source_and_action_condition(source, action),
+            key_expression: format!("json_extract(content, '$.content.\"{field}\"')"),
+        }
+    }
+
+    /// Count the events grouped by the value of the given header, the events without this
+    /// header are counted with the `default_value` key.
+    pub fn by_header(source: &str, action: &str, name: &str, default_value: &str) -> Self {
+        Self {
+            condition: Self::source_and_action_condition(source, action),
+            key_expression: format!(
+                "coalesce(json_extract(content, '{}'), '{default_value}')",
+                header_json_path(name)
+            ),
+        }
+    }
+
+    fn source_and_action_condition(source: &str, action: &str) -> WhereCondition {
+        WhereCondition::new(
+            "source = ?* and action = ?*",
+            vec![
+                Value::String(source.to_string()),
+                Value::String(action.to_string()),
+            ],
+        )
+    }
+}
+
+impl Query for GetEventCountQuery {
+    type Entity = EventCountRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        format!(
+            "select {} as key, count(*) as count from event where {condition} group by key order by count desc, key",
+            self.key_expression
+        )
+    }
+}
+
+/// Query to delete [Event] from the event store database.
+pub struct DeleteEventQuery {
+    condition: WhereCondition,
+}
+
+impl DeleteEventQuery {
+    /// Delete the events created strictly before the given date
+    pub fn created_before(date: DateTime<Utc>) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "created_at < ?*",
+                vec![Value::String(date.to_rfc3339())],
+            ),
+        }
+    }
+
+    /// Delete the oldest events so at most `max_rows` events are kept
+    pub fn exceeding_max_rows(max_rows: u64) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "event_id <= (select event_id from event order by event_id desc limit 1 offset ?*)",
+                vec![Value::Integer(max_rows as i64)],
+            ),
+        }
+    }
+}
+
+impl Query for DeleteEventQuery {
+    type Entity = Event;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let projection = Self::Entity::get_projection().expand(SourceAlias::default());
+
+        format!("delete from event where {condition} returning {projection}")
+    }
+}
+
+fn header_json_path(name: &str) -> String {
+    format!("$.headers.\"{name}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use std::sync::Arc;
+
+    use mithril_common::StdResult;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::event_store::test_helper::{event_store_db_connection, insert_event};
+
+    use super::*;
+
+    #[test]
+    fn get_events_most_recent_first_with_pagination() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        let now = Utc::now();
+        for index in 0..5 {
+            insert_event(
+                &connection,
+                now,
+                "source",
+                "action",
+                &[],
+                &format!(r#"{{"index": {index}}}"#),
+            )?;
+        }
+
+        let events: Vec<Event> = connection.fetch_collect(GetEventQuery::all(2, 1))?;
+
+        assert_eq!(
+            vec![4, 3],
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn get_events_filtered_by_source_action_and_header() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        let now = Utc::now();
+        insert_event(&connection, now, "source", "action", &[("h", "a")], "{}")?;
+        insert_event(&connection, now, "source", "action", &[("h", "b")], "{}")?;
+        insert_event(&connection, now, "source", "other", &[("h", "a")], "{}")?;
+        insert_event(&connection, now, "other", "action", &[("h", "a")], "{}")?;
+
+        let events: Vec<Event> = connection.fetch_collect(
+            GetEventQuery::all(10, 0)
+                .with_source("source")
+                .with_action("action")
+                .with_header("h", "a"),
+        )?;
+
+        assert_eq!(
+            vec![1],
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn get_events_filtered_by_creation_date() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        let now = Utc::now();
+        for days in [3, 2, 1] {
+            insert_event(
+                &connection,
+                now - Duration::days(days),
+                "source",
+                "action",
+                &[],
+                "{}",
+            )?;
+        }
+
+        let events: Vec<Event> = connection.fetch_collect(
+            GetEventQuery::all(10, 0)
+                .with_created_from(now - Duration::days(2))
+                .with_created_to(now - Duration::hours(36)),
+        )?;
+
+        assert_eq!(
+            vec![2],
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn delete_events_created_before_date() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        let now = Utc::now();
+        for days in [3, 2, 1] {
+            insert_event(
+                &connection,
+                now - Duration::days(days),
+                "source",
+                "action",
+                &[],
+                "{}",
+            )?;
+        }
+
+        let deleted_events: Vec<Event> = connection
+            .fetch_collect(DeleteEventQuery::created_before(now - Duration::hours(36)))?;
+        let remaining_events: Vec<Event> = connection.fetch_collect(GetEventQuery::all(10, 0))?;
+
+        assert_eq!(
+            vec![1, 2],
+            deleted_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3],
+            remaining_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn delete_events_exceeding_max_rows() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        for _ in 0..5 {
+            insert_event(&connection, Utc::now(), "source", "action", &[], "{}")?;
+        }
+
+        let deleted_events: Vec<Event> =
+            connection.fetch_collect(DeleteEventQuery::exceeding_max_rows(10))?;
+        assert!(deleted_events.is_empty());
+
+        let deleted_events: Vec<Event> =
+            connection.fetch_collect(DeleteEventQuery::exceeding_max_rows(2))?;
+        let remaining_events: Vec<Event> = connection.fetch_collect(GetEventQuery::all(10, 0))?;
+
+        assert_eq!(
+            vec![1, 2, 3],
+            deleted_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![5, 4],
+            remaining_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count_events_by_content_field_and_header() -> StdResult<()> {
+        let connection = Arc::new(event_store_db_connection()?);
+        let now = Utc::now();
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "action",
+            &[("client_type", "CLI")],
+            r#"{"digest": "digest-1"}"#,
+        )?;
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "action",
+            &[],
+            r#"{"digest": "digest-1"}"#,
+        )?;
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "action",
+            &[("client_type", "CLI")],
+            r#"{"digest": "digest-2"}"#,
+        )?;
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "other",
+            &[],
+            r#"{"digest": "digest-2"}"#,
+        )?;
+
+        let per_digest: Vec<EventCountRecord> = connection.fetch_collect(
+            GetEventCountQuery::by_content_field("source", "action", "digest"),
+        )?;
+        let per_client_type: Vec<EventCountRecord> = connection.fetch_collect(
+            GetEventCountQuery::by_header("source", "action", "client_type", "UNKNOWN"),
+        )?;
+
+        assert_eq!(
+            vec![
+                EventCountRecord {
+                    key: "digest-1".to_string(),
+                    count: 2
+                },
+                EventCountRecord {
+                    key: "digest-2".to_string(),
+                    count: 1
+                },
+            ],
+            per_digest
+        );
+        assert_eq!(
+            vec![
+                EventCountRecord {
+                    key: "CLI".to_string(),
+                    count: 2
+                },
+                EventCountRecord {
+                    key: "UNKNOWN".to_string(),
+                    count: 1
+                },
+            ],
+            per_client_type
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,375 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+use crate::entities::{LIST_DEFAULT_LIMIT, LIST_MAX_LIMIT};
+
+use super::query::{DeleteEventQuery, EventCountRecord, GetEventCountQuery, GetEventQuery};
+use super::Event;
+
+/// Source of the events sent when a client downloads a snapshot.
+pub const SNAPSHOT_DOWNLOADED_EVENT_SOURCE: &str = "HTTP::statistics";
+
+/// Action of the events sent when a client downloads a snapshot.
+pub const SNAPSHOT_DOWNLOADED_EVENT_ACTION: &str = "snapshot_downloaded";
+
+/// Header of the snapshot downloaded events holding the type of the client.
+pub const CLIENT_TYPE_EVENT_HEADER: &str = "client_type";
+
+/// Client type used in the statistics for the events sent without a client type header.
+pub const UNKNOWN_CLIENT_TYPE: &str = "UNKNOWN";
+
+/// Filters applied when reading events from the event store.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventFilters {
+    /// Only return the events sent by this source
+    pub source: Option<String>,
+
+    /// Only return the events of this action
+    pub action: Option<String>,
+
+    /// Only return the events created at or after this date
+    pub from: Option<DateTime<Utc>>,
+
+    /// Only return the events created at or before this date
+    pub to: Option<DateTime<Utc>>,
+
+    /// Only return the events with this header, formatted as `name:value`
+    pub header: Option<String>,
+
+    /// Maximum number of events to return
+    pub limit: Option<usize>,
+
+    /// Number of the most recent events to skip
+    pub offset: Option<usize>,
+}
+
+impl EventFilters {
+    /// Maximum number of events to return, defaults to [LIST_DEFAULT_LIMIT] and capped to
+    /// [LIST_MAX_LIMIT].
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(LIST_DEFAULT_LIMIT).min(LIST_MAX_LIMIT)
+    }
+
+    /// Number of the most recent events to skip
+    pub fn offset(&self) -> usize {
+        self.offset.unwrap_or_default()
+    }
+
+    /// Name and value of the header filter, `None` if not set or not formatted as `name:value`.
+    pub fn header_name_and_value(&self) -> Option<(&str, &str)> {
+        self.header
+            .as_ref()
+            .and_then(|header| header.split_once(':'))
+            .filter(|(name, _)| !name.is_empty())
+    }
+}
+
+/// Snapshot downloads aggregated from the snapshot downloaded events.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDownloadStatistics {
+    /// Total number of snapshot downloads
+    pub total: u64,
+
+    /// Number of downloads per snapshot digest
+    pub per_digest: BTreeMap<String, u64>,
+
+    /// Number of downloads per client type
+    pub per_client_type: BTreeMap<String, u64>,
+}
+
+/// Retention policy applied to the events of the event store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventRetentionPolicy {
+    /// Maximum age of the events, older events are deleted
+    pub max_age: Option<Duration>,
+
+    /// Maximum number of events, the oldest events exceeding it are deleted
+    pub max_rows: Option<u64>,
+}
+
+impl EventRetentionPolicy {
+    /// Check if the policy does not delete any event
+    pub fn is_disabled(&self) -> bool {
+        self.max_age.is_none() && self.max_rows.is_none()
+    }
+}
+
+/// Read side of the event store.
+pub struct EventRepository {
+    connection: Arc<SqliteConnection>,
+}
+
+impl EventRepository {
+    /// Instantiate a new repository
+    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+        Self { connection }
+    }
+
+    /// Return the events matching the given filters, most recent first.
+    pub fn get_events(&self, filters: &EventFilters) -> StdResult<Vec<Event>> {
+        let mut query = GetEventQuery::all(filters.limit(), filters.offset());
+        if let Some(source) = &filters.source {
+            query = query.with_source(source);
+        }
+        if let Some(action) = &filters.action {
+            query = query.with_action(action);
+        }
+        if let Some(from) = filters.from {
+            query = query.with_created_from(from);
+        }
+        if let Some(to) = filters.to {
+            query = query.with_created_to(to);
+        }
+        if let Some((name, value)) = filters.header_name_and_value() {
+            query = query.with_header(name, value);
+        }
+
+        self.connection.fetch_collect(query)
+    }
+
+    /// Delete the events that do not comply with the given retention policy, return the number
+    /// of deleted events.
+    pub fn prune(&self, policy: &EventRetentionPolicy) -> StdResult<usize> {
+        let mut deleted_events = 0;
+        // No event can be older than a max age exceeding the range of the dates
+        if let Some(oldest_kept_date) = policy
+            .max_age
+            .and_then(|max_age| Utc::now().checked_sub_signed(max_age))
+        {
+            deleted_events += self
+                .connection
+                .fetch(DeleteEventQuery::created_before(oldest_kept_date))?
+                .count();
+        }
+        if let Some(max_rows) = policy.max_rows {
+            deleted_events += self
+                .connection
+                .fetch(DeleteEventQuery::exceeding_max_rows(max_rows))?
+                .count();
+        }
+
+        Ok(deleted_events)
+    }
+
+    /// Compute the snapshot download statistics from the snapshot downloaded events.
+    pub fn get_snapshot_download_statistics(&self) -> StdResult<SnapshotDownloadStatistics> {
+        let per_digest: Vec<EventCountRecord> =
+            self.connection
+                .fetch_collect(GetEventCountQuery::by_content_field(
+                    SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+                    SNAPSHOT_DOWNLOADED_EVENT_ACTION,
+                    "digest",
+                ))?;
+        let per_client_type: Vec<EventCountRecord> =
+            self.connection
+                .fetch_collect(GetEventCountQuery::by_header(
+                    SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+                    SNAPSHOT_DOWNLOADED_EVENT_ACTION,
+                    CLIENT_TYPE_EVENT_HEADER,
+                    UNKNOWN_CLIENT_TYPE,
+                ))?;
+
+        Ok(SnapshotDownloadStatistics {
+            total: per_digest.iter().map(|record| record.count).sum(),
+            per_digest: per_digest.into_iter().map(|r| (r.key, r.count)).collect(),
+            per_client_type: per_client_type
+                .into_iter()
+                .map(|r| (r.key, r.count))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event_store::test_helper::{event_store_db_connection, insert_event};
+
+    use super::*;
+
+    #[test]
+    fn event_filters_limit_defaults_and_is_capped() {
+        assert_eq!(LIST_DEFAULT_LIMIT, EventFilters::default().limit());
+        assert_eq!(
+            LIST_MAX_LIMIT,
+            EventFilters {
+                limit: Some(LIST_MAX_LIMIT + 1),
+                ..EventFilters::default()
+            }
+            .limit()
+        );
+    }
+
+    #[test]
+    fn event_filters_header_name_and_value() {
+        let filters_with_header = |header: &str| EventFilters {
+            header: Some(header.to_string()),
+            ..EventFilters::default()
+        };
+
+        assert_eq!(None, EventFilters::default().header_name_and_value());
+        assert_eq!(
+            None,
+            filters_with_header("no_value").header_name_and_value()
+        );
+        assert_eq!(None, filters_with_header(":value").header_name_and_value());
+        assert_eq!(
+            Some(("epoch", "12")),
+            filters_with_header("epoch:12").header_name_and_value()
+        );
+    }
+
+    #[test]
+    fn get_events_with_filters() {
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let now = Utc::now();
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "action",
+            &[("epoch", "1")],
+            "{}",
+        )
+        .unwrap();
+        insert_event(
+            &connection,
+            now,
+            "source",
+            "action",
+            &[("epoch", "2")],
+            "{}",
+        )
+        .unwrap();
+        insert_event(&connection, now, "source", "other", &[("epoch", "2")], "{}").unwrap();
+        let repository = EventRepository::new(connection);
+
+        let events = repository
+            .get_events(&EventFilters {
+                action: Some("action".to_string()),
+                header: Some("epoch:2".to_string()),
+                ..EventFilters::default()
+            })
+            .unwrap();
+
+        assert_eq!(
+            vec![2],
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn prune_events_with_max_age_and_max_rows() {
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let now = Utc::now();
+        for days in [10, 5, 3, 2, 1] {
+            insert_event(
+                &connection,
+                now - Duration::days(days),
+                "source",
+                "action",
+                &[],
+                "{}",
+            )
+            .unwrap();
+        }
+        let repository = EventRepository::new(connection);
+
+        let deleted_events = repository
+            .prune(&EventRetentionPolicy {
+                max_age: Some(Duration::days(4)),
+                max_rows: Some(2),
+            })
+            .unwrap();
+
+        assert_eq!(3, deleted_events);
+        let remaining_events = repository.get_events(&EventFilters::default()).unwrap();
+        assert_eq!(
+            vec![5, 4],
+            remaining_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn prune_events_with_a_max_age_exceeding_the_dates_range_keeps_all_events() {
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        insert_event(&connection, Utc::now(), "source", "action", &[], "{}").unwrap();
+        let repository = EventRepository::new(connection);
+
+        let deleted_events = repository
+            .prune(&EventRetentionPolicy {
+                max_age: Duration::try_days(i64::MAX / 86_400_000),
+                max_rows: None,
+            })
+            .unwrap();
+
+        assert_eq!(0, deleted_events);
+    }
+
+    #[test]
+    fn prune_events_with_disabled_policy_keeps_all_events() {
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        insert_event(&connection, Utc::now(), "source", "action", &[], "{}").unwrap();
+        let repository = EventRepository::new(connection);
+
+        let deleted_events = repository.prune(&EventRetentionPolicy::default()).unwrap();
+
+        assert_eq!(0, deleted_events);
+        assert_eq!(
+            1,
+            repository
+                .get_events(&EventFilters::default())
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn get_snapshot_download_statistics() {
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let now = Utc::now();
+        for (digest, headers) in [
+            ("digest-1", vec![(CLIENT_TYPE_EVENT_HEADER, "CLI")]),
+            ("digest-1", vec![(CLIENT_TYPE_EVENT_HEADER, "LIBRARY")]),
+            ("digest-2", vec![(CLIENT_TYPE_EVENT_HEADER, "CLI")]),
+            ("digest-2", vec![]),
+        ] {
+            insert_event(
+                &connection,
+                now,
+                SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+                SNAPSHOT_DOWNLOADED_EVENT_ACTION,
+                &headers,
+                &format!(r#"{{"digest": "{digest}"}}"#),
+            )
+            .unwrap();
+        }
+        insert_event(&connection, now, "source", "action", &[], "{}").unwrap();
+        let repository = EventRepository::new(connection);
+
+        let statistics = repository.get_snapshot_download_statistics().unwrap();
+
+        assert_eq!(
+            SnapshotDownloadStatistics {
+                total: 4,
+                per_digest: BTreeMap::from([
+                    ("digest-1".to_string(), 2),
+                    ("digest-2".to_string(), 2)
+                ]),
+                per_client_type: BTreeMap::from([
+                    ("CLI".to_string(), 2),
+                    ("LIBRARY".to_string(), 1),
+                    (UNKNOWN_CLIENT_TYPE.to_string(), 1)
+                ]),
+            },
+            statistics
+        );
+    }
+}
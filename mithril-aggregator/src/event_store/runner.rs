@@ -1,36 +1,57 @@
 use anyhow::Context;
-use slog_scope::{debug, info};
-use sqlite::Connection;
-use std::{path::PathBuf, sync::Arc};
-use tokio::sync::mpsc::UnboundedReceiver;
+use slog_scope::{debug, info, warn};
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, error::TrySendError, Sender, UnboundedReceiver};
+use tokio::task::JoinHandle;
 
 use mithril_common::StdResult;
+use mithril_persistence::sqlite::SqliteConnection;
 
-use super::{EventMessage, EventPersister};
+use crate::entities::EventListItemMessage;
+
+use super::{Event, EventMessage, EventPersister, EventSink};
+
+/// Default maximum number of persisted events waiting to be exported to the sinks
+const DEFAULT_SINKS_EXPORTER_CAPACITY: usize = 1_000;
 
 /// EventMessage receiver service.
 pub struct EventStore {
     receiver: UnboundedReceiver<EventMessage>,
+    connection: Arc<SqliteConnection>,
+    sinks: Vec<Arc<dyn EventSink>>,
+    sinks_exporter_capacity: usize,
 }
 
 impl EventStore {
     /// Instantiate the EventMessage receiver service.
-    pub fn new(receiver: UnboundedReceiver<EventMessage>) -> Self {
-        Self { receiver }
+    pub fn new(
+        receiver: UnboundedReceiver<EventMessage>,
+        connection: Arc<SqliteConnection>,
+        sinks: Vec<Arc<dyn EventSink>>,
+    ) -> Self {
+        Self {
+            receiver,
+            connection,
+            sinks,
+            sinks_exporter_capacity: DEFAULT_SINKS_EXPORTER_CAPACITY,
+        }
+    }
+
+    /// Set the maximum number of persisted events waiting to be exported to the sinks, the
+    /// events persisted while it is reached are not exported.
+    pub fn with_sinks_exporter_capacity(mut self, capacity: usize) -> Self {
+        self.sinks_exporter_capacity = capacity;
+        self
     }
 
     /// Launch the service. It runs until all the transmitters are gone and all
     /// messages have been processed. This means this service shall be waited
-    /// upon completion to ensure all events are properly saved in the database.
-    pub async fn run(&mut self, file: Option<PathBuf>) -> StdResult<()> {
-        let connection = {
-            let connection = match file {
-                Some(path) => Connection::open_thread_safe(path)?,
-                None => Connection::open_thread_safe(":memory:")?,
-            };
-            Arc::new(connection)
-        };
-        let persister = EventPersister::new(connection);
+    /// upon completion to ensure all events are properly saved in the database
+    /// and exported to the sinks.
+    pub async fn run(&mut self) -> StdResult<()> {
+        let persister = EventPersister::new(self.connection.clone());
+        let sinks_exporter = self.spawn_sinks_exporter();
+        let mut not_exported_events: u64 = 0;
         info!("monitoring: starting event loop to log messages.");
         loop {
             if let Some(message) = self.receiver.recv().await {
@@ -39,12 +60,219 @@ impl EventStore {
                     .persist(message)
                     .with_context(|| "event persist failure")?;
                 debug!("event ID={} created", event.event_id);
+                if let Some((sender, _)) = &sinks_exporter {
+                    match sender.try_send(event) {
+                        Ok(()) => {}
+                        Err(TrySendError::Full(event)) => {
+                            not_exported_events += 1;
+                            warn!(
+                                "Event ID={} not exported: too many events are waiting to be exported to the sinks",
+                                event.event_id; "not_exported_events" => not_exported_events
+                            );
+                        }
+                        Err(TrySendError::Closed(event)) => {
+                            not_exported_events += 1;
+                            warn!(
+                                "Event ID={} could not be sent to the sinks exporter",
+                                event.event_id; "not_exported_events" => not_exported_events
+                            );
+                        }
+                    }
+                }
             } else {
                 info!("No more events to proceed, quitting…");
                 break;
             }
         }
 
+        if let Some((sender, exporter)) = sinks_exporter {
+            drop(sender);
+            if let Err(error) = exporter.await {
+                warn!("Event sinks exporter stopped unexpectedly"; "error" => ?error);
+            }
+        }
+
         Ok(())
     }
+
+    /// Spawn the task exporting the persisted events to the sinks, so a slow sink does not
+    /// delay the persistence of the next events.
+    fn spawn_sinks_exporter(&self) -> Option<(Sender<Event>, JoinHandle<()>)> {
+        if self.sinks.is_empty() {
+            return None;
+        }
+
+        let (sender, mut receiver) = mpsc::channel::<Event>(self.sinks_exporter_capacity);
+        let sinks = self.sinks.clone();
+        let exporter = tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                Self::export_to_sinks(&sinks, event).await;
+            }
+        });
+
+        Some((sender, exporter))
+    }
+
+    /// Export the persisted event to the sinks, a failure is only logged so it does not
+    /// prevent the next events to be exported.
+    async fn export_to_sinks(sinks: &[Arc<dyn EventSink>], event: Event) {
+        let event_id = event.event_id;
+        let message = match EventListItemMessage::try_from(event) {
+            Ok(message) => message,
+            Err(error) => {
+                warn!("Event ID={event_id} could not be exported"; "error" => ?error);
+                return;
+            }
+        };
+        for sink in sinks {
+            if let Err(error) = sink.export(&message).await {
+                warn!("Event ID={event_id} could not be exported to a sink"; "error" => ?error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::predicate::function;
+    use std::time::Duration;
+    use tokio::sync::Semaphore;
+
+    use crate::event_store::sink::MockEventSink;
+    use crate::event_store::test_helper::event_store_db_connection;
+    use crate::event_store::{EventFilters, EventRepository};
+
+    use super::*;
+
+    /// A sink whose exports wait for a permit
+    struct BlockedSink {
+        permits: Arc<Semaphore>,
+    }
+
+    #[async_trait]
+    impl EventSink for BlockedSink {
+        async fn export(&self, _event: &EventListItemMessage) -> StdResult<()> {
+            self.permits.acquire().await?.forget();
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn persisted_events_are_exported_to_all_sinks_even_if_one_fails() {
+        let (transmitter, receiver) = mpsc::unbounded_channel();
+        let mut failing_sink = MockEventSink::new();
+        failing_sink
+            .expect_export()
+            .times(2)
+            .returning(|_| Err(anyhow::anyhow!("sink error")));
+        let mut sink = MockEventSink::new();
+        sink.expect_export()
+            .with(function(|event: &EventListItemMessage| {
+                event.source == "source" && event.action == "action"
+            }))
+            .times(2)
+            .returning(|_| Ok(()));
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let mut event_store = EventStore::new(
+            receiver,
+            connection,
+            vec![Arc::new(failing_sink), Arc::new(sink)],
+        );
+
+        transmitter
+            .send(EventMessage::new("source", "action", "{}"))
+            .unwrap();
+        transmitter
+            .send(EventMessage::new("source", "action", "{}"))
+            .unwrap();
+        drop(transmitter);
+
+        event_store.run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn events_are_persisted_while_a_sink_is_blocked() {
+        let (transmitter, receiver) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let mut event_store = EventStore::new(
+            receiver,
+            connection.clone(),
+            vec![Arc::new(BlockedSink {
+                permits: permits.clone(),
+            })],
+        );
+        let event_store_handle = tokio::spawn(async move { event_store.run().await });
+
+        for _ in 0..3 {
+            transmitter
+                .send(EventMessage::new("source", "action", "{}"))
+                .unwrap();
+        }
+        drop(transmitter);
+
+        let repository = EventRepository::new(connection);
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while repository
+                .get_events(&EventFilters::default())
+                .unwrap()
+                .len()
+                < 3
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("All events should be persisted while the sink is blocked");
+        assert!(!event_store_handle.is_finished());
+
+        permits.add_permits(3);
+        event_store_handle.await.unwrap().unwrap();
+        assert_eq!(0, permits.available_permits());
+    }
+
+    #[tokio::test]
+    async fn events_are_not_exported_while_the_sinks_exporter_is_full() {
+        let (transmitter, receiver) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let connection = Arc::new(event_store_db_connection().unwrap());
+        let mut event_store = EventStore::new(
+            receiver,
+            connection.clone(),
+            vec![Arc::new(BlockedSink {
+                permits: permits.clone(),
+            })],
+        )
+        .with_sinks_exporter_capacity(1);
+        let event_store_handle = tokio::spawn(async move { event_store.run().await });
+
+        for _ in 0..3 {
+            transmitter
+                .send(EventMessage::new("source", "action", "{}"))
+                .unwrap();
+        }
+        drop(transmitter);
+
+        let repository = EventRepository::new(connection);
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while repository
+                .get_events(&EventFilters::default())
+                .unwrap()
+                .len()
+                < 3
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("All events should be persisted while the sinks exporter is full");
+
+        permits.add_permits(3);
+        event_store_handle.await.unwrap().unwrap();
+        assert!(
+            permits.available_permits() > 0,
+            "Not all the events should have been exported"
+        );
+    }
 }
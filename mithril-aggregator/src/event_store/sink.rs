@@ -0,0 +1,179 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use reqwest::Client;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+use mithril_common::StdResult;
+
+use crate::entities::EventListItemMessage;
+
+/// Destination to which the persisted events are exported, in addition to the event store
+/// database.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Export the given event
+    async fn export(&self, event: &EventListItemMessage) -> StdResult<()>;
+}
+
+/// [EventSink] that appends the events to a file, one JSON document per line.
+pub struct JsonLinesFileEventSink {
+    file_path: PathBuf,
+}
+
+impl JsonLinesFileEventSink {
+    /// Instantiate a new sink writing to the given file, it is created if it does not exist.
+    pub fn new(file_path: &Path) -> Self {
+        Self {
+            file_path: file_path.to_path_buf(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventSink for JsonLinesFileEventSink {
+    async fn export(&self, event: &EventListItemMessage) -> StdResult<()> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file_path)
+            .await
+            .with_context(|| {
+                format!("Could not open events file: '{}'", self.file_path.display())
+            })?;
+        // Flush since a tokio file may not have written all its data when dropped
+        async {
+            file.write_all(line.as_bytes()).await?;
+            file.flush().await
+        }
+        .await
+        .with_context(|| {
+            format!(
+                "Could not write event to file: '{}'",
+                self.file_path.display()
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+/// [EventSink] that forwards the events as JSON to a webhook with a `POST` request.
+pub struct WebhookEventSink {
+    url: String,
+    client: Client,
+}
+
+impl WebhookEventSink {
+    /// Timeout of the requests sent to the webhook
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Instantiate a new sink forwarding the events to the given url.
+    pub fn new(url: &str) -> StdResult<Self> {
+        let client = Client::builder()
+            .timeout(Self::REQUEST_TIMEOUT)
+            .build()
+            .with_context(|| "Could not build the events webhook HTTP client")?;
+
+        Ok(Self {
+            url: url.to_string(),
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookEventSink {
+    async fn export(&self, event: &EventListItemMessage) -> StdResult<()> {
+        self.client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Could not forward event to webhook: '{}'", self.url))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use httpmock::{Method::POST, MockServer};
+    use std::collections::HashMap;
+
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn dummy_event(event_id: i64) -> EventListItemMessage {
+        EventListItemMessage {
+            event_id,
+            created_at: Utc::now(),
+            source: "source".to_string(),
+            action: "action".to_string(),
+            headers: HashMap::from([("client_type".to_string(), "CLI".to_string())]),
+            content: serde_json::json!({ "digest": "digest-123" }),
+        }
+    }
+
+    #[tokio::test]
+    async fn json_lines_file_sink_appends_one_event_per_line() {
+        let file_path = TempDir::create(
+            "event_sink",
+            "json_lines_file_sink_appends_one_event_per_line",
+        )
+        .join("events.jsonl");
+        let sink = JsonLinesFileEventSink::new(&file_path);
+        let events = vec![dummy_event(1), dummy_event(2)];
+
+        for event in &events {
+            sink.export(event).await.unwrap();
+        }
+
+        let exported_events: Vec<EventListItemMessage> = std::fs::read_to_string(&file_path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events, exported_events);
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_posts_event_as_json() {
+        let event = dummy_event(1);
+        let server = MockServer::start();
+        let webhook_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/events")
+                .json_body(serde_json::to_value(&event).unwrap());
+            then.status(200);
+        });
+        let sink = WebhookEventSink::new(&server.url("/events")).unwrap();
+
+        sink.export(&event).await.unwrap();
+
+        webhook_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_fails_when_webhook_returns_an_error() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/events");
+            then.status(500);
+        });
+        let sink = WebhookEventSink::new(&server.url("/events")).unwrap();
+
+        sink.export(&dummy_event(1))
+            .await
+            .expect_err("Export should fail when the webhook returns an error");
+    }
+}
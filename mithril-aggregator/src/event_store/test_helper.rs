@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnection};
+
+/// In-memory sqlite event store database with migrations applied
+pub fn event_store_db_connection() -> StdResult<SqliteConnection> {
+    let connection = ConnectionBuilder::open_memory()
+        .with_migrations(crate::event_store::migration::get_migrations())
+        .build()?;
+    Ok(connection)
+}
+
+/// Insert an event with the given creation date, headers and content in the event store database
+pub fn insert_event(
+    connection: &SqliteConnection,
+    created_at: DateTime<Utc>,
+    source: &str,
+    action: &str,
+    headers: &[(&str, &str)],
+    content: &str,
+) -> StdResult<()> {
+    let headers = serde_json::to_string(
+        &headers
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<std::collections::HashMap<_, _>>(),
+    )?;
+    let query = "insert into event (created_at, source, action, content) values (?, ?, ?, ?)";
+    let mut statement = connection.prepare(query)?;
+    statement.bind::<&[(_, Value)]>(&[
+        (1, created_at.to_rfc3339().into()),
+        (2, source.into()),
+        (3, action.into()),
+        (
+            4,
+            format!(r#"{{"headers": {headers}, "content": {content}}}"#).into(),
+        ),
+    ])?;
+    statement.next()?;
+
+    Ok(())
+}
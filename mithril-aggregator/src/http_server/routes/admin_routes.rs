@@ -0,0 +1,316 @@
+use std::sync::Arc;
+use warp::Filter;
+
+use crate::event_store::EventFilters;
+use crate::http_server::routes::middlewares;
+use crate::DependencyContainer;
+
+pub fn routes(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    admin_events(dependency_manager.clone())
+        .or(admin_snapshot_download_statistics(dependency_manager))
+}
+
+/// GET /admin/events
+fn admin_events(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("admin" / "events")
+        .and(warp::get())
+        .and(warp::header::optional::<String>("authorization"))
+        .and(warp::query::<EventFilters>())
+        .and(middlewares::validators::with_admin_authorization_validator(
+            dependency_manager.clone(),
+        ))
+        .and(middlewares::with_event_repository(dependency_manager))
+        .and_then(handlers::list_events)
+}
+
+/// GET /admin/statistics/snapshot-downloads
+fn admin_snapshot_download_statistics(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("admin" / "statistics" / "snapshot-downloads")
+        .and(warp::get())
+        .and(warp::header::optional::<String>("authorization"))
+        .and(middlewares::validators::with_admin_authorization_validator(
+            dependency_manager.clone(),
+        ))
+        .and(middlewares::with_event_repository(dependency_manager))
+        .and_then(handlers::snapshot_download_statistics)
+}
+
+mod handlers {
+    use slog_scope::{debug, warn};
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use warp::http::StatusCode;
+
+    use crate::entities::EventListItemMessage;
+    use crate::event_store::{EventFilters, EventRepository};
+    use crate::http_server::routes::reply;
+    use crate::http_server::validators::AdminAuthorizationValidator;
+    use crate::unwrap_to_internal_server_error;
+
+    /// List the persisted events
+    pub async fn list_events(
+        authorization: Option<String>,
+        filters: EventFilters,
+        validator: AdminAuthorizationValidator,
+        event_repository: Arc<EventRepository>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: admin/events"; "filters" => ?filters);
+
+        if let Err(error) = validator.validate(authorization.as_deref()) {
+            warn!("admin_events::unauthorized");
+            return Ok(reply::json(&error, StatusCode::UNAUTHORIZED));
+        }
+
+        if filters.header.is_some() && filters.header_name_and_value().is_none() {
+            warn!("admin_events::bad_request");
+            return Ok(reply::bad_request(
+                "invalid_header_filter".to_string(),
+                "Header filter must be formatted as 'name:value'".to_string(),
+            ));
+        }
+
+        let events = unwrap_to_internal_server_error!(
+            event_repository.get_events(&filters),
+            "admin_events::error"
+        );
+        let messages = unwrap_to_internal_server_error!(
+            events
+                .into_iter()
+                .map(EventListItemMessage::try_from)
+                .collect::<Result<Vec<_>, _>>(),
+            "admin_events::error"
+        );
+
+        Ok(reply::json(&messages, StatusCode::OK))
+    }
+
+    /// Snapshot download statistics computed from the persisted events
+    pub async fn snapshot_download_statistics(
+        authorization: Option<String>,
+        validator: AdminAuthorizationValidator,
+        event_repository: Arc<EventRepository>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: admin/statistics/snapshot-downloads");
+
+        if let Err(error) = validator.validate(authorization.as_deref()) {
+            warn!("admin_snapshot_download_statistics::unauthorized");
+            return Ok(reply::json(&error, StatusCode::UNAUTHORIZED));
+        }
+
+        let statistics = unwrap_to_internal_server_error!(
+            event_repository.get_snapshot_download_statistics(),
+            "admin_snapshot_download_statistics::error"
+        );
+
+        Ok(reply::json(&statistics, StatusCode::OK))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::Value::Null;
+    use warp::{
+        http::{Method, StatusCode},
+        test::request,
+    };
+
+    use mithril_common::test_utils::apispec::APISpec;
+
+    use crate::entities::EventListItemMessage;
+    use crate::event_store::test_helper::insert_event;
+    use crate::event_store::{
+        SnapshotDownloadStatistics, SNAPSHOT_DOWNLOADED_EVENT_ACTION,
+        SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+    };
+    use crate::{
+        dependency_injection::DependenciesBuilder, http_server::SERVER_BASE_PATH, Configuration,
+    };
+
+    use super::*;
+
+    const ADMIN_API_TOKEN: &str = "admin-api-token";
+
+    fn setup_router(
+        dependency_manager: Arc<DependencyContainer>,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_headers(vec!["content-type"])
+            .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
+
+        warp::any()
+            .and(warp::path(SERVER_BASE_PATH))
+            .and(routes(dependency_manager).with(cors))
+    }
+
+    async fn build_dependencies() -> (DependenciesBuilder, DependencyContainer) {
+        let config = Configuration {
+            admin_api_token: Some(ADMIN_API_TOKEN.to_string()),
+            ..Configuration::new_sample()
+        };
+        let mut builder = DependenciesBuilder::new(config);
+        let dependency_manager = builder.build_dependency_container().await.unwrap();
+
+        (builder, dependency_manager)
+    }
+
+    #[tokio::test]
+    async fn admin_events_ok() {
+        let (mut builder, dependency_manager) = build_dependencies().await;
+        let connection = builder.get_sqlite_connection_event_store().await.unwrap();
+        insert_event(&connection, Utc::now(), "source", "action", &[], "{}").unwrap();
+        insert_event(&connection, Utc::now(), "source", "other", &[], "{}").unwrap();
+
+        let method = Method::GET.as_str();
+        let path = "/admin/events";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}?action=action"))
+            .header("authorization", format!("Bearer {ADMIN_API_TOKEN}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let events: Vec<EventListItemMessage> = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(
+            vec![1],
+            events.iter().map(|e| e.event_id).collect::<Vec<_>>()
+        );
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn admin_events_ko_400_with_invalid_header_filter() {
+        let (_, dependency_manager) = build_dependencies().await;
+
+        let method = Method::GET.as_str();
+        let path = "/admin/events";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}?header=invalid"))
+            .header("authorization", format!("Bearer {ADMIN_API_TOKEN}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn admin_events_ko_401_without_valid_token() {
+        let (_, dependency_manager) = build_dependencies().await;
+
+        let method = Method::GET.as_str();
+        let path = "/admin/events";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .header("authorization", "Bearer invalid-token")
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::UNAUTHORIZED,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn admin_snapshot_download_statistics_ok() {
+        let (mut builder, dependency_manager) = build_dependencies().await;
+        let connection = builder.get_sqlite_connection_event_store().await.unwrap();
+        insert_event(
+            &connection,
+            Utc::now(),
+            SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+            SNAPSHOT_DOWNLOADED_EVENT_ACTION,
+            &[],
+            r#"{"digest": "digest-1"}"#,
+        )
+        .unwrap();
+
+        let method = Method::GET.as_str();
+        let path = "/admin/statistics/snapshot-downloads";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .header("authorization", format!("Bearer {ADMIN_API_TOKEN}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let statistics: SnapshotDownloadStatistics =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(1, statistics.total);
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn admin_snapshot_download_statistics_ko_401_when_admin_routes_are_disabled() {
+        let config = Configuration::new_sample();
+        let mut builder = DependenciesBuilder::new(config);
+        let dependency_manager = builder.build_dependency_container().await.unwrap();
+
+        let method = Method::GET.as_str();
+        let path = "/admin/statistics/snapshot-downloads";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .header("authorization", format!("Bearer {ADMIN_API_TOKEN}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::UNAUTHORIZED,
+        )
+        .unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::entities::ListFilters;
 use crate::http_server::routes::middlewares;
 use crate::DependencyContainer;
 use std::sync::Arc;
@@ -16,6 +17,7 @@ fn artifact_cardano_transactions(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "cardano-transactions")
         .and(warp::get())
+        .and(warp::query::<ListFilters>())
         .and(middlewares::with_http_message_service(dependency_manager))
         .and_then(handlers::list_artifacts)
 }
@@ -31,6 +33,7 @@ fn artifact_cardano_transaction_by_id(
 }
 
 pub mod handlers {
+    use crate::entities::ListFilters;
     use crate::http_server::routes::reply;
     use crate::services::MessageService;
 
@@ -39,16 +42,23 @@ pub mod handlers {
     use std::sync::Arc;
     use warp::http::StatusCode;
 
-    pub const LIST_MAX_ITEMS: usize = 20;
-
     /// List Cardano Transactions set artifacts
     pub async fn list_artifacts(
+        filters: ListFilters,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: artifacts");
 
+        if filters.signed_entity_type.is_some() {
+            warn!("list_artifacts_cardano_transactions::bad_request");
+            return Ok(reply::bad_request(
+                "invalid_filter".to_string(),
+                "Artifacts can not be filtered by signed entity type".to_string(),
+            ));
+        }
+
         match http_message_service
-            .get_cardano_transaction_list_message(LIST_MAX_ITEMS)
+            .get_cardano_transaction_list_message(&filters)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -189,6 +199,39 @@ pub mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_cardano_transactions_returns_400_bad_request_when_filtered_by_signed_entity_type()
+    {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_cardano_transaction_list_message()
+            .never();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/cardano-transactions";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "/{SERVER_BASE_PATH}{path}?signed_entity_type=MithrilStakeDistribution"
+            ))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_cardano_transaction_get_ok() {
         let signed_entity = create_signed_entities(
@@ -1,3 +1,4 @@
+use crate::entities::ListFilters;
 use crate::http_server::routes::middlewares;
 use crate::DependencyContainer;
 use std::sync::Arc;
@@ -17,6 +18,7 @@ fn artifact_mithril_stake_distributions(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "mithril-stake-distributions")
         .and(warp::get())
+        .and(warp::query::<ListFilters>())
         .and(middlewares::with_http_message_service(dependency_manager))
         .and_then(handlers::list_artifacts)
 }
@@ -32,6 +34,7 @@ fn artifact_mithril_stake_distribution_by_id(
 }
 
 pub mod handlers {
+    use crate::entities::ListFilters;
     use crate::http_server::routes::reply;
     use crate::services::MessageService;
 
@@ -40,16 +43,23 @@ pub mod handlers {
     use std::sync::Arc;
     use warp::http::StatusCode;
 
-    pub const LIST_MAX_ITEMS: usize = 20;
-
     /// List MithrilStakeDistribution artifacts
     pub async fn list_artifacts(
+        filters: ListFilters,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: artifacts");
 
+        if filters.signed_entity_type.is_some() {
+            warn!("list_artifacts_mithril_stake_distribution::bad_request");
+            return Ok(reply::bad_request(
+                "invalid_filter".to_string(),
+                "Artifacts can not be filtered by signed entity type".to_string(),
+            ));
+        }
+
         match http_message_service
-            .get_mithril_stake_distribution_list_message(LIST_MAX_ITEMS)
+            .get_mithril_stake_distribution_list_message(&filters)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -189,6 +199,39 @@ pub mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_mithril_stake_distributions_returns_400_bad_request_when_filtered_by_signed_entity_type(
+    ) {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_mithril_stake_distribution_list_message()
+            .never();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/mithril-stake-distributions";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "/{SERVER_BASE_PATH}{path}?signed_entity_type=MithrilStakeDistribution"
+            ))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_mithril_stake_distribution_get_ok() {
         let signed_entity = create_signed_entities(
@@ -1,3 +1,4 @@
+use crate::entities::ListFilters;
 use crate::http_server::routes::middlewares;
 use crate::http_server::SERVER_BASE_PATH;
 use crate::DependencyContainer;
@@ -24,6 +25,7 @@ fn artifact_cardano_full_immutable_snapshots(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "snapshots")
         .and(warp::get())
+        .and(warp::query::<ListFilters>())
         .and(middlewares::with_http_message_service(dependency_manager))
         .and_then(handlers::list_artifacts)
 }
@@ -89,6 +91,7 @@ fn artifact_cardano_full_immutable_snapshot_by_id_legacy(
 }
 
 mod handlers {
+    use crate::entities::ListFilters;
     use crate::http_server::routes::reply;
     use crate::http_server::SERVER_BASE_PATH;
     use crate::services::MessageService;
@@ -99,16 +102,23 @@ mod handlers {
     use std::sync::Arc;
     use warp::http::{StatusCode, Uri};
 
-    pub const LIST_MAX_ITEMS: usize = 20;
-
     /// List Snapshot artifacts
     pub async fn list_artifacts(
+        filters: ListFilters,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: artifacts");
 
+        if filters.signed_entity_type.is_some() {
+            warn!("list_artifacts_snapshot::bad_request");
+            return Ok(reply::bad_request(
+                "invalid_filter".to_string(),
+                "Artifacts can not be filtered by signed entity type".to_string(),
+            ));
+        }
+
         match http_message_service
-            .get_snapshot_list_message(LIST_MAX_ITEMS)
+            .get_snapshot_list_message(&filters)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -321,6 +331,38 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_snapshots_returns_400_bad_request_when_filtered_by_signed_entity_type() {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_snapshot_list_message()
+            .never();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/snapshots";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "/{SERVER_BASE_PATH}{path}?signed_entity_type=MithrilStakeDistribution"
+            ))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_snapshot_digest_get_ok() {
         let signed_entity = create_signed_entities(
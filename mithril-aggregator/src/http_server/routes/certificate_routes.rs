@@ -1,3 +1,4 @@
+use crate::entities::ListFilters;
 use crate::http_server::routes::middlewares;
 use crate::DependencyContainer;
 use std::sync::Arc;
@@ -7,6 +8,7 @@ pub fn routes(
     dependency_manager: Arc<DependencyContainer>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     certificate_pending(dependency_manager.clone())
+        .or(signing_opportunities(dependency_manager.clone()))
         .or(certificate_certificates(dependency_manager.clone()))
         .or(certificate_certificate_hash(dependency_manager))
 }
@@ -23,12 +25,23 @@ fn certificate_pending(
         .and_then(handlers::certificate_pending)
 }
 
+/// GET /signing-opportunities
+fn signing_opportunities(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("signing-opportunities")
+        .and(warp::get())
+        .and(middlewares::with_http_message_service(dependency_manager))
+        .and_then(handlers::signing_opportunities)
+}
+
 /// GET /certificates
 fn certificate_certificates(
     dependency_manager: Arc<DependencyContainer>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("certificates")
         .and(warp::get())
+        .and(warp::query::<ListFilters>())
         .and(middlewares::with_http_message_service(dependency_manager))
         .and_then(handlers::certificate_certificates)
 }
@@ -44,6 +57,7 @@ fn certificate_certificate_hash(
 }
 
 mod handlers {
+    use crate::entities::ListFilters;
     use crate::{
         http_server::routes::reply, services::MessageService, CertificatePendingStore,
         ToCertificatePendingMessageAdapter,
@@ -54,8 +68,6 @@ mod handlers {
     use std::sync::Arc;
     use warp::http::StatusCode;
 
-    pub const LIST_MAX_ITEMS: usize = 20;
-
     /// Certificate Pending
     pub async fn certificate_pending(
         certificate_pending_store: Arc<CertificatePendingStore>,
@@ -75,14 +87,33 @@ mod handlers {
         }
     }
 
+    /// List all the messages open for signatures
+    pub async fn signing_opportunities(
+        http_message_service: Arc<dyn MessageService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: signing_opportunities");
+
+        match http_message_service
+            .get_signing_opportunity_list_message()
+            .await
+        {
+            Ok(signing_opportunities) => Ok(reply::json(&signing_opportunities, StatusCode::OK)),
+            Err(err) => {
+                warn!("signing_opportunities::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
+
     /// List all Certificates
     pub async fn certificate_certificates(
+        filters: ListFilters,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: certificate_certificates",);
 
         match http_message_service
-            .get_certificate_list_message(LIST_MAX_ITEMS)
+            .get_certificate_list_message(&filters)
             .await
         {
             Ok(certificates) => Ok(reply::json(&certificates, StatusCode::OK)),
@@ -121,7 +152,8 @@ mod handlers {
 mod tests {
     use anyhow::anyhow;
     use mithril_common::{
-        entities::CertificatePending,
+        entities::{CertificatePending, Epoch, SignedEntityTypeDiscriminants},
+        messages::SigningOpportunityListItemMessage,
         test_utils::{apispec::APISpec, fake_data},
     };
     use mithril_persistence::store::adapter::DumbStoreAdapter;
@@ -240,6 +272,67 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_signing_opportunities_get_ok() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_signing_opportunity_list_message()
+            .return_once(|| Ok(vec![SigningOpportunityListItemMessage::dummy()]))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/signing-opportunities";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_signing_opportunities_when_error_returns_ko_500() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_signing_opportunity_list_message()
+            .returning(|| Err(anyhow!("an error")));
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/signing-opportunities";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_certificate_certificates_get_ok() {
         let dependency_manager = initialize_dependencies().await;
@@ -270,6 +363,39 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_certificate_certificates_forwards_query_filters_to_message_service() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_list_message()
+            .withf(|filters| {
+                *filters
+                    == ListFilters {
+                        limit: Some(5),
+                        offset: Some(10),
+                        epoch_from: Some(Epoch(3)),
+                        epoch_to: None,
+                        signed_entity_type: Some(
+                            SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                        ),
+                    }
+            })
+            .return_once(|_| Ok(vec![]))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path(&format!(
+                "/{SERVER_BASE_PATH}/certificates?limit=5&offset=10&epoch_from=3&signed_entity_type=MithrilStakeDistribution"
+            ))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
     #[tokio::test]
     async fn test_certificate_when_error_retrieving_certificates_returns_ko_500() {
         let mut dependency_manager = initialize_dependencies().await;
@@ -0,0 +1,175 @@
+use crate::http_server::routes::middlewares;
+use crate::DependencyContainer;
+use std::sync::Arc;
+use warp::Filter;
+
+pub fn routes(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    events(dependency_manager)
+}
+
+/// GET /events
+fn events(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("events")
+        .and(warp::get())
+        .and(middlewares::with_aggregator_event_broadcaster(
+            dependency_manager,
+        ))
+        .and_then(handlers::events)
+}
+
+mod handlers {
+    use futures::Stream;
+    use slog_scope::{debug, warn};
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use tokio::sync::broadcast::{error::RecvError, Receiver};
+    use warp::sse::Event;
+
+    use mithril_common::messages::AggregatorEventMessage;
+
+    use crate::services::AggregatorEventBroadcaster;
+
+    /// Events
+    pub async fn events(
+        aggregator_event_broadcaster: Arc<AggregatorEventBroadcaster>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: events");
+        let stream = event_stream(aggregator_event_broadcaster.subscribe());
+
+        Ok(warp::sse::reply(warp::sse::keep_alive().stream(stream)))
+    }
+
+    /// Convert the events received from the broadcaster into server-sent events.
+    ///
+    /// The events missed because the subscriber lagged behind are skipped.
+    pub fn event_stream(
+        receiver: Receiver<AggregatorEventMessage>,
+    ) -> impl Stream<Item = Result<Event, Infallible>> + Send {
+        futures::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(message) => match Event::default()
+                        .event(message.event_name())
+                        .json_data(&message)
+                    {
+                        Ok(event) => return Some((Ok(event), receiver)),
+                        Err(err) => {
+                            warn!("events::serialization_error"; "error" => ?err);
+                        }
+                    },
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("events::subscriber lagged behind, {skipped} event(s) skipped");
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use warp::http::{Method, StatusCode};
+    use warp::hyper::body::HttpBody;
+    use warp::test::request;
+    use warp::Reply;
+
+    use mithril_common::{entities::Epoch, messages::AggregatorEventMessage};
+
+    use crate::http_server::SERVER_BASE_PATH;
+    use crate::initialize_dependencies;
+    use crate::services::AggregatorEventBroadcaster;
+
+    use super::*;
+
+    fn setup_router(
+        dependency_manager: Arc<DependencyContainer>,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_headers(vec!["content-type"])
+            .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
+
+        warp::any()
+            .and(warp::path(SERVER_BASE_PATH))
+            .and(routes(dependency_manager).with(cors))
+    }
+
+    #[tokio::test]
+    async fn test_events_get_ok() {
+        let dependency_manager = Arc::new(initialize_dependencies().await);
+        let message = AggregatorEventMessage::EpochChanged { epoch: Epoch(7) };
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/events"))
+            .filter(&setup_router(dependency_manager.clone()))
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "text/event-stream",
+            response.headers()["content-type"].to_str().unwrap()
+        );
+
+        dependency_manager
+            .aggregator_event_broadcaster
+            .broadcast(message.clone());
+        let mut body = response.into_body();
+        let first_event = body.data().await.unwrap().unwrap();
+
+        assert_eq!(
+            format!(
+                "event:epoch_changed\ndata:{}\n\n",
+                serde_json::to_string(&message).unwrap()
+            ),
+            String::from_utf8(first_event.to_vec()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn event_stream_yields_broadcast_events_as_server_sent_events() {
+        let broadcaster = AggregatorEventBroadcaster::default();
+        let stream = handlers::event_stream(broadcaster.subscribe());
+        let message = AggregatorEventMessage::EpochChanged { epoch: Epoch(7) };
+
+        broadcaster.broadcast(message.clone());
+        drop(broadcaster);
+        let events: Vec<String> = stream
+            .map(|event| event.unwrap().to_string())
+            .collect()
+            .await;
+
+        assert_eq!(
+            vec![format!(
+                "event:epoch_changed\ndata:{}\n\n",
+                serde_json::to_string(&message).unwrap()
+            )],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn event_stream_skips_events_missed_by_a_lagging_subscriber() {
+        let broadcaster = AggregatorEventBroadcaster::new(1);
+        let stream = handlers::event_stream(broadcaster.subscribe());
+
+        broadcaster.broadcast(AggregatorEventMessage::EpochChanged { epoch: Epoch(7) });
+        broadcaster.broadcast(AggregatorEventMessage::EpochChanged { epoch: Epoch(8) });
+        drop(broadcaster);
+        let events: Vec<String> = stream
+            .map(|event| event.unwrap().to_string())
+            .collect()
+            .await;
+
+        assert_eq!(1, events.len());
+        assert!(events[0].contains(r#""epoch":8"#));
+    }
+}
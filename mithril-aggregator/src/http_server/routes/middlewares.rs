@@ -8,10 +8,13 @@ use mithril_common::{api_version::APIVersionProvider, TickerService};
 
 use crate::database::repository::SignerGetter;
 use crate::dependency_injection::EpochServiceWrapper;
-use crate::event_store::{EventMessage, TransmitterService};
-use crate::services::{CertifierService, MessageService, ProverService, SignedEntityService};
+use crate::event_store::{EventMessage, EventRepository, TransmitterService};
+use crate::services::{
+    AggregatorEventBroadcaster, CertifierService, MessageService, ProverService,
+    SignedEntityService,
+};
 use crate::{
-    CertificatePendingStore, Configuration, DependencyContainer, SignerRegisterer,
+    CertificatePendingStore, Configuration, DependencyContainer, MetricsService, SignerRegisterer,
     VerificationKeyStorer,
 };
 
@@ -57,6 +60,13 @@ pub fn with_event_transmitter(
     warp::any().map(move || dependency_manager.event_transmitter.clone())
 }
 
+/// With aggregator event broadcaster middleware
+pub fn with_aggregator_event_broadcaster(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (Arc<AggregatorEventBroadcaster>,), Error = Infallible> + Clone {
+    warp::any().map(move || dependency_manager.aggregator_event_broadcaster.clone())
+}
+
 /// With certifier service middleware
 pub fn with_certifier_service(
     dependency_manager: Arc<DependencyContainer>,
@@ -113,8 +123,24 @@ pub fn with_prover_service(
     warp::any().map(move || dependency_manager.prover_service.clone())
 }
 
+/// With Metrics service
+pub fn with_metrics_service(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (Arc<MetricsService>,), Error = Infallible> + Clone {
+    warp::any().map(move || dependency_manager.metrics_service.clone())
+}
+
+/// With Event repository
+pub fn with_event_repository(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (Arc<EventRepository>,), Error = Infallible> + Clone {
+    warp::any().map(move || dependency_manager.event_repository.clone())
+}
+
 pub mod validators {
-    use crate::http_server::validators::ProverTransactionsHashValidator;
+    use crate::http_server::validators::{
+        AdminAuthorizationValidator, ProverTransactionsHashValidator,
+    };
 
     use super::*;
 
@@ -128,4 +154,13 @@ pub mod validators {
 
         warp::any().map(move || ProverTransactionsHashValidator::new(max_hashes))
     }
+
+    /// With Admin Authorization Validator
+    pub fn with_admin_authorization_validator(
+        dependency_manager: Arc<DependencyContainer>,
+    ) -> impl Filter<Extract = (AdminAuthorizationValidator,), Error = Infallible> + Clone {
+        let admin_api_token = dependency_manager.config.admin_api_token.clone();
+
+        warp::any().map(move || AdminAuthorizationValidator::new(admin_api_token.clone()))
+    }
 }
@@ -1,6 +1,8 @@
+mod admin_routes;
 mod artifact_routes;
 mod certificate_routes;
 mod epoch_routes;
+mod events_routes;
 mod middlewares;
 mod proof_routes;
 pub(crate) mod reply;
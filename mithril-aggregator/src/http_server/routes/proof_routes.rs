@@ -47,7 +47,8 @@ fn proof_cardano_transaction(
                 dependency_manager.clone(),
             ),
         )
-        .and(middlewares::with_prover_service(dependency_manager))
+        .and(middlewares::with_prover_service(dependency_manager.clone()))
+        .and(middlewares::with_metrics_service(dependency_manager))
         .and_then(handlers::proof_cardano_transaction)
 }
 
@@ -58,14 +59,14 @@ mod handlers {
         StdResult,
     };
     use slog_scope::{debug, warn};
-    use std::{convert::Infallible, sync::Arc};
+    use std::{convert::Infallible, sync::Arc, time::Instant};
     use warp::http::StatusCode;
 
     use crate::{
         http_server::{routes::reply, validators::ProverTransactionsHashValidator},
         message_adapters::ToCardanoTransactionsProofsMessageAdapter,
         services::{ProverService, SignedEntityService},
-        unwrap_to_internal_server_error,
+        unwrap_to_internal_server_error, MetricsService,
     };
 
     use super::CardanoTransactionProofQueryParams;
@@ -75,6 +76,7 @@ mod handlers {
         signed_entity_service: Arc<dyn SignedEntityService>,
         validator: ProverTransactionsHashValidator,
         prover_service: Arc<dyn ProverService>,
+        metrics_service: Arc<MetricsService>,
     ) -> Result<impl warp::Reply, Infallible> {
         let transaction_hashes = transaction_parameters.split_transactions_hashes();
         debug!(
@@ -96,10 +98,14 @@ mod handlers {
             "proof_cardano_transaction::error"
         ) {
             Some(signed_entity) => {
+                let prover_request_start = Instant::now();
                 let message = unwrap_to_internal_server_error!(
                     build_response_message(prover_service, signed_entity, sanitized_hashes).await,
                     "proof_cardano_transaction"
                 );
+                metrics_service.prover_request_duration_seconds_histogram_observe(
+                    prover_request_start.elapsed(),
+                );
                 Ok(reply::json(&message, StatusCode::OK))
             }
             None => {
@@ -214,6 +220,7 @@ mod tests {
             .expect_compute_transactions_proofs()
             .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
         dependency_manager.prover_service = Arc::new(mock_prover_service);
+        let metrics_service = dependency_manager.metrics_service.clone();
 
         let method = Method::GET.as_str();
         let path = "/proof/cardano-transaction";
@@ -238,6 +245,10 @@ mod tests {
             &StatusCode::OK,
         )
         .unwrap();
+        assert_eq!(
+            1,
+            metrics_service.prover_request_duration_seconds_histogram_count_get()
+        );
     }
 
     #[tokio::test]
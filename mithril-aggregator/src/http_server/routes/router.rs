@@ -1,6 +1,6 @@
 use crate::http_server::routes::{
-    artifact_routes, certificate_routes, epoch_routes, root_routes, signatures_routes,
-    signer_routes, statistics_routes,
+    admin_routes, artifact_routes, certificate_routes, epoch_routes, events_routes, root_routes,
+    signatures_routes, signer_routes, statistics_routes,
 };
 use crate::http_server::SERVER_BASE_PATH;
 use crate::DependencyContainer;
@@ -60,6 +60,8 @@ pub fn routes(
                 .or(signatures_routes::routes(dependency_manager.clone()))
                 .or(epoch_routes::routes(dependency_manager.clone()))
                 .or(statistics_routes::routes(dependency_manager.clone()))
+                .or(events_routes::routes(dependency_manager.clone()))
+                .or(admin_routes::routes(dependency_manager.clone()))
                 .or(root_routes::routes(dependency_manager.clone()))
                 .with(cors),
         )
@@ -19,6 +19,7 @@ fn register_signatures(
         .and(middlewares::with_certifier_service(
             dependency_manager.clone(),
         ))
+        .and(middlewares::with_metrics_service(dependency_manager))
         .and_then(handlers::register_signatures)
 }
 
@@ -34,12 +35,14 @@ mod handlers {
         http_server::routes::reply,
         message_adapters::FromRegisterSingleSignatureAdapter,
         services::{CertifierService, CertifierServiceError},
+        MetricsService,
     };
 
     /// Register Signatures
     pub async fn register_signatures(
         message: RegisterSignatureMessage,
         certifier_service: Arc<dyn CertifierService>,
+        metrics_service: Arc<MetricsService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: register_signatures/{:?}", message);
         trace!("⇄ HTTP SERVER: register_signatures"; "complete_message" => #?message );
@@ -76,7 +79,12 @@ mod handlers {
                     Ok(reply::server_error(err))
                 }
             },
-            Ok(()) => Ok(reply::empty(StatusCode::CREATED)),
+            Ok(()) => {
+                metrics_service.single_signature_received_since_startup_counter_increment(
+                    (&signed_entity_type).into(),
+                );
+                Ok(reply::empty(StatusCode::CREATED))
+            }
         }
     }
 }
@@ -146,6 +154,33 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_register_signatures_post_increments_signature_received_metric() {
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_register_single_signature()
+            .return_once(move |_, _| Ok(()));
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+        let metrics_service = dependency_manager.metrics_service.clone();
+
+        let message = RegisterSignatureMessage::dummy();
+        let signed_entity_type = message.signed_entity_type.clone();
+
+        request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures"))
+            .json(&message)
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(
+            1,
+            metrics_service
+                .single_signature_received_since_startup_counter_get((&signed_entity_type).into())
+        );
+    }
+
     #[tokio::test]
     async fn test_register_signatures_post_ko_400() {
         let mut mock_certifier_service = MockCertifierService::new();
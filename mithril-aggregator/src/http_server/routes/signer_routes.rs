@@ -12,7 +12,8 @@ pub fn routes(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     register_signer(dependency_manager.clone())
         .or(registered_signers(dependency_manager.clone()))
-        .or(signers_tickers(dependency_manager))
+        .or(signers_tickers(dependency_manager.clone()))
+        .or(signer_participation(dependency_manager))
 }
 
 /// POST /register-signer
@@ -31,7 +32,8 @@ fn register_signer(
         .and(middlewares::with_event_transmitter(
             dependency_manager.clone(),
         ))
-        .and(middlewares::with_ticker_service(dependency_manager))
+        .and(middlewares::with_ticker_service(dependency_manager.clone()))
+        .and(middlewares::with_metrics_service(dependency_manager))
         .and_then(handlers::register_signer)
 }
 
@@ -56,14 +58,26 @@ fn registered_signers(
         .and_then(handlers::registered_signers)
 }
 
+/// Get /signers/:party_id/participation
+fn signer_participation(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("signers" / String / "participation")
+        .and(warp::get())
+        .and(middlewares::with_http_message_service(dependency_manager))
+        .and_then(handlers::signer_participation)
+}
+
 mod handlers {
     use crate::database::repository::SignerGetter;
     use crate::entities::{
         SignerRegistrationsMessage, SignerTickerListItemMessage, SignersTickersMessage,
     };
     use crate::event_store::{EventMessage, TransmitterService};
+    use crate::services::MessageService;
     use crate::{
-        http_server::routes::reply, Configuration, SignerRegisterer, SignerRegistrationError,
+        http_server::routes::reply, Configuration, MetricsService, SignerRegisterer,
+        SignerRegistrationError,
     };
     use crate::{FromRegisterSignerAdapter, VerificationKeyStorer};
     use mithril_common::entities::Epoch;
@@ -81,6 +95,7 @@ mod handlers {
         signer_registerer: Arc<dyn SignerRegisterer>,
         event_transmitter: Arc<TransmitterService<EventMessage>>,
         ticker_service: Arc<dyn TickerService>,
+        metrics_service: Arc<MetricsService>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!(
             "⇄ HTTP SERVER: register_signer/{:?}",
@@ -90,6 +105,7 @@ mod handlers {
             "⇄ HTTP SERVER: register_signer";
             "complete_message" => #?register_signer_message
         );
+        metrics_service.signer_registration_total_since_startup_counter_increment();
 
         let registration_epoch = match register_signer_message.epoch {
             Some(epoch) => epoch,
@@ -135,6 +151,7 @@ mod handlers {
             .await
         {
             Ok(signer_with_stake) => {
+                metrics_service.signer_registration_success_since_startup_counter_increment();
                 let _ = event_transmitter.send_event_message(
                     "HTTP::signer_register",
                     "register_signer",
@@ -146,6 +163,7 @@ mod handlers {
             }
             Err(SignerRegistrationError::ExistingSigner(signer_with_stake)) => {
                 debug!("register_signer::already_registered");
+                metrics_service.signer_registration_success_since_startup_counter_increment();
                 let _ = event_transmitter.send_event_message(
                     "HTTP::signer_register",
                     "register_signer",
@@ -241,6 +259,25 @@ mod handlers {
             }
         }
     }
+
+    /// Get the participation of a signer in the certificates production
+    pub async fn signer_participation(
+        party_id: String,
+        http_message_service: Arc<dyn MessageService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: signers/{party_id}/participation");
+
+        match http_message_service
+            .get_signer_participation_message(&party_id)
+            .await
+        {
+            Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
+            Err(err) => {
+                warn!("signer_participation::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -256,7 +293,7 @@ mod tests {
     use mithril_common::entities::Epoch;
     use mithril_common::{
         crypto_helper::ProtocolRegistrationError,
-        messages::RegisterSignerMessage,
+        messages::{RegisterSignerMessage, SignerParticipationMessage},
         test_utils::{apispec::APISpec, fake_data},
     };
     use mithril_persistence::store::adapter::AdapterError;
@@ -265,6 +302,7 @@ mod tests {
         database::{record::SignerRecord, repository::MockSignerGetter},
         http_server::SERVER_BASE_PATH,
         initialize_dependencies,
+        services::MockMessageService,
         signer_registerer::MockSignerRegisterer,
         store::MockVerificationKeyStorer,
         SignerRegistrationError,
@@ -363,6 +401,48 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_register_signer_post_increments_registration_metrics() {
+        let mut mock_signer_registerer = MockSignerRegisterer::new();
+        mock_signer_registerer
+            .expect_register_signer()
+            .return_once(|_, _| Ok(fake_data::signers_with_stakes(1).pop().unwrap()))
+            .once();
+        mock_signer_registerer
+            .expect_register_signer()
+            .return_once(|_, _| {
+                Err(SignerRegistrationError::FailedSignerRegistration(anyhow!(
+                    ProtocolRegistrationError::OpCertInvalid
+                )))
+            })
+            .once();
+        mock_signer_registerer
+            .expect_get_current_round()
+            .returning(|| None);
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.signer_registerer = Arc::new(mock_signer_registerer);
+        let metrics_service = dependency_manager.metrics_service.clone();
+        let router = setup_router(Arc::new(dependency_manager));
+
+        for _ in 0..2 {
+            request()
+                .method(Method::POST.as_str())
+                .path(&format!("/{SERVER_BASE_PATH}/register-signer"))
+                .json(&RegisterSignerMessage::dummy())
+                .reply(&router)
+                .await;
+        }
+
+        assert_eq!(
+            2,
+            metrics_service.signer_registration_total_since_startup_counter_get()
+        );
+        assert_eq!(
+            1,
+            metrics_service.signer_registration_success_since_startup_counter_get()
+        );
+    }
+
     #[tokio::test]
     async fn test_register_signer_post_ko_400() {
         let mut mock_signer_registerer = MockSignerRegisterer::new();
@@ -675,4 +755,66 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_signer_participation_get_ok() {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_signer_participation_message()
+            .return_once(|_| Ok(SignerParticipationMessage::dummy()))
+            .once();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/signers/{party_id}/participation";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_signer_participation_get_ko() {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_signer_participation_message()
+            .return_once(|_| Err(anyhow!("an error")))
+            .once();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/signers/{party_id}/participation";
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
 }
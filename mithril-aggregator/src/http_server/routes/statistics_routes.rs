@@ -1,6 +1,8 @@
 use std::sync::Arc;
 use warp::Filter;
 
+use mithril_common::MITHRIL_CLIENT_TYPE_HEADER;
+
 use crate::http_server::routes::middlewares;
 use crate::DependencyContainer;
 
@@ -16,6 +18,7 @@ fn post_statistics(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("statistics" / "snapshot")
         .and(warp::post())
+        .and(warp::header::optional::<String>(MITHRIL_CLIENT_TYPE_HEADER))
         .and(warp::body::json())
         .and(middlewares::with_event_transmitter(
             dependency_manager.clone(),
@@ -29,18 +32,25 @@ mod handlers {
     use mithril_common::messages::SnapshotDownloadMessage;
     use warp::http::StatusCode;
 
-    use crate::event_store::{EventMessage, TransmitterService};
+    use crate::event_store::{
+        EventMessage, TransmitterService, CLIENT_TYPE_EVENT_HEADER,
+        SNAPSHOT_DOWNLOADED_EVENT_ACTION, SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+    };
     use crate::http_server::routes::reply;
 
     pub async fn post_snapshot_statistics(
+        client_type: Option<String>,
         snapshot_download_message: SnapshotDownloadMessage,
         event_transmitter: Arc<TransmitterService<EventMessage>>,
     ) -> Result<impl warp::Reply, Infallible> {
-        let headers: Vec<(&str, &str)> = Vec::new();
+        let headers: Vec<(&str, &str)> = match client_type.as_deref() {
+            Some(client_type) => vec![(CLIENT_TYPE_EVENT_HEADER, client_type)],
+            None => Vec::new(),
+        };
 
         match event_transmitter.send_event_message(
-            "HTTP::statistics",
-            "snapshot_downloaded",
+            SNAPSHOT_DOWNLOADED_EVENT_SOURCE,
+            SNAPSHOT_DOWNLOADED_EVENT_ACTION,
             &snapshot_download_message,
             headers,
         ) {
@@ -63,7 +73,8 @@ mod tests {
     };
 
     use crate::{
-        dependency_injection::DependenciesBuilder, http_server::SERVER_BASE_PATH, Configuration,
+        dependency_injection::DependenciesBuilder, event_store::CLIENT_TYPE_EVENT_HEADER,
+        http_server::SERVER_BASE_PATH, Configuration,
     };
 
     fn setup_router(
@@ -110,4 +121,26 @@ mod tests {
         let _ = rx.try_recv().unwrap();
         result.unwrap();
     }
+
+    #[tokio::test]
+    async fn post_statistics_forwards_client_type_header_to_event() {
+        let config = Configuration::new_sample();
+        let mut builder = DependenciesBuilder::new(config);
+        let mut rx = builder.get_event_transmitter_receiver().await.unwrap();
+        let dependency_manager = builder.build_dependency_container().await.unwrap();
+
+        request()
+            .method(Method::POST.as_str())
+            .json(&SnapshotDownloadMessage::dummy())
+            .header(MITHRIL_CLIENT_TYPE_HEADER, "CLI")
+            .path(&format!("/{SERVER_BASE_PATH}/statistics/snapshot"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let event = rx.try_recv().unwrap();
+        assert_eq!(
+            Some(&"CLI".to_string()),
+            event.headers.get(CLIENT_TYPE_EVENT_HEADER)
+        );
+    }
 }
@@ -0,0 +1,93 @@
+use subtle::ConstantTimeEq;
+
+use mithril_common::entities::ClientError;
+
+/// Check the authorization of the requests to the admin routes.
+pub struct AdminAuthorizationValidator {
+    admin_api_token: Option<String>,
+}
+
+impl AdminAuthorizationValidator {
+    const LABEL: &'static str = "unauthorized";
+
+    pub fn new(admin_api_token: Option<String>) -> Self {
+        Self { admin_api_token }
+    }
+
+    /// Validate the value of the `Authorization` header of a request, it must be a bearer
+    /// token matching the configured admin API token.
+    ///
+    /// The tokens are compared in constant time to not leak the configured token through
+    /// the response time.
+    pub fn validate(&self, authorization_header: Option<&str>) -> Result<(), ClientError> {
+        let admin_api_token = match &self.admin_api_token {
+            Some(token) if !token.is_empty() => token,
+            _ => {
+                return Err(ClientError::new(
+                    Self::LABEL,
+                    "Admin routes are disabled on this aggregator",
+                ))
+            }
+        };
+
+        match authorization_header.and_then(|header| header.strip_prefix("Bearer ")) {
+            Some(token) if bool::from(token.as_bytes().ct_eq(admin_api_token.as_bytes())) => Ok(()),
+            Some(_) => Err(ClientError::new(Self::LABEL, "Invalid admin API token")),
+            None => Err(ClientError::new(
+                Self::LABEL,
+                "Missing admin API bearer token in the 'Authorization' header",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_all_requests_if_no_token_is_configured() {
+        let validator = AdminAuthorizationValidator::new(None);
+        validator
+            .validate(Some("Bearer any-token"))
+            .expect_err("Should reject all requests without a configured token");
+
+        let validator = AdminAuthorizationValidator::new(Some(String::new()));
+        validator
+            .validate(Some("Bearer "))
+            .expect_err("Should reject all requests with an empty configured token");
+    }
+
+    #[test]
+    fn reject_requests_without_bearer_token() {
+        let validator = AdminAuthorizationValidator::new(Some("token".to_string()));
+        validator
+            .validate(None)
+            .expect_err("Should reject requests without authorization header");
+        validator
+            .validate(Some("Basic token"))
+            .expect_err("Should reject requests without a bearer token");
+    }
+
+    #[test]
+    fn reject_requests_with_invalid_token() {
+        let validator = AdminAuthorizationValidator::new(Some("token".to_string()));
+        validator
+            .validate(Some("Bearer invalid"))
+            .expect_err("Should reject requests with an invalid token");
+        validator
+            .validate(Some("Bearer toke"))
+            .expect_err("Should reject requests with a prefix of the token");
+        validator
+            .validate(Some("Bearer token-and-more"))
+            .expect_err("Should reject requests with a token starting with the token");
+    }
+
+    #[test]
+    fn accept_requests_with_valid_token() {
+        let validator = AdminAuthorizationValidator::new(Some("token".to_string()));
+        validator
+            .validate(Some("Bearer token"))
+            .expect("Should accept requests with the configured token");
+    }
+}
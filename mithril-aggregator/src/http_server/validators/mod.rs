@@ -1,3 +1,5 @@
+mod admin_authorization_validator;
 mod prover_transactions_hash_validator;
 
+pub use admin_authorization_validator::*;
 pub use prover_transactions_hash_validator::*;
@@ -20,6 +20,7 @@ pub mod entities;
 pub mod event_store;
 mod http_server;
 mod message_adapters;
+pub mod metrics;
 mod multi_signer;
 mod runtime;
 pub mod services;
@@ -40,6 +41,7 @@ pub use dependency_injection::DependencyContainer;
 pub use message_adapters::{
     FromRegisterSignerAdapter, ToCertificatePendingMessageAdapter, ToEpochSettingsMessageAdapter,
 };
+pub use metrics::*;
 pub use runtime::{
     AggregatorConfig, AggregatorRunner, AggregatorRunnerTrait, AggregatorRuntime, RuntimeError,
 };
@@ -48,7 +50,8 @@ pub use signer_registerer::{
     SignerRegistrationRound, SignerRegistrationRoundOpener,
 };
 pub use snapshot_uploaders::{
-    DumbSnapshotUploader, LocalSnapshotUploader, RemoteSnapshotUploader, SnapshotUploader,
+    DumbSnapshotUploader, LocalSnapshotUploader, RemoteSnapshotUploader, S3SnapshotUploader,
+    SnapshotUploader,
 };
 pub use snapshotter::{
     CompressedArchiveSnapshotter, DumbSnapshotter, SnapshotError, Snapshotter,
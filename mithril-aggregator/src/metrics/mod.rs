@@ -0,0 +1,78 @@
+//! metrics module.
+//! This module contains the aggregator metrics service and metrics server.
+
+mod server;
+mod service;
+
+pub use server::MetricsServer;
+pub use service::MetricsService;
+
+/// 'signer_registration_total_since_startup' metric name
+pub const SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_aggregator_signer_registration_total_since_startup";
+/// 'signer_registration_total_since_startup' metric help
+pub const SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of signer registrations received since startup on a Mithril aggregator node";
+
+/// 'signer_registration_success_since_startup' metric name
+pub const SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_aggregator_signer_registration_success_since_startup";
+/// 'signer_registration_success_since_startup' metric help
+pub const SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of successful signer registrations since startup on a Mithril aggregator node";
+
+/// 'single_signature_received_since_startup' metric name
+pub const SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_aggregator_single_signature_received_since_startup";
+/// 'single_signature_received_since_startup' metric help
+pub const SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of single signatures registered per signed entity type since startup on a Mithril aggregator node";
+
+/// 'single_signatures_per_open_message' metric name
+pub const SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_NAME: &str =
+    "mithril_aggregator_single_signatures_per_open_message";
+/// 'single_signatures_per_open_message' metric help
+pub const SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_HELP: &str =
+    "Number of single signatures registered for an open message once it is certified or expired on a Mithril aggregator node";
+
+/// 'certificate_created_since_startup' metric name
+pub const CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_aggregator_certificate_created_since_startup";
+/// 'certificate_created_since_startup' metric help
+pub const CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of certificates created per signed entity type since startup on a Mithril aggregator node";
+
+/// 'artifact_build_duration_seconds' metric name
+pub const ARTIFACT_BUILD_DURATION_SECONDS_METRIC_NAME: &str =
+    "mithril_aggregator_artifact_build_duration_seconds";
+/// 'artifact_build_duration_seconds' metric help
+pub const ARTIFACT_BUILD_DURATION_SECONDS_METRIC_HELP: &str =
+    "Duration in seconds of the artifacts builds per signed entity type on a Mithril aggregator node";
+
+/// 'snapshot_archive_size_bytes' metric name
+pub const SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_NAME: &str =
+    "mithril_aggregator_snapshot_archive_size_bytes";
+/// 'snapshot_archive_size_bytes' metric help
+pub const SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_HELP: &str =
+    "Size in bytes of the latest snapshot archive created on a Mithril aggregator node";
+
+/// 'snapshot_upload_duration_seconds' metric name
+pub const SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_NAME: &str =
+    "mithril_aggregator_snapshot_upload_duration_seconds";
+/// 'snapshot_upload_duration_seconds' metric help
+pub const SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_HELP: &str =
+    "Duration in seconds of the snapshot archives uploads on a Mithril aggregator node";
+
+/// 'prover_request_duration_seconds' metric name
+pub const PROVER_REQUEST_DURATION_SECONDS_METRIC_NAME: &str =
+    "mithril_aggregator_prover_request_duration_seconds";
+/// 'prover_request_duration_seconds' metric help
+pub const PROVER_REQUEST_DURATION_SECONDS_METRIC_HELP: &str =
+    "Duration in seconds of the Cardano transactions prover requests on a Mithril aggregator node";
+
+/// 'runtime_state_transition_since_startup' metric name
+pub const RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_aggregator_runtime_state_transition_since_startup";
+/// 'runtime_state_transition_since_startup' metric help
+pub const RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of runtime state transitions since startup on a Mithril aggregator node";
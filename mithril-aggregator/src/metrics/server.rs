@@ -0,0 +1,114 @@
+use std::convert::Infallible;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use anyhow::Context;
+use mithril_common::StdResult;
+use slog_scope::{info, warn};
+use tokio::sync::oneshot::Receiver;
+use warp::http::StatusCode;
+use warp::Filter;
+
+use crate::MetricsService;
+
+/// The MetricsServer is responsible for exposing the metrics of the aggregator.
+pub struct MetricsServer {
+    server_port: u16,
+    server_ip: String,
+    metrics_service: Arc<MetricsService>,
+}
+
+impl MetricsServer {
+    /// Create a new MetricsServer instance.
+    pub fn new(server_ip: &str, server_port: u16, metrics_service: Arc<MetricsService>) -> Self {
+        Self {
+            server_port,
+            server_ip: server_ip.to_string(),
+            metrics_service,
+        }
+    }
+
+    /// Metrics server endpoint.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}:{}", self.server_ip, self.server_port)
+    }
+
+    /// Serve the metrics on a HTTP server.
+    pub async fn start(&self, shutdown_rx: Receiver<()>) -> StdResult<()> {
+        info!(
+            "MetricsServer: starting HTTP server for metrics on port {}",
+            self.server_port
+        );
+        let server_ip = self
+            .server_ip
+            .parse::<IpAddr>()
+            .with_context(|| format!("Invalid metrics server IP: '{}'", self.server_ip))?;
+        let metrics_service = self.metrics_service.clone();
+        let routes = warp::path!("metrics")
+            .and(warp::get())
+            .and(warp::any().map(move || metrics_service.clone()))
+            .and_then(export_metrics);
+
+        let (_, server) = warp::serve(routes).try_bind_with_graceful_shutdown(
+            (server_ip, self.server_port),
+            async {
+                shutdown_rx.await.ok();
+                warn!("MetricsServer: shutting down HTTP server after receiving signal");
+            },
+        )?;
+        server.await;
+
+        Ok(())
+    }
+}
+
+async fn export_metrics(
+    metrics_service: Arc<MetricsService>,
+) -> Result<impl warp::Reply, Infallible> {
+    match metrics_service.export_metrics() {
+        Ok(metrics) => Ok(warp::reply::with_status(metrics, StatusCode::OK)),
+        Err(err) => {
+            warn!("MetricsServer: could not export metrics"; "error" => ?err);
+            Ok(warp::reply::with_status(
+                format!("Error: {err:?}"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use std::time::Duration;
+    use tokio::{sync::oneshot, task::yield_now, time::sleep};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metrics_server() {
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel();
+        let metrics_service = Arc::new(MetricsService::new().unwrap());
+        let metrics_server = Arc::new(MetricsServer::new("127.0.0.1", 9091, metrics_service));
+        let metrics_server_endpoint = metrics_server.endpoint();
+
+        let exported_metrics_test = tokio::spawn(async move {
+            // Yield to make sure the web server starts first.
+            yield_now().await;
+
+            let response = reqwest::get(format!("{metrics_server_endpoint}/metrics"))
+                .await
+                .unwrap();
+
+            assert_eq!(reqwest::StatusCode::OK, response.status());
+            assert_ne!("", response.text().await.unwrap());
+        });
+
+        tokio::select!(
+            res =  metrics_server.start(shutdown_rx)  => Err(anyhow!("Metrics server exited with value '{res:?}'")),
+            _res = sleep(Duration::from_secs(1)) => Err(anyhow!("Timeout: The test should have already completed.")),
+            res = exported_metrics_test => res.map_err(|e| e.into()),
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,610 @@
+use std::time::Duration;
+
+use mithril_common::{entities::SignedEntityTypeDiscriminants, StdResult};
+use prometheus::{
+    exponential_buckets, Counter, CounterVec, Encoder, Gauge, Histogram, HistogramOpts,
+    HistogramVec, Opts, Registry, TextEncoder,
+};
+use slog_scope::debug;
+
+use super::{
+    ARTIFACT_BUILD_DURATION_SECONDS_METRIC_HELP, ARTIFACT_BUILD_DURATION_SECONDS_METRIC_NAME,
+    CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_HELP, CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_NAME,
+    PROVER_REQUEST_DURATION_SECONDS_METRIC_HELP, PROVER_REQUEST_DURATION_SECONDS_METRIC_NAME,
+    RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_HELP,
+    RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_NAME,
+    SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_HELP,
+    SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_NAME,
+    SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_HELP,
+    SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME,
+    SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_HELP, SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_NAME,
+    SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_HELP,
+    SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_NAME, SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_HELP,
+    SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_NAME, SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_HELP,
+    SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_NAME,
+};
+
+/// Type alias for a metric name.
+pub type MetricName = str;
+
+/// Type alias for a counter value.
+type CounterValue = u32;
+
+/// Type alias for the number of samples observed by a histogram.
+type SampleCount = u64;
+
+/// Label of the metrics recorded per signed entity type.
+const SIGNED_ENTITY_TYPE_LABEL: &str = "signed_entity_type";
+
+/// Labels of the runtime state transitions metric.
+const RUNTIME_STATE_TRANSITION_LABELS: [&str; 2] = ["from", "to"];
+
+/// Metrics service which is responsible for recording and exposing metrics.
+pub struct MetricsService {
+    registry: Registry,
+    signer_registration_total_since_startup_counter: Box<Counter>,
+    signer_registration_success_since_startup_counter: Box<Counter>,
+    single_signature_received_since_startup_counter: Box<CounterVec>,
+    single_signatures_per_open_message_histogram: Box<Histogram>,
+    certificate_created_since_startup_counter: Box<CounterVec>,
+    artifact_build_duration_seconds_histogram: Box<HistogramVec>,
+    snapshot_archive_size_bytes_gauge: Box<Gauge>,
+    snapshot_upload_duration_seconds_histogram: Box<Histogram>,
+    prover_request_duration_seconds_histogram: Box<Histogram>,
+    runtime_state_transition_since_startup_counter: Box<CounterVec>,
+}
+
+impl MetricsService {
+    /// Create a new `MetricsService` instance.
+    pub fn new() -> StdResult<Self> {
+        let registry = Registry::new();
+
+        // Signer registration metrics
+        let signer_registration_total_since_startup_counter =
+            Box::new(Self::create_metric_counter(
+                SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME,
+                SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_HELP,
+            )?);
+        registry.register(signer_registration_total_since_startup_counter.clone())?;
+
+        let signer_registration_success_since_startup_counter =
+            Box::new(Self::create_metric_counter(
+                SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_NAME,
+                SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_HELP,
+            )?);
+        registry.register(signer_registration_success_since_startup_counter.clone())?;
+
+        // Single signatures metrics
+        let single_signature_received_since_startup_counter =
+            Box::new(Self::create_metric_counter_vec(
+                SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_NAME,
+                SINGLE_SIGNATURE_RECEIVED_SINCE_STARTUP_METRIC_HELP,
+                &[SIGNED_ENTITY_TYPE_LABEL],
+            )?);
+        registry.register(single_signature_received_since_startup_counter.clone())?;
+
+        let single_signatures_per_open_message_histogram = Box::new(Self::create_metric_histogram(
+            SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_NAME,
+            SINGLE_SIGNATURES_PER_OPEN_MESSAGE_METRIC_HELP,
+            // 1 to 4096 signatures
+            exponential_buckets(1.0, 2.0, 13)?,
+        )?);
+        registry.register(single_signatures_per_open_message_histogram.clone())?;
+
+        // Certificates and artifacts metrics
+        let certificate_created_since_startup_counter = Box::new(Self::create_metric_counter_vec(
+            CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_NAME,
+            CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_HELP,
+            &[SIGNED_ENTITY_TYPE_LABEL],
+        )?);
+        registry.register(certificate_created_since_startup_counter.clone())?;
+
+        let artifact_build_duration_seconds_histogram =
+            Box::new(Self::create_metric_histogram_vec(
+                ARTIFACT_BUILD_DURATION_SECONDS_METRIC_NAME,
+                ARTIFACT_BUILD_DURATION_SECONDS_METRIC_HELP,
+                // 100ms to ~7h
+                exponential_buckets(0.1, 4.0, 10)?,
+                &[SIGNED_ENTITY_TYPE_LABEL],
+            )?);
+        registry.register(artifact_build_duration_seconds_histogram.clone())?;
+
+        // Snapshot archive metrics
+        let snapshot_archive_size_bytes_gauge = Box::new(Self::create_metric_gauge(
+            SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_NAME,
+            SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_HELP,
+        )?);
+        registry.register(snapshot_archive_size_bytes_gauge.clone())?;
+
+        let snapshot_upload_duration_seconds_histogram = Box::new(Self::create_metric_histogram(
+            SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_NAME,
+            SNAPSHOT_UPLOAD_DURATION_SECONDS_METRIC_HELP,
+            // 1s to ~18h
+            exponential_buckets(1.0, 4.0, 9)?,
+        )?);
+        registry.register(snapshot_upload_duration_seconds_histogram.clone())?;
+
+        // Prover metrics
+        let prover_request_duration_seconds_histogram = Box::new(Self::create_metric_histogram(
+            PROVER_REQUEST_DURATION_SECONDS_METRIC_NAME,
+            PROVER_REQUEST_DURATION_SECONDS_METRIC_HELP,
+            prometheus::DEFAULT_BUCKETS.to_vec(),
+        )?);
+        registry.register(prover_request_duration_seconds_histogram.clone())?;
+
+        // Runtime metrics
+        let runtime_state_transition_since_startup_counter =
+            Box::new(Self::create_metric_counter_vec(
+                RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_NAME,
+                RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_HELP,
+                &RUNTIME_STATE_TRANSITION_LABELS,
+            )?);
+        registry.register(runtime_state_transition_since_startup_counter.clone())?;
+
+        Ok(Self {
+            registry,
+            signer_registration_total_since_startup_counter,
+            signer_registration_success_since_startup_counter,
+            single_signature_received_since_startup_counter,
+            single_signatures_per_open_message_histogram,
+            certificate_created_since_startup_counter,
+            artifact_build_duration_seconds_histogram,
+            snapshot_archive_size_bytes_gauge,
+            snapshot_upload_duration_seconds_histogram,
+            prover_request_duration_seconds_histogram,
+            runtime_state_transition_since_startup_counter,
+        })
+    }
+
+    fn create_metric_counter(name: &MetricName, help: &str) -> StdResult<Counter> {
+        let counter_opts = Opts::new(name, help);
+        let counter = Counter::with_opts(counter_opts)?;
+
+        Ok(counter)
+    }
+
+    fn create_metric_counter_vec(
+        name: &MetricName,
+        help: &str,
+        labels: &[&str],
+    ) -> StdResult<CounterVec> {
+        let counter_opts = Opts::new(name, help);
+        let counter = CounterVec::new(counter_opts, labels)?;
+
+        Ok(counter)
+    }
+
+    fn create_metric_gauge(name: &MetricName, help: &str) -> StdResult<Gauge> {
+        let gauge_opts = Opts::new(name, help);
+        let gauge = Gauge::with_opts(gauge_opts)?;
+
+        Ok(gauge)
+    }
+
+    fn create_metric_histogram(
+        name: &MetricName,
+        help: &str,
+        buckets: Vec<f64>,
+    ) -> StdResult<Histogram> {
+        let histogram_opts = HistogramOpts::new(name, help).buckets(buckets);
+        let histogram = Histogram::with_opts(histogram_opts)?;
+
+        Ok(histogram)
+    }
+
+    fn create_metric_histogram_vec(
+        name: &MetricName,
+        help: &str,
+        buckets: Vec<f64>,
+        labels: &[&str],
+    ) -> StdResult<HistogramVec> {
+        let histogram_opts = HistogramOpts::new(name, help).buckets(buckets);
+        let histogram = HistogramVec::new(histogram_opts, labels)?;
+
+        Ok(histogram)
+    }
+
+    /// Export the metrics as a string with the Open Metrics standard format.
+    /// These metrics can be exposed on a HTTP server.
+    pub fn export_metrics(&self) -> StdResult<String> {
+        let mut buffer = vec![];
+        let encoder = TextEncoder::new();
+        let metric_families = self.registry.gather();
+        encoder.encode(&metric_families, &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Increment the `signer_registration_total_since_startup` counter.
+    pub fn signer_registration_total_since_startup_counter_increment(&self) {
+        debug!("MetricsService: incrementing 'signer_registration_total_since_startup' counter");
+        self.signer_registration_total_since_startup_counter.inc();
+    }
+
+    /// Get the `signer_registration_total_since_startup` counter.
+    pub fn signer_registration_total_since_startup_counter_get(&self) -> CounterValue {
+        self.signer_registration_total_since_startup_counter
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Increment the `signer_registration_success_since_startup` counter.
+    pub fn signer_registration_success_since_startup_counter_increment(&self) {
+        debug!("MetricsService: incrementing 'signer_registration_success_since_startup' counter");
+        self.signer_registration_success_since_startup_counter.inc();
+    }
+
+    /// Get the `signer_registration_success_since_startup` counter.
+    pub fn signer_registration_success_since_startup_counter_get(&self) -> CounterValue {
+        self.signer_registration_success_since_startup_counter
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Increment the `single_signature_received_since_startup` counter of the given signed entity type.
+    pub fn single_signature_received_since_startup_counter_increment(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) {
+        debug!("MetricsService: incrementing 'single_signature_received_since_startup' counter for '{signed_entity_type}'");
+        self.single_signature_received_since_startup_counter
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .inc();
+    }
+
+    /// Get the `single_signature_received_since_startup` counter of the given signed entity type.
+    pub fn single_signature_received_since_startup_counter_get(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) -> CounterValue {
+        self.single_signature_received_since_startup_counter
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Record the number of single signatures registered for an open message.
+    pub fn single_signatures_per_open_message_histogram_observe(&self, signatures: usize) {
+        debug!("MetricsService: observing {signatures} for 'single_signatures_per_open_message' histogram");
+        self.single_signatures_per_open_message_histogram
+            .observe(signatures as f64);
+    }
+
+    /// Get the number of open messages recorded by the `single_signatures_per_open_message` histogram.
+    pub fn single_signatures_per_open_message_histogram_count_get(&self) -> SampleCount {
+        self.single_signatures_per_open_message_histogram
+            .get_sample_count()
+    }
+
+    /// Get the total number of single signatures recorded by the `single_signatures_per_open_message` histogram.
+    pub fn single_signatures_per_open_message_histogram_sum_get(&self) -> f64 {
+        self.single_signatures_per_open_message_histogram
+            .get_sample_sum()
+    }
+
+    /// Increment the `certificate_created_since_startup` counter of the given signed entity type.
+    pub fn certificate_created_since_startup_counter_increment(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) {
+        debug!("MetricsService: incrementing 'certificate_created_since_startup' counter for '{signed_entity_type}'");
+        self.certificate_created_since_startup_counter
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .inc();
+    }
+
+    /// Get the `certificate_created_since_startup` counter of the given signed entity type.
+    pub fn certificate_created_since_startup_counter_get(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) -> CounterValue {
+        self.certificate_created_since_startup_counter
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Record the duration of an artifact build of the given signed entity type.
+    pub fn artifact_build_duration_seconds_histogram_observe(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        duration: Duration,
+    ) {
+        debug!("MetricsService: observing {duration:?} for 'artifact_build_duration_seconds' histogram for '{signed_entity_type}'");
+        self.artifact_build_duration_seconds_histogram
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Get the number of builds recorded by the `artifact_build_duration_seconds` histogram of
+    /// the given signed entity type.
+    pub fn artifact_build_duration_seconds_histogram_count_get(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) -> SampleCount {
+        self.artifact_build_duration_seconds_histogram
+            .with_label_values(&[signed_entity_type.as_ref()])
+            .get_sample_count()
+    }
+
+    /// Set the `snapshot_archive_size_bytes` gauge value.
+    pub fn snapshot_archive_size_bytes_gauge_set(&self, size: u64) {
+        debug!("MetricsService: set 'snapshot_archive_size_bytes' gauge value to {size}");
+        self.snapshot_archive_size_bytes_gauge.set(size as f64);
+    }
+
+    /// Get the `snapshot_archive_size_bytes` gauge value.
+    pub fn snapshot_archive_size_bytes_gauge_get(&self) -> u64 {
+        self.snapshot_archive_size_bytes_gauge.get().round() as u64
+    }
+
+    /// Record the duration of a snapshot archive upload.
+    pub fn snapshot_upload_duration_seconds_histogram_observe(&self, duration: Duration) {
+        debug!("MetricsService: observing {duration:?} for 'snapshot_upload_duration_seconds' histogram");
+        self.snapshot_upload_duration_seconds_histogram
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Get the number of uploads recorded by the `snapshot_upload_duration_seconds` histogram.
+    pub fn snapshot_upload_duration_seconds_histogram_count_get(&self) -> SampleCount {
+        self.snapshot_upload_duration_seconds_histogram
+            .get_sample_count()
+    }
+
+    /// Record the duration of a request to the Cardano transactions prover.
+    pub fn prover_request_duration_seconds_histogram_observe(&self, duration: Duration) {
+        debug!("MetricsService: observing {duration:?} for 'prover_request_duration_seconds' histogram");
+        self.prover_request_duration_seconds_histogram
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Get the number of requests recorded by the `prover_request_duration_seconds` histogram.
+    pub fn prover_request_duration_seconds_histogram_count_get(&self) -> SampleCount {
+        self.prover_request_duration_seconds_histogram
+            .get_sample_count()
+    }
+
+    /// Increment the `runtime_state_transition_since_startup` counter of the given transition.
+    pub fn runtime_state_transition_since_startup_counter_increment(&self, from: &str, to: &str) {
+        debug!("MetricsService: incrementing 'runtime_state_transition_since_startup' counter from '{from}' to '{to}'");
+        self.runtime_state_transition_since_startup_counter
+            .with_label_values(&[from, to])
+            .inc();
+    }
+
+    /// Get the `runtime_state_transition_since_startup` counter of the given transition.
+    pub fn runtime_state_transition_since_startup_counter_get(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> CounterValue {
+        self.runtime_state_transition_since_startup_counter
+            .with_label_values(&[from, to])
+            .get()
+            .round() as CounterValue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus_parse::Value;
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn parse_metrics(raw_metrics: &str) -> StdResult<BTreeMap<String, Value>> {
+        Ok(
+            prometheus_parse::Scrape::parse(raw_metrics.lines().map(|s| Ok(s.to_owned())))?
+                .samples
+                .into_iter()
+                .map(|s| (s.metric, s.value))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn test_export_metrics() {
+        let metrics_service = MetricsService::new().unwrap();
+        let exported_metrics = metrics_service.export_metrics().unwrap();
+
+        let parsed_metrics = parse_metrics(&exported_metrics).unwrap();
+
+        // Histograms are left out, and metrics with labels are only exported once a value has
+        // been recorded for a label
+        assert_eq!(
+            BTreeMap::from([
+                (
+                    SIGNER_REGISTRATION_SUCCESS_SINCE_STARTUP_METRIC_NAME.to_string(),
+                    Value::Counter(0.0),
+                ),
+                (
+                    SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME.to_string(),
+                    Value::Counter(0.0),
+                ),
+                (
+                    SNAPSHOT_ARCHIVE_SIZE_BYTES_METRIC_NAME.to_string(),
+                    Value::Gauge(0.0),
+                ),
+            ]),
+            parsed_metrics
+                .into_iter()
+                .filter(|(_, value)| matches!(value, Value::Counter(_) | Value::Gauge(_)))
+                .collect::<BTreeMap<_, _>>()
+        );
+    }
+
+    #[test]
+    fn test_export_metrics_with_labels() {
+        let metrics_service = MetricsService::new().unwrap();
+        metrics_service.certificate_created_since_startup_counter_increment(
+            SignedEntityTypeDiscriminants::CardanoTransactions,
+        );
+        metrics_service.runtime_state_transition_since_startup_counter_increment("idle", "ready");
+
+        let exported_metrics = metrics_service.export_metrics().unwrap();
+
+        assert!(exported_metrics.contains(&format!(
+            r#"{CERTIFICATE_CREATED_SINCE_STARTUP_METRIC_NAME}{{signed_entity_type="CardanoTransactions"}} 1"#
+        )));
+        assert!(exported_metrics.contains(&format!(
+            r#"{RUNTIME_STATE_TRANSITION_SINCE_STARTUP_METRIC_NAME}{{from="idle",to="ready"}} 1"#
+        )));
+    }
+
+    #[test]
+    fn test_signer_registration_total_since_startup_counter_increment() {
+        let metrics_service = MetricsService::new().unwrap();
+        assert_eq!(
+            0,
+            metrics_service.signer_registration_total_since_startup_counter_get(),
+        );
+
+        metrics_service.signer_registration_total_since_startup_counter_increment();
+        assert_eq!(
+            1,
+            metrics_service.signer_registration_total_since_startup_counter_get(),
+        );
+    }
+
+    #[test]
+    fn test_signer_registration_success_since_startup_counter_increment() {
+        let metrics_service = MetricsService::new().unwrap();
+        assert_eq!(
+            0,
+            metrics_service.signer_registration_success_since_startup_counter_get(),
+        );
+
+        metrics_service.signer_registration_success_since_startup_counter_increment();
+        assert_eq!(
+            1,
+            metrics_service.signer_registration_success_since_startup_counter_get(),
+        );
+    }
+
+    #[test]
+    fn test_single_signature_received_since_startup_counter_increment_per_signed_entity_type() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service.single_signature_received_since_startup_counter_increment(
+            SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+        );
+        assert_eq!(
+            1,
+            metrics_service.single_signature_received_since_startup_counter_get(
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution
+            ),
+        );
+        assert_eq!(
+            0,
+            metrics_service.single_signature_received_since_startup_counter_get(
+                SignedEntityTypeDiscriminants::CardanoTransactions
+            ),
+        );
+    }
+
+    #[test]
+    fn test_single_signatures_per_open_message_histogram_observe() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service.single_signatures_per_open_message_histogram_observe(12);
+        metrics_service.single_signatures_per_open_message_histogram_observe(15);
+        assert_eq!(
+            2,
+            metrics_service.single_signatures_per_open_message_histogram_count_get(),
+        );
+    }
+
+    #[test]
+    fn test_certificate_created_since_startup_counter_increment_per_signed_entity_type() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service.certificate_created_since_startup_counter_increment(
+            SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+        );
+        assert_eq!(
+            1,
+            metrics_service.certificate_created_since_startup_counter_get(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull
+            ),
+        );
+        assert_eq!(
+            0,
+            metrics_service.certificate_created_since_startup_counter_get(
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution
+            ),
+        );
+    }
+
+    #[test]
+    fn test_artifact_build_duration_seconds_histogram_observe_per_signed_entity_type() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service.artifact_build_duration_seconds_histogram_observe(
+            SignedEntityTypeDiscriminants::CardanoStakeDistribution,
+            Duration::from_millis(250),
+        );
+        assert_eq!(
+            1,
+            metrics_service.artifact_build_duration_seconds_histogram_count_get(
+                SignedEntityTypeDiscriminants::CardanoStakeDistribution
+            ),
+        );
+        assert_eq!(
+            0,
+            metrics_service.artifact_build_duration_seconds_histogram_count_get(
+                SignedEntityTypeDiscriminants::CardanoTransactions
+            ),
+        );
+    }
+
+    #[test]
+    fn test_snapshot_archive_size_bytes_gauge_set() {
+        let metrics_service = MetricsService::new().unwrap();
+        assert_eq!(0, metrics_service.snapshot_archive_size_bytes_gauge_get());
+
+        metrics_service.snapshot_archive_size_bytes_gauge_set(123_456_789);
+        assert_eq!(
+            123_456_789,
+            metrics_service.snapshot_archive_size_bytes_gauge_get()
+        );
+    }
+
+    #[test]
+    fn test_snapshot_upload_duration_seconds_histogram_observe() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service.snapshot_upload_duration_seconds_histogram_observe(Duration::from_secs(3));
+        assert_eq!(
+            1,
+            metrics_service.snapshot_upload_duration_seconds_histogram_count_get(),
+        );
+    }
+
+    #[test]
+    fn test_prover_request_duration_seconds_histogram_observe() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service
+            .prover_request_duration_seconds_histogram_observe(Duration::from_millis(20));
+        assert_eq!(
+            1,
+            metrics_service.prover_request_duration_seconds_histogram_count_get(),
+        );
+    }
+
+    #[test]
+    fn test_runtime_state_transition_since_startup_counter_increment_per_transition() {
+        let metrics_service = MetricsService::new().unwrap();
+
+        metrics_service
+            .runtime_state_transition_since_startup_counter_increment("ready", "signing");
+        assert_eq!(
+            1,
+            metrics_service.runtime_state_transition_since_startup_counter_get("ready", "signing"),
+        );
+        assert_eq!(
+            0,
+            metrics_service.runtime_state_transition_since_startup_counter_get("signing", "ready"),
+        );
+    }
+}
@@ -8,6 +8,7 @@ use mithril_common::entities::{
     Certificate, CertificatePending, Epoch, ProtocolMessage, ProtocolMessagePartKey,
     SignedEntityConfig, SignedEntityType, Signer, TimePoint,
 };
+use mithril_common::messages::AggregatorEventMessage;
 use mithril_common::StdResult;
 use mithril_persistence::store::StakeStorer;
 
@@ -390,7 +391,8 @@ impl AggregatorRunnerTrait for AggregatorRunner {
     ) -> StdResult<Option<Certificate>> {
         debug!("RUNNER: create_certificate");
 
-        self.dependencies
+        let certificate = self
+            .dependencies
             .certifier_service
             .create_certificate(signed_entity_type)
             .await
@@ -398,7 +400,22 @@ impl AggregatorRunnerTrait for AggregatorRunner {
                 format!(
                     "CertifierService can not create certificate for signed_entity_type: '{signed_entity_type}'"
                 )
-            })
+            })?;
+
+        if let Some(certificate) = &certificate {
+            self.dependencies
+                .metrics_service
+                .certificate_created_since_startup_counter_increment(signed_entity_type.into());
+            self.dependencies.aggregator_event_broadcaster.broadcast(
+                AggregatorEventMessage::NewCertificate {
+                    certificate_hash: certificate.hash.clone(),
+                    epoch: certificate.epoch,
+                    signed_entity_type: signed_entity_type.to_owned(),
+                },
+            );
+        }
+
+        Ok(certificate)
     }
 
     async fn create_artifact(
@@ -418,6 +435,13 @@ impl AggregatorRunnerTrait for AggregatorRunner {
                 )
             })?;
 
+        self.dependencies.aggregator_event_broadcaster.broadcast(
+            AggregatorEventMessage::NewArtifact {
+                signed_entity_type: signed_entity_type.to_owned(),
+                certificate_hash: certificate.hash.clone(),
+            },
+        );
+
         Ok(())
     }
 
@@ -478,6 +502,10 @@ impl AggregatorRunnerTrait for AggregatorRunner {
             .inform_epoch(epoch)
             .await?;
 
+        self.dependencies
+            .aggregator_event_broadcaster
+            .broadcast(AggregatorEventMessage::EpochChanged { epoch });
+
         Ok(())
     }
 
@@ -516,9 +544,10 @@ pub mod tests {
         chain_observer::FakeObserver,
         digesters::DumbImmutableFileObserver,
         entities::{
-            CertificatePending, ProtocolMessage, SignedEntityType, Signer, StakeDistribution,
-            TimePoint,
+            CertificatePending, Epoch, ProtocolMessage, SignedEntityType, Signer,
+            StakeDistribution, TimePoint,
         },
+        messages::AggregatorEventMessage,
         signable_builder::SignableBuilderService,
         test_utils::{fake_data, MithrilFixtureBuilder},
         MithrilTickerService, StdResult,
@@ -912,9 +941,101 @@ pub mod tests {
             &MithrilFixtureBuilder::default().build(),
         )));
 
+        let mut events = deps.aggregator_event_broadcaster.subscribe();
         let runner = AggregatorRunner::new(Arc::new(deps));
 
         runner.inform_new_epoch(current_epoch).await.unwrap();
+
+        assert_eq!(
+            AggregatorEventMessage::EpochChanged {
+                epoch: current_epoch
+            },
+            events.try_recv().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_certificate_broadcast_new_certificate_event() {
+        let certificate = fake_data::certificate("certificate_hash".to_string());
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(certificate.epoch);
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_create_certificate()
+            .return_once({
+                let certificate = certificate.clone();
+                move |_| Ok(Some(certificate))
+            })
+            .times(1);
+        let mut deps = initialize_dependencies().await;
+        deps.certifier_service = Arc::new(mock_certifier_service);
+        let mut events = deps.aggregator_event_broadcaster.subscribe();
+        let runner = AggregatorRunner::new(Arc::new(deps));
+
+        runner
+            .create_certificate(&signed_entity_type)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            AggregatorEventMessage::NewCertificate {
+                certificate_hash: certificate.hash,
+                epoch: certificate.epoch,
+                signed_entity_type,
+            },
+            events.try_recv().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_certificate_without_certificate_does_not_broadcast_event() {
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_create_certificate()
+            .return_once(|_| Ok(None))
+            .times(1);
+        let mut deps = initialize_dependencies().await;
+        deps.certifier_service = Arc::new(mock_certifier_service);
+        let mut events = deps.aggregator_event_broadcaster.subscribe();
+        let runner = AggregatorRunner::new(Arc::new(deps));
+
+        runner
+            .create_certificate(&SignedEntityType::MithrilStakeDistribution(Epoch(1)))
+            .await
+            .unwrap();
+
+        events
+            .try_recv()
+            .expect_err("no event should be broadcast without certificate");
+    }
+
+    #[tokio::test]
+    async fn test_create_certificate_records_metrics() {
+        let certificate = fake_data::certificate("certificate_hash".to_string());
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(certificate.epoch);
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_create_certificate()
+            .return_once({
+                let certificate = certificate.clone();
+                move |_| Ok(Some(certificate))
+            })
+            .times(1);
+        let mut deps = initialize_dependencies().await;
+        deps.certifier_service = Arc::new(mock_certifier_service);
+        let metrics_service = deps.metrics_service.clone();
+        let runner = AggregatorRunner::new(Arc::new(deps));
+
+        runner
+            .create_certificate(&signed_entity_type)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            1,
+            metrics_service.certificate_created_since_startup_counter_get(
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution
+            )
+        );
     }
 
     #[tokio::test]
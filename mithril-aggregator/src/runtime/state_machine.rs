@@ -1,7 +1,7 @@
 use crate::{
     entities::OpenMessage,
     runtime::{AggregatorRunnerTrait, RuntimeError},
-    AggregatorConfig,
+    AggregatorConfig, MetricsService,
 };
 
 use anyhow::Context;
@@ -65,6 +65,9 @@ pub struct AggregatorRuntime {
 
     /// specific runner for this state machine
     runner: Arc<dyn AggregatorRunnerTrait>,
+
+    /// Metrics service
+    metrics_service: Arc<MetricsService>,
 }
 
 impl AggregatorRuntime {
@@ -73,6 +76,7 @@ impl AggregatorRuntime {
         aggregator_config: AggregatorConfig,
         init_state: Option<AggregatorState>,
         runner: Arc<dyn AggregatorRunnerTrait>,
+        metrics_service: Arc<MetricsService>,
     ) -> Result<Self, RuntimeError> {
         info!("initializing runtime");
 
@@ -90,6 +94,7 @@ impl AggregatorRuntime {
             config: aggregator_config,
             state,
             runner,
+            metrics_service,
         })
     }
 
@@ -161,6 +166,7 @@ impl AggregatorRuntime {
     pub async fn cycle(&mut self) -> Result<(), RuntimeError> {
         info!("================================================================================");
         info!("STATE MACHINE: new cycle: {}", self.state);
+        let previous_state = self.get_state();
 
         match self.state.clone() {
             AggregatorState::Idle(state) => {
@@ -266,6 +272,16 @@ impl AggregatorRuntime {
                 }
             }
         }
+
+        let current_state = self.get_state();
+        if previous_state != current_state {
+            self.metrics_service
+                .runtime_state_transition_since_startup_counter_increment(
+                    &previous_state,
+                    &current_state,
+                );
+        }
+
         Ok(())
     }
 
@@ -423,6 +439,7 @@ mod tests {
             AggregatorConfig::new(Duration::from_millis(20), SignedEntityConfig::dummy()),
             init_state,
             Arc::new(runner),
+            Arc::new(MetricsService::new().unwrap()),
         )
         .await
         .unwrap()
@@ -563,6 +580,12 @@ mod tests {
         runtime.cycle().await.unwrap();
 
         assert_eq!("idle".to_string(), runtime.get_state());
+        assert_eq!(
+            1,
+            runtime
+                .metrics_service
+                .runtime_state_transition_since_startup_counter_get("ready", "idle")
+        );
     }
 
     #[tokio::test]
@@ -598,6 +621,12 @@ mod tests {
             }),
             runtime.state
         );
+        assert_eq!(
+            0,
+            runtime
+                .metrics_service
+                .runtime_state_transition_since_startup_counter_get("ready", "ready")
+        );
     }
 
     #[tokio::test]
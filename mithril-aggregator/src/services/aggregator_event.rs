@@ -0,0 +1,93 @@
+use slog_scope::debug;
+use tokio::sync::broadcast;
+
+use mithril_common::messages::AggregatorEventMessage;
+
+/// Default number of events kept for subscribers that lag behind.
+pub const DEFAULT_AGGREGATOR_EVENT_CAPACITY: usize = 100;
+
+/// Broadcast the events produced by the aggregator runtime (new certificates, new artifacts,
+/// epoch changes) to all their subscribers, ie: the clients connected to the events route.
+pub struct AggregatorEventBroadcaster {
+    sender: broadcast::Sender<AggregatorEventMessage>,
+}
+
+impl AggregatorEventBroadcaster {
+    /// Create a new broadcaster keeping at most `capacity` events for lagging subscribers.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    /// Subscribe to the events broadcast after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<AggregatorEventMessage> {
+        self.sender.subscribe()
+    }
+
+    /// Broadcast an event to all the current subscribers.
+    ///
+    /// Broadcasting when there is no subscriber is not an error, the event is just dropped.
+    pub fn broadcast(&self, event: AggregatorEventMessage) {
+        match self.sender.send(event) {
+            Ok(receivers) => {
+                debug!("AggregatorEventBroadcaster: event sent to {receivers} subscriber(s)")
+            }
+            Err(broadcast::error::SendError(event)) => {
+                debug!("AggregatorEventBroadcaster: no subscriber for event"; "event" => ?event)
+            }
+        }
+    }
+
+    /// Number of the current subscribers.
+    pub fn subscribers_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for AggregatorEventBroadcaster {
+    fn default() -> Self {
+        Self::new(DEFAULT_AGGREGATOR_EVENT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::Epoch;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn broadcast_events_to_all_subscribers() {
+        let broadcaster = AggregatorEventBroadcaster::default();
+        let mut receiver_1 = broadcaster.subscribe();
+        let mut receiver_2 = broadcaster.subscribe();
+        let event = AggregatorEventMessage::EpochChanged { epoch: Epoch(4) };
+
+        broadcaster.broadcast(event.clone());
+
+        assert_eq!(event, receiver_1.recv().await.unwrap());
+        assert_eq!(event, receiver_2.recv().await.unwrap());
+    }
+
+    #[test]
+    fn broadcast_without_subscriber_does_not_fail() {
+        let broadcaster = AggregatorEventBroadcaster::default();
+
+        broadcaster.broadcast(AggregatorEventMessage::dummy());
+
+        assert_eq!(0, broadcaster.subscribers_count());
+    }
+
+    #[tokio::test]
+    async fn subscribers_only_receive_events_broadcast_after_subscription() {
+        let broadcaster = AggregatorEventBroadcaster::default();
+        broadcaster.broadcast(AggregatorEventMessage::EpochChanged { epoch: Epoch(4) });
+        let mut receiver = broadcaster.subscribe();
+        let event = AggregatorEventMessage::EpochChanged { epoch: Epoch(5) };
+
+        broadcaster.broadcast(event.clone());
+
+        assert_eq!(event, receiver.recv().await.unwrap());
+    }
+}
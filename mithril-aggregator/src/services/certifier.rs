@@ -29,7 +29,7 @@ use crate::{
         CertificateRepository, OpenMessageRepository, SingleSignatureRepository,
     },
     entities::OpenMessage,
-    MultiSigner,
+    MetricsService, MultiSigner,
 };
 
 use crate::dependency_injection::EpochServiceWrapper;
@@ -156,6 +156,7 @@ pub struct MithrilCertifierService {
     // todo: should be removed after removing immutable file number from the certificate metadata
     ticker_service: Arc<dyn TickerService>,
     epoch_service: EpochServiceWrapper,
+    metrics_service: Arc<MetricsService>,
     _logger: Logger,
 }
 
@@ -172,6 +173,7 @@ impl MithrilCertifierService {
         multi_signer: Arc<RwLock<dyn MultiSigner>>,
         ticker_service: Arc<dyn TickerService>,
         epoch_service: EpochServiceWrapper,
+        metrics_service: Arc<MetricsService>,
         logger: Logger,
     ) -> Self {
         Self {
@@ -184,6 +186,7 @@ impl MithrilCertifierService {
             genesis_verifier,
             ticker_service,
             epoch_service,
+            metrics_service,
             _logger: logger,
         }
     }
@@ -328,6 +331,13 @@ impl CertifierService for MithrilCertifierService {
                 .update_open_message(open_message_record)
                 .await
                 .with_context(|| "Certifier can not update open message to mark it as expired")?;
+            let single_signatures = self
+                .get_open_message_record(signed_entity_type)
+                .await?
+                .map(|record| record.single_signatures.len())
+                .unwrap_or_default();
+            self.metrics_service
+                .single_signatures_per_open_message_histogram_observe(single_signatures);
         }
 
         Ok(open_message_record.map(|record| record.into()))
@@ -445,6 +455,20 @@ impl CertifierService for MithrilCertifierService {
             .await
             .with_context(|| format!("Certifier can not update open message for signed entity type: '{signed_entity_type}'"))
             ?;
+        let certified_signers = certificate
+            .metadata
+            .signers
+            .iter()
+            .map(|signer| signer.party_id.clone())
+            .collect::<Vec<_>>();
+        self.single_signature_repository
+            .record_single_signatures_included(open_message.epoch, &certified_signers)
+            .await
+            .with_context(|| format!("Certifier can not update the signers participation for signed entity type: '{signed_entity_type}'"))?;
+        self.metrics_service
+            .single_signatures_per_open_message_histogram_observe(
+                open_message.single_signatures.len(),
+            );
 
         Ok(Some(certificate))
     }
@@ -493,8 +517,8 @@ impl CertifierService for MithrilCertifierService {
 #[cfg(test)]
 mod tests {
     use crate::{
-        dependency_injection::DependenciesBuilder, multi_signer::MockMultiSigner,
-        services::FakeEpochService, Configuration,
+        database::record::SignerParticipationRecord, dependency_injection::DependenciesBuilder,
+        multi_signer::MockMultiSigner, services::FakeEpochService, Configuration,
     };
     use chrono::{DateTime, Days};
     use mithril_common::{
@@ -519,6 +543,7 @@ mod tests {
             let multi_signer = dependency_builder.get_multi_signer().await.unwrap();
             let ticker_service = dependency_builder.get_ticker_service().await.unwrap();
             let epoch_service = dependency_builder.get_epoch_service().await.unwrap();
+            let metrics_service = dependency_builder.get_metrics_service().await.unwrap();
             let logger = dependency_builder.get_logger().unwrap();
 
             Self::new(
@@ -531,6 +556,7 @@ mod tests {
                 multi_signer,
                 ticker_service,
                 epoch_service,
+                metrics_service,
                 logger,
             )
         }
@@ -628,6 +654,12 @@ mod tests {
             .expect("mark_open_message_if_expired should not fail");
         assert!(open_message.is_some());
         assert!(open_message.unwrap().is_expired);
+        assert_eq!(
+            1,
+            certifier_service
+                .metrics_service
+                .single_signatures_per_open_message_histogram_count_get()
+        );
     }
 
     #[tokio::test]
@@ -887,6 +919,85 @@ mod tests {
 
         let latest_certificates = certifier_service.get_latest_certificates(10).await.unwrap();
         assert!(!latest_certificates.is_empty());
+
+        assert_eq!(
+            1,
+            certifier_service
+                .metrics_service
+                .single_signatures_per_open_message_histogram_count_get()
+        );
+        assert_eq!(
+            open_message.single_signatures.len() as f64,
+            certifier_service
+                .metrics_service
+                .single_signatures_per_open_message_histogram_sum_get()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_signers_participation_after_cleaning_their_epoch() {
+        let network = fake_data::network();
+        let beacon = CardanoDbBeacon::new(network.to_string(), 3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let certifier_service = setup_certifier_service_with_network(
+            network,
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+        let genesis_certificate =
+            fixture.create_genesis_certificate(network.to_string(), beacon.epoch - 1, 1);
+        certifier_service
+            .certificate_repository
+            .create_certificate(genesis_certificate)
+            .await
+            .unwrap();
+        let signatures = fixture
+            .signers_fixture()
+            .iter()
+            .filter_map(|signer_fixture| signer_fixture.sign(&protocol_message))
+            .collect::<Vec<_>>();
+        for signature in &signatures {
+            certifier_service
+                .register_single_signature(&signed_entity_type, signature)
+                .await
+                .unwrap();
+        }
+        certifier_service
+            .create_certificate(&signed_entity_type)
+            .await
+            .unwrap()
+            .expect("a certificate should have been created");
+
+        certifier_service
+            .inform_epoch(beacon.epoch + 1)
+            .await
+            .unwrap();
+
+        for signature in signatures {
+            let participation = certifier_service
+                .single_signature_repository
+                .get_signer_participation(&signature.party_id)
+                .await
+                .unwrap();
+            assert_eq!(
+                vec![SignerParticipationRecord {
+                    epoch: beacon.epoch,
+                    signatures_sent: 1,
+                    signatures_included: 1,
+                    lottery_wins: signature.won_indexes.len() as u64,
+                }],
+                participation
+            );
+        }
     }
 
     #[tokio::test]
@@ -6,17 +6,21 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use mithril_common::{
-    entities::{Epoch, SignedEntityTypeDiscriminants},
+    entities::{Epoch, PartyId, SignedEntityTypeDiscriminants},
     messages::{
         CardanoStakeDistributionListMessage, CardanoStakeDistributionMessage,
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
         CertificateListMessage, CertificateMessage, MithrilStakeDistributionListMessage,
-        MithrilStakeDistributionMessage, SnapshotListMessage, SnapshotMessage,
+        MithrilStakeDistributionMessage, SignerParticipationMessage, SigningOpportunityListMessage,
+        SnapshotListMessage, SnapshotMessage,
     },
     StdResult,
 };
 
-use crate::database::repository::{CertificateRepository, SignedEntityStorer};
+use crate::database::repository::{
+    CertificateRepository, OpenMessageRepository, SignedEntityStorer, SingleSignatureRepository,
+};
+use crate::entities::ListFilters;
 
 #[cfg(test)]
 use mockall::automock;
@@ -38,9 +42,11 @@ pub trait MessageService: Sync + Send {
         certificate_hash: &str,
     ) -> StdResult<Option<CertificateMessage>>;
 
-    /// Return the message representation of the last N certificates
-    async fn get_certificate_list_message(&self, limit: usize)
-        -> StdResult<CertificateListMessage>;
+    /// Return the message representation of the last certificates matching the given filters
+    async fn get_certificate_list_message(
+        &self,
+        filters: &ListFilters,
+    ) -> StdResult<CertificateListMessage>;
 
     /// Return the information regarding the given snapshot
     async fn get_snapshot_message(
@@ -48,9 +54,11 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<SnapshotMessage>>;
 
-    /// Return the list of the last signed snapshots. The limit of the list is
-    /// passed as argument.
-    async fn get_snapshot_list_message(&self, limit: usize) -> StdResult<SnapshotListMessage>;
+    /// Return the list of the last signed snapshots matching the given filters.
+    async fn get_snapshot_list_message(
+        &self,
+        filters: &ListFilters,
+    ) -> StdResult<SnapshotListMessage>;
 
     /// Return the information regarding the MSD for the given identifier.
     async fn get_mithril_stake_distribution_message(
@@ -58,10 +66,10 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<MithrilStakeDistributionMessage>>;
 
-    /// Return the list of the last Mithril stake distributions message
+    /// Return the list of the last Mithril stake distributions message matching the given filters
     async fn get_mithril_stake_distribution_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<MithrilStakeDistributionListMessage>;
 
     /// Return the information regarding the Cardano transactions set for the given identifier.
@@ -70,10 +78,10 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<CardanoTransactionSnapshotMessage>>;
 
-    /// Return the list of the last Cardano transactions set message
+    /// Return the list of the last Cardano transactions set message matching the given filters
     async fn get_cardano_transaction_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<CardanoTransactionSnapshotListMessage>;
 
     /// Return the information regarding the Cardano stake distribution for the given identifier.
@@ -88,17 +96,30 @@ pub trait MessageService: Sync + Send {
         epoch: Epoch,
     ) -> StdResult<Option<CardanoStakeDistributionMessage>>;
 
-    /// Return the list of the last Cardano stake distributions message
+    /// Return the list of the last Cardano stake distributions message matching the given filters
     async fn get_cardano_stake_distribution_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<CardanoStakeDistributionListMessage>;
+
+    /// Return the list of the messages that are currently open for signatures
+    async fn get_signing_opportunity_list_message(
+        &self,
+    ) -> StdResult<SigningOpportunityListMessage>;
+
+    /// Return the participation of the given signer in the certificates production
+    async fn get_signer_participation_message(
+        &self,
+        party_id: &PartyId,
+    ) -> StdResult<SignerParticipationMessage>;
 }
 
 /// Implementation of the [MessageService]
 pub struct MithrilMessageService {
     certificate_repository: Arc<CertificateRepository>,
     signed_entity_storer: Arc<dyn SignedEntityStorer>,
+    open_message_repository: Arc<OpenMessageRepository>,
+    single_signature_repository: Arc<SingleSignatureRepository>,
 }
 
 impl MithrilMessageService {
@@ -106,10 +127,14 @@ impl MithrilMessageService {
     pub fn new(
         certificate_repository: Arc<CertificateRepository>,
         signed_entity_storer: Arc<dyn SignedEntityStorer>,
+        open_message_repository: Arc<OpenMessageRepository>,
+        single_signature_repository: Arc<SingleSignatureRepository>,
     ) -> Self {
         Self {
             certificate_repository,
             signed_entity_storer,
+            open_message_repository,
+            single_signature_repository,
         }
     }
 }
@@ -127,10 +152,10 @@ impl MessageService for MithrilMessageService {
 
     async fn get_certificate_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<CertificateListMessage> {
         self.certificate_repository
-            .get_latest_certificates(limit)
+            .get_certificates_with_filters(filters)
             .await
     }
 
@@ -146,11 +171,14 @@ impl MessageService for MithrilMessageService {
         signed_entity.map(|s| s.try_into()).transpose()
     }
 
-    async fn get_snapshot_list_message(&self, limit: usize) -> StdResult<SnapshotListMessage> {
+    async fn get_snapshot_list_message(
+        &self,
+        filters: &ListFilters,
+    ) -> StdResult<SnapshotListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoImmutableFilesFull;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filters(&signed_entity_type_id, filters)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -170,12 +198,12 @@ impl MessageService for MithrilMessageService {
 
     async fn get_mithril_stake_distribution_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<MithrilStakeDistributionListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::MithrilStakeDistribution;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filters(&signed_entity_type_id, filters)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -195,12 +223,12 @@ impl MessageService for MithrilMessageService {
 
     async fn get_cardano_transaction_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<CardanoTransactionSnapshotListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoTransactions;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filters(&signed_entity_type_id, filters)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -232,16 +260,42 @@ impl MessageService for MithrilMessageService {
 
     async fn get_cardano_stake_distribution_list_message(
         &self,
-        limit: usize,
+        filters: &ListFilters,
     ) -> StdResult<CardanoStakeDistributionListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoStakeDistribution;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filters(&signed_entity_type_id, filters)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
     }
+
+    async fn get_signing_opportunity_list_message(
+        &self,
+    ) -> StdResult<SigningOpportunityListMessage> {
+        let open_messages = self
+            .open_message_repository
+            .get_signing_opportunities()
+            .await?;
+
+        Ok(open_messages.into_iter().map(|o| o.into()).collect())
+    }
+
+    async fn get_signer_participation_message(
+        &self,
+        party_id: &PartyId,
+    ) -> StdResult<SignerParticipationMessage> {
+        let participation = self
+            .single_signature_repository
+            .get_signer_participation(party_id)
+            .await?;
+
+        Ok(SignerParticipationMessage {
+            party_id: party_id.to_owned(),
+            epochs: participation.into_iter().map(|p| p.into()).collect(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -250,14 +304,24 @@ mod tests {
 
     use mithril_common::entities::{
         CardanoStakeDistribution, CardanoTransactionsSnapshot, Certificate, Epoch,
-        MithrilStakeDistribution, SignedEntity, SignedEntityType, Snapshot,
+        MithrilStakeDistribution, ProtocolMessage, SignedEntity, SignedEntityType, Snapshot,
+    };
+    use mithril_common::messages::{
+        SignerParticipationEpochMessage, SignerParticipationMessage,
+        SigningOpportunityListItemMessage, ToMessageAdapter,
     };
-    use mithril_common::messages::ToMessageAdapter;
-    use mithril_common::test_utils::MithrilFixtureBuilder;
+    use mithril_common::test_utils::{fake_data, MithrilFixtureBuilder};
+    use mithril_persistence::sqlite::ConnectionExtensions;
 
-    use crate::database::record::SignedEntityRecord;
+    use crate::database::query::IncrementSignerParticipationQuery;
+    use crate::database::record::{SignedEntityRecord, SignerRecord, SingleSignatureRecord};
     use crate::database::repository::MockSignedEntityStorer;
+    use crate::database::test_helper::{
+        insert_epoch_settings, insert_signer_registrations, insert_signers,
+        insert_single_signatures_in_db, setup_single_signature_records,
+    };
     use crate::dependency_injection::DependenciesBuilder;
+    use crate::entities::ListFilters;
     use crate::message_adapters::{
         ToCardanoStakeDistributionListMessageAdapter, ToCardanoStakeDistributionMessageAdapter,
         ToCardanoTransactionListMessageAdapter, ToCardanoTransactionMessageAdapter,
@@ -324,7 +388,10 @@ mod tests {
             .unwrap();
 
         // test
-        let certificate_messages = service.get_certificate_list_message(5).await.unwrap();
+        let certificate_messages = service
+            .get_certificate_list_message(&ListFilters::with_limit(5))
+            .await
+            .unwrap();
 
         assert_eq!(2, certificate_messages.len());
         assert_eq!(last_certificate_hash, certificate_messages[0].hash);
@@ -389,12 +456,15 @@ mod tests {
         let mut dep_builder = DependenciesBuilder::new(configuration);
         let mut storer = MockSignedEntityStorer::new();
         storer
-            .expect_get_last_signed_entities_by_type()
+            .expect_get_signed_entities_by_type_with_filters()
             .return_once(|_, _| Ok(records))
             .once();
         dep_builder.signed_entity_storer = Some(Arc::new(storer));
         let service = dep_builder.get_message_service().await.unwrap();
-        let response = service.get_snapshot_list_message(3).await.unwrap();
+        let response = service
+            .get_snapshot_list_message(&ListFilters::with_limit(3))
+            .await
+            .unwrap();
 
         assert_eq!(message, response);
     }
@@ -462,13 +532,13 @@ mod tests {
         let mut dep_builder = DependenciesBuilder::new(configuration);
         let mut storer = MockSignedEntityStorer::new();
         storer
-            .expect_get_last_signed_entities_by_type()
+            .expect_get_signed_entities_by_type_with_filters()
             .return_once(|_, _| Ok(records))
             .once();
         dep_builder.signed_entity_storer = Some(Arc::new(storer));
         let service = dep_builder.get_message_service().await.unwrap();
         let response = service
-            .get_mithril_stake_distribution_list_message(10)
+            .get_mithril_stake_distribution_list_message(&ListFilters::with_limit(10))
             .await
             .unwrap();
 
@@ -544,13 +614,13 @@ mod tests {
         let mut dep_builder = DependenciesBuilder::new(configuration);
         let mut storer = MockSignedEntityStorer::new();
         storer
-            .expect_get_last_signed_entities_by_type()
+            .expect_get_signed_entities_by_type_with_filters()
             .return_once(|_, _| Ok(records))
             .once();
         dep_builder.signed_entity_storer = Some(Arc::new(storer));
         let service = dep_builder.get_message_service().await.unwrap();
         let response = service
-            .get_cardano_transaction_list_message(10)
+            .get_cardano_transaction_list_message(&ListFilters::with_limit(10))
             .await
             .unwrap();
 
@@ -668,16 +738,105 @@ mod tests {
         let mut dep_builder = DependenciesBuilder::new(configuration);
         let mut storer = MockSignedEntityStorer::new();
         storer
-            .expect_get_last_signed_entities_by_type()
+            .expect_get_signed_entities_by_type_with_filters()
             .return_once(|_, _| Ok(records))
             .once();
         dep_builder.signed_entity_storer = Some(Arc::new(storer));
         let service = dep_builder.get_message_service().await.unwrap();
         let response = service
-            .get_cardano_stake_distribution_list_message(10)
+            .get_cardano_stake_distribution_list_message(&ListFilters::with_limit(10))
             .await
             .unwrap();
 
         assert_eq!(message, response);
     }
+
+    #[tokio::test]
+    async fn get_signing_opportunity_list_message() {
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        insert_epoch_settings(&dep_builder.get_sqlite_connection().await.unwrap(), &[1]).unwrap();
+        let repository = dep_builder.get_open_message_repository().await.unwrap();
+        let open_message = repository
+            .create_open_message(
+                Epoch(1),
+                &SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+                &ProtocolMessage::new(),
+            )
+            .await
+            .unwrap();
+        let service = dep_builder.get_message_service().await.unwrap();
+
+        let response = service
+            .get_signing_opportunity_list_message()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![SigningOpportunityListItemMessage::from(open_message)],
+            response
+        );
+    }
+
+    #[tokio::test]
+    async fn get_signer_participation_message() {
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let connection = dep_builder.get_sqlite_connection().await.unwrap();
+        let signer = fake_data::signers_with_stakes(1).pop().unwrap();
+        insert_epoch_settings(&connection, &[1, 3]).unwrap();
+        insert_signers(
+            &connection,
+            vec![SignerRecord {
+                signer_id: signer.party_id.clone(),
+                pool_ticker: None,
+                created_at: Default::default(),
+                updated_at: Default::default(),
+                last_registered_at: None,
+            }],
+        )
+        .unwrap();
+        insert_signer_registrations(&connection, vec![(Epoch(1), vec![signer.clone()])]).unwrap();
+        let repository = dep_builder.get_open_message_repository().await.unwrap();
+        let open_message = repository
+            .create_open_message(
+                Epoch(3),
+                &SignedEntityType::MithrilStakeDistribution(Epoch(3)),
+                &ProtocolMessage::new(),
+            )
+            .await
+            .unwrap();
+        let single_signature = SingleSignatureRecord {
+            open_message_id: open_message.open_message_id,
+            signer_id: signer.party_id.clone(),
+            lottery_indexes: vec![1, 5],
+            ..setup_single_signature_records(1, 1, 1).pop().unwrap()
+        };
+        connection
+            .fetch_first(IncrementSignerParticipationQuery::single_signature_sent(
+                open_message.epoch,
+                &single_signature,
+            ))
+            .unwrap();
+        insert_single_signatures_in_db(&connection, vec![single_signature]).unwrap();
+        let service = dep_builder.get_message_service().await.unwrap();
+
+        let response = service
+            .get_signer_participation_message(&signer.party_id)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            SignerParticipationMessage {
+                party_id: signer.party_id,
+                epochs: vec![SignerParticipationEpochMessage {
+                    epoch: Epoch(3),
+                    signatures_sent: 1,
+                    signatures_included: 0,
+                    lottery_wins: 2,
+                }],
+            },
+            response
+        );
+    }
 }
@@ -6,9 +6,11 @@
 //! * StakeEntity: fetches Cardano stake distribution information
 //! * Certifier: registers signers and create certificates once ready
 //! * SignedEntity: provides information about signed entities.
+//! * AggregatorEvent: broadcasts the events produced by the runtime.
 //!
 //! Each service is defined by a public API (a trait) that is used in the controllers (runtimes).
 
+mod aggregator_event;
 mod cardano_transactions_importer;
 mod certifier;
 mod epoch_service;
@@ -18,6 +20,7 @@ mod signed_entity;
 mod stake_distribution;
 mod upkeep;
 
+pub use aggregator_event::*;
 pub use cardano_transactions_importer::*;
 pub use certifier::*;
 pub use epoch_service::*;
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use slog_scope::info;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::task::JoinHandle;
 
 use mithril_common::{
@@ -23,6 +24,7 @@ use mithril_common::{
 use crate::{
     artifact_builder::ArtifactBuilder,
     database::{record::SignedEntityRecord, repository::SignedEntityStorer},
+    MetricsService,
 };
 
 #[cfg(test)]
@@ -90,6 +92,7 @@ pub struct MithrilSignedEntityService {
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
     cardano_stake_distribution_artifact_builder:
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl MithrilSignedEntityService {
@@ -109,6 +112,7 @@ impl MithrilSignedEntityService {
         cardano_stake_distribution_artifact_builder: Arc<
             dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>,
         >,
+        metrics_service: Arc<MetricsService>,
     ) -> Self {
         Self {
             signed_entity_storer,
@@ -117,6 +121,7 @@ impl MithrilSignedEntityService {
             cardano_transactions_artifact_builder,
             signed_entity_type_lock,
             cardano_stake_distribution_artifact_builder,
+            metrics_service,
         }
     }
 
@@ -131,6 +136,7 @@ impl MithrilSignedEntityService {
             "certificate_hash" => &certificate.hash
         );
 
+        let artifact_build_start = Instant::now();
         let mut remaining_retries = 2;
         let artifact = loop {
             remaining_retries -= 1;
@@ -144,6 +150,11 @@ impl MithrilSignedEntityService {
                 Ok(artifact) => break Ok(artifact),
             };
         }?;
+        self.metrics_service
+            .artifact_build_duration_seconds_histogram_observe(
+                (&signed_entity_type).into(),
+                artifact_build_start.elapsed(),
+            );
 
         let signed_entity = SignedEntityRecord {
             signed_entity_id: artifact.get_id(),
@@ -440,6 +451,7 @@ mod tests {
             MockArtifactBuilder<BlockNumber, CardanoTransactionsSnapshot>,
         mock_cardano_stake_distribution_artifact_builder:
             MockArtifactBuilder<Epoch, CardanoStakeDistribution>,
+        metrics_service: Arc<MetricsService>,
     }
 
     impl MockDependencyInjector {
@@ -462,6 +474,7 @@ mod tests {
                     Epoch,
                     CardanoStakeDistribution,
                 >::new(),
+                metrics_service: Arc::new(MetricsService::new().unwrap()),
             }
         }
 
@@ -473,6 +486,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(SignedEntityTypeLock::default()),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
+                self.metrics_service,
             )
         }
 
@@ -524,6 +538,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(SignedEntityTypeLock::default()),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
+                self.metrics_service,
             )
         }
 
@@ -745,6 +760,7 @@ mod tests {
                 .times(1)
                 .return_once(|_, _| Ok(artifact_cloned));
         }
+        let metrics_service = mock_container.metrics_service.clone();
         let artifact_builder_service = mock_container.build_artifact_builder_service();
 
         let certificate = fake_data::certificate("hash".to_string());
@@ -755,9 +771,15 @@ mod tests {
         let error_message_str = error_message.as_str();
 
         artifact_builder_service
-            .create_artifact_task(signed_entity_type, &certificate)
+            .create_artifact_task(signed_entity_type.clone(), &certificate)
             .await
             .expect(error_message_str);
+
+        assert_eq!(
+            1,
+            metrics_service
+                .artifact_build_duration_seconds_histogram_count_get((&signed_entity_type).into())
+        );
     }
 
     #[tokio::test]
@@ -4,12 +4,13 @@
 //!
 //! It is in charge of the following tasks:
 //! * free up space by executing vacuum and WAL checkpoint on the database
+//! * prune the events of the event store according to the event retention policy
 
 use std::sync::Arc;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use slog::{info, Logger};
+use slog::{debug, info, Logger};
 
 use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
 use mithril_common::StdResult;
@@ -17,6 +18,8 @@ use mithril_persistence::sqlite::{
     SqliteCleaner, SqliteCleaningTask, SqliteConnection, SqliteConnectionPool,
 };
 
+use crate::event_store::{EventRepository, EventRetentionPolicy};
+
 /// Define the service responsible for the upkeep of the application.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -33,6 +36,8 @@ pub struct AggregatorUpkeepService {
     main_db_connection: Arc<SqliteConnection>,
     cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+    event_repository: Arc<EventRepository>,
+    event_retention_policy: EventRetentionPolicy,
     logger: Logger,
 }
 
@@ -42,16 +47,49 @@ impl AggregatorUpkeepService {
         main_db_connection: Arc<SqliteConnection>,
         cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
         signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+        event_repository: Arc<EventRepository>,
+        event_retention_policy: EventRetentionPolicy,
         logger: Logger,
     ) -> Self {
         Self {
             main_db_connection,
             cardano_tx_connection_pool,
             signed_entity_type_lock,
+            event_repository,
+            event_retention_policy,
             logger,
         }
     }
 
+    async fn prune_event_store(&self) -> StdResult<()> {
+        if self.event_retention_policy.is_disabled() {
+            debug!(
+                self.logger,
+                "UpkeepService::No event retention policy - Skipping event store pruning"
+            );
+            return Ok(());
+        }
+
+        let event_repository = self.event_repository.clone();
+        let event_retention_policy = self.event_retention_policy.clone();
+        let event_pruning_logger = self.logger.clone();
+
+        // Run the pruning in another thread to avoid blocking the tokio runtime
+        let event_pruning_thread = tokio::task::spawn_blocking(move || -> StdResult<()> {
+            let deleted_events = event_repository.prune(&event_retention_policy)?;
+            info!(
+                event_pruning_logger,
+                "UpkeepService::Pruned {deleted_events} events from the event store"
+            );
+
+            Ok(())
+        });
+
+        event_pruning_thread
+            .await
+            .with_context(|| "Event store pruning thread crashed")?
+    }
+
     async fn upkeep_all_databases(&self) -> StdResult<()> {
         if self.signed_entity_type_lock.has_locked_entities().await {
             info!(
@@ -104,6 +142,10 @@ impl UpkeepService for AggregatorUpkeepService {
             .await
             .with_context(|| "Database upkeep failed")?;
 
+        self.prune_event_store()
+            .await
+            .with_context(|| "Event store pruning failed")?;
+
         info!(self.logger, "UpkeepService::end");
         Ok(())
     }
@@ -111,6 +153,8 @@ impl UpkeepService for AggregatorUpkeepService {
 
 #[cfg(test)]
 mod tests {
+    use chrono::{Duration, Utc};
+
     use mithril_common::entities::SignedEntityTypeDiscriminants;
     use mithril_common::test_utils::TempDir;
 
@@ -118,6 +162,8 @@ mod tests {
         cardano_tx_db_connection, cardano_tx_db_file_connection, main_db_connection,
         main_db_file_connection,
     };
+    use crate::event_store::test_helper::{event_store_db_connection, insert_event};
+    use crate::event_store::EventFilters;
     use crate::test_tools::TestLogger;
 
     use super::*;
@@ -144,6 +190,10 @@ mod tests {
                     cardano_tx_connection,
                 )),
                 Arc::new(SignedEntityTypeLock::default()),
+                Arc::new(EventRepository::new(Arc::new(
+                    event_store_db_connection().unwrap(),
+                ))),
+                EventRetentionPolicy::default(),
                 TestLogger::file(&log_path),
             );
 
@@ -185,6 +235,10 @@ mod tests {
                 Arc::new(main_db_connection().unwrap()),
                 Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
                 signed_entity_type_lock.clone(),
+                Arc::new(EventRepository::new(Arc::new(
+                    event_store_db_connection().unwrap(),
+                ))),
+                EventRetentionPolicy::default(),
                 TestLogger::file(&log_path),
             );
             service.run().await.expect("Upkeep service failed");
@@ -203,4 +257,45 @@ mod tests {
             0,
         );
     }
+
+    #[tokio::test]
+    async fn test_prune_event_store_with_retention_policy() {
+        let event_store_connection = Arc::new(event_store_db_connection().unwrap());
+        for days in [3, 2, 1] {
+            insert_event(
+                &event_store_connection,
+                Utc::now() - Duration::days(days),
+                "source",
+                "action",
+                &[],
+                "{}",
+            )
+            .unwrap();
+        }
+        let event_repository = Arc::new(EventRepository::new(event_store_connection));
+
+        let service = AggregatorUpkeepService::new(
+            Arc::new(main_db_connection().unwrap()),
+            Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
+            Arc::new(SignedEntityTypeLock::default()),
+            event_repository.clone(),
+            EventRetentionPolicy {
+                max_age: Some(Duration::hours(36)),
+                max_rows: None,
+            },
+            TestLogger::stdout(),
+        );
+        service.run().await.expect("Upkeep service failed");
+
+        let remaining_events = event_repository
+            .get_events(&EventFilters::default())
+            .unwrap();
+        assert_eq!(
+            vec![3],
+            remaining_events
+                .iter()
+                .map(|e| e.event_id)
+                .collect::<Vec<_>>()
+        );
+    }
 }
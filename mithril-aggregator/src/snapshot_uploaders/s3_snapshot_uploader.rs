@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use mithril_common::StdResult;
+use slog_scope::debug;
+use std::path::Path;
+
+use crate::snapshot_uploaders::{SnapshotLocation, SnapshotUploader};
+use crate::tools::{RemoteFileUploader, S3Bucket};
+
+/// S3SnapshotUploader is a snapshot uploader working with any store speaking the S3 API
+/// (AWS S3, MinIO, ...)
+pub struct S3SnapshotUploader {
+    bucket: S3Bucket,
+    file_uploader: Box<dyn RemoteFileUploader>,
+    cdn_domain: Option<String>,
+}
+
+impl S3SnapshotUploader {
+    /// S3SnapshotUploader factory
+    ///
+    /// If a `cdn_domain` is given the snapshot locations are built using it instead of the
+    /// bucket url.
+    pub fn new(
+        file_uploader: Box<dyn RemoteFileUploader>,
+        bucket: S3Bucket,
+        cdn_domain: Option<String>,
+    ) -> Self {
+        debug!("New S3SnapshotUploader created");
+        Self {
+            bucket,
+            file_uploader,
+            cdn_domain,
+        }
+    }
+}
+
+#[async_trait]
+impl SnapshotUploader for S3SnapshotUploader {
+    async fn upload_snapshot(&self, snapshot_filepath: &Path) -> StdResult<SnapshotLocation> {
+        let archive_name = snapshot_filepath.file_name().unwrap().to_str().unwrap();
+        let location = match &self.cdn_domain {
+            Some(domain) => format!("https://{domain}/{archive_name}"),
+            None => self.bucket.object_url(archive_name)?.to_string(),
+        };
+
+        self.file_uploader.upload_file(snapshot_filepath).await?;
+
+        Ok(location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::S3SnapshotUploader;
+    use crate::snapshot_uploaders::SnapshotUploader;
+    use crate::tools::{MockRemoteFileUploader, S3Bucket};
+    use anyhow::anyhow;
+    use std::path::Path;
+
+    fn file_uploader_ok() -> Box<MockRemoteFileUploader> {
+        let mut file_uploader = MockRemoteFileUploader::new();
+        file_uploader.expect_upload_file().returning(|_| Ok(()));
+        Box::new(file_uploader)
+    }
+
+    #[tokio::test]
+    async fn test_upload_snapshot_path_style_ok() {
+        let snapshot_uploader = S3SnapshotUploader::new(
+            file_uploader_ok(),
+            S3Bucket::new("http://minio.local:9000", "cardano-testnet", true).unwrap(),
+            None,
+        );
+        let snapshot_filepath = Path::new("test/snapshot.xxx.tar.gz");
+        let expected_location =
+            "http://minio.local:9000/cardano-testnet/snapshot.xxx.tar.gz".to_string();
+
+        let location = snapshot_uploader
+            .upload_snapshot(snapshot_filepath)
+            .await
+            .expect("s3 upload should not fail");
+
+        assert_eq!(expected_location, location);
+    }
+
+    #[tokio::test]
+    async fn test_upload_snapshot_virtual_hosted_style_ok() {
+        let snapshot_uploader = S3SnapshotUploader::new(
+            file_uploader_ok(),
+            S3Bucket::new(
+                &S3Bucket::aws_endpoint("eu-west-3"),
+                "cardano-testnet",
+                false,
+            )
+            .unwrap(),
+            None,
+        );
+        let snapshot_filepath = Path::new("test/snapshot.xxx.tar.gz");
+        let expected_location =
+            "https://cardano-testnet.s3.eu-west-3.amazonaws.com/snapshot.xxx.tar.gz".to_string();
+
+        let location = snapshot_uploader
+            .upload_snapshot(snapshot_filepath)
+            .await
+            .expect("s3 upload should not fail");
+
+        assert_eq!(expected_location, location);
+    }
+
+    #[tokio::test]
+    async fn test_upload_snapshot_using_cdn_domain_ok() {
+        let snapshot_uploader = S3SnapshotUploader::new(
+            file_uploader_ok(),
+            S3Bucket::new("http://minio.local:9000", "cardano-testnet", true).unwrap(),
+            Some("cdn.mithril.network".to_string()),
+        );
+        let snapshot_filepath = Path::new("test/snapshot.xxx.tar.gz");
+        let expected_location = "https://cdn.mithril.network/snapshot.xxx.tar.gz".to_string();
+
+        let location = snapshot_uploader
+            .upload_snapshot(snapshot_filepath)
+            .await
+            .expect("s3 upload should not fail");
+
+        assert_eq!(expected_location, location);
+    }
+
+    #[tokio::test]
+    async fn test_upload_snapshot_ko() {
+        let mut file_uploader = MockRemoteFileUploader::new();
+        file_uploader
+            .expect_upload_file()
+            .returning(|_| Err(anyhow!("unexpected error")));
+        let snapshot_uploader = S3SnapshotUploader::new(
+            Box::new(file_uploader),
+            S3Bucket::new("http://minio.local:9000", "cardano-testnet", true).unwrap(),
+            None,
+        );
+        let snapshot_filepath = Path::new("test/snapshot.xxx.tar.gz");
+
+        let result = snapshot_uploader
+            .upload_snapshot(snapshot_filepath)
+            .await
+            .expect_err("s3 upload should fail");
+        assert_eq!("unexpected error".to_string(), result.to_string());
+    }
+}
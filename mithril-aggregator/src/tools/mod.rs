@@ -5,6 +5,7 @@ mod genesis;
 #[cfg(test)]
 pub mod mocks;
 mod remote_file_uploader;
+mod s3_file_uploader;
 mod signer_importer;
 
 pub use certificates_hash_migrator::CertificatesHashMigrator;
@@ -12,6 +13,9 @@ pub use digest_helpers::extract_digest_from_path;
 pub use era::EraTools;
 pub use genesis::{GenesisTools, GenesisToolsDependency};
 pub use remote_file_uploader::{GcpFileUploader, RemoteFileUploader};
+pub use s3_file_uploader::{
+    S3Bucket, S3Credentials, S3FileUploader, S3_DEFAULT_MULTIPART_PART_SIZE, S3_DEFAULT_REGION,
+};
 pub use signer_importer::{
     CExplorerSignerRetriever, SignersImporter, SignersImporterPersister, SignersImporterRetriever,
 };
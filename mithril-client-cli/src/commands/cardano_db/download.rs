@@ -40,6 +40,13 @@ pub struct CardanoDbDownloadCommand {
     #[clap(long)]
     download_dir: Option<PathBuf>,
 
+    /// Directory where the downloaded bytes of the cardano db are persisted so an interrupted
+    /// download can be resumed by running the command again.
+    ///
+    /// The downloaded archive is stored in this directory until the download completes.
+    #[clap(long)]
+    partial_downloads_dir: Option<PathBuf>,
+
     /// Genesis Verification Key to check the certificate chain.
     #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
     genesis_verification_key: Option<String>,
@@ -64,11 +71,16 @@ impl CardanoDbDownloadCommand {
             ProgressOutputType::Tty
         };
         let progress_printer = ProgressPrinter::new(progress_output_type, 5);
-        let client = client_builder(&params)?
-            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
-                progress_output_type,
-            )))
-            .build()?;
+        let mut client_builder = client_builder(&params)?.add_feedback_receiver(Arc::new(
+            IndicatifFeedbackReceiver::new(progress_output_type),
+        ));
+        if let Some(partial_downloads_dir) = params.get("partial_downloads_dir") {
+            let partial_downloads_dir = Path::new(&partial_downloads_dir);
+            CardanoDbDownloadChecker::ensure_dir_exist(partial_downloads_dir)?;
+            client_builder =
+                client_builder.with_snapshot_partial_downloads_dir(partial_downloads_dir);
+        }
+        let client = client_builder.build()?;
 
         let get_list_of_artifact_ids = || async {
             let cardano_dbs = client.snapshot().list().await.with_context(|| {
@@ -325,6 +337,21 @@ impl Source for CardanoDbDownloadCommand {
             );
         }
 
+        if let Some(partial_downloads_dir) = self.partial_downloads_dir.clone() {
+            map.insert(
+                "partial_downloads_dir".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(partial_downloads_dir.to_str().ok_or_else(|| {
+                        config::ConfigError::Message(format!(
+                            "Could not read partial downloads directory: '{}'.",
+                            partial_downloads_dir.display()
+                        ))
+                    })?),
+                ),
+            );
+        }
+
         if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
             map.insert(
                 "genesis_verification_key".to_string(),
@@ -2,11 +2,17 @@ use anyhow::{anyhow, Context};
 use clap::Parser;
 use cli_table::{print_stdout, Cell, Table};
 use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use serde::Serialize;
 use slog_scope::debug;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use mithril_client::{
-    common::TransactionHash, CardanoTransactionsProofs, MessageBuilder, MithrilCertificate,
+    common::TransactionHash, CardanoTransactionsProofs, Client, MessageBuilder, MithrilCertificate,
     MithrilResult, VerifiedCardanoTransactions, VerifyCardanoTransactionsProofsError,
 };
 
@@ -20,13 +26,32 @@ pub struct CardanoTransactionsCertifyCommand {
     #[clap(long)]
     json: bool,
 
+    /// Enable CSV output of the certification report.
+    #[clap(long, conflicts_with = "json")]
+    csv: bool,
+
     /// Genesis Verification Key to check the certificate chain.
     #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
     genesis_verification_key: Option<String>,
 
     /// Hashes of the transactions to certify.
-    #[clap(value_delimiter = ',', required = true)]
+    #[clap(value_delimiter = ',', required_unless_present = "transactions_file")]
     transactions_hashes: Vec<String>,
+
+    /// File containing the hashes of the transactions to certify, separated by commas,
+    /// spaces or new lines.
+    ///
+    /// If `-` is specified as file, the hashes are read from the standard input.
+    #[clap(long)]
+    transactions_file: Option<PathBuf>,
+}
+
+/// Certification status of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TransactionCertification {
+    transaction_hash: TransactionHash,
+    certified: bool,
+    certificate_hash: Option<String>,
 }
 
 impl CardanoTransactionsCertifyCommand {
@@ -35,7 +60,7 @@ impl CardanoTransactionsCertifyCommand {
         let config = config_builder.add_source(self.clone()).build()?;
         let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
 
-        let progress_output_type = if self.json {
+        let progress_output_type = if self.json || self.csv {
             ProgressOutputType::JsonReporter
         } else {
             ProgressOutputType::Tty
@@ -47,118 +72,256 @@ impl CardanoTransactionsCertifyCommand {
             )))
             .build()?;
 
-        progress_printer.report_step(1, "Fetching a proof for the given transactions…")?;
-        let cardano_transaction_proof = client
-            .cardano_transaction()
-            .get_proofs(&self.transactions_hashes)
-            .await
-            .with_context(|| {
-                format!(
-                    "Can not get proof from aggregator, transactions hashes: '{:?}'",
-                    self.transactions_hashes
-                )
-            })?;
-        debug!(
-            "Got Proof from aggregator, proof: {:?}",
-            cardano_transaction_proof
-        );
+        let transactions_hashes = self.collect_transactions_hashes()?;
+
+        progress_printer.report_step(1, "Fetching proofs for the given transactions…")?;
+        let cardano_transaction_proofs =
+            Self::fetch_proofs_in_batches(&client, &transactions_hashes).await?;
 
         let verified_transactions =
-            Self::verify_proof_validity(2, &progress_printer, &cardano_transaction_proof)?;
+            Self::verify_proofs_validity(2, &progress_printer, &cardano_transaction_proofs)?;
 
         progress_printer.report_step(
             3,
-            "Fetching the associated certificate and verifying the certificate chain…",
+            "Fetching the associated certificates and verifying the certificate chains…",
         )?;
-        let certificate = client
-            .certificate()
-            .verify_chain(&cardano_transaction_proof.certificate_hash)
-            .await
-            .with_context(|| {
-                format!(
-                    "Can not verify the certificate chain from certificate_hash: '{}'",
-                    verified_transactions.certificate_hash()
-                )
-            })?;
+        let mut certificates: HashMap<String, MithrilCertificate> = HashMap::new();
+        for verified in &verified_transactions {
+            let certificate_hash = verified.certificate_hash();
+            if !certificates.contains_key(certificate_hash) {
+                let certificate = client
+                    .certificate()
+                    .verify_chain(certificate_hash)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Can not verify the certificate chain from certificate_hash: '{certificate_hash}'"
+                        )
+                    })?;
+                certificates.insert(certificate_hash.to_string(), certificate);
+            }
+        }
 
-        Self::verify_proof_match_certificate(
+        Self::verify_proofs_match_certificates(
             4,
             &progress_printer,
-            &certificate,
+            &certificates,
             &verified_transactions,
         )?;
 
-        Self::log_certify_information(
-            &verified_transactions,
-            &cardano_transaction_proof.non_certified_transactions,
-            self.json,
-        )
+        let report = Self::build_report(&transactions_hashes, &verified_transactions);
+        if self.csv {
+            print!("{}", Self::format_csv_report(&report));
+            Ok(())
+        } else {
+            Self::log_certify_information(&report, self.json)
+        }
+    }
+
+    fn collect_transactions_hashes(&self) -> MithrilResult<Vec<TransactionHash>> {
+        let mut transactions_hashes = self.transactions_hashes.clone();
+        if let Some(transactions_file) = &self.transactions_file {
+            let content = Self::read_transactions_file(transactions_file)?;
+            transactions_hashes.extend(Self::parse_transactions_hashes(&content));
+        }
+
+        let mut seen = HashSet::new();
+        transactions_hashes.retain(|hash| seen.insert(hash.clone()));
+
+        if transactions_hashes.is_empty() {
+            return Err(anyhow!("No transaction hash to certify was given"));
+        }
+
+        Ok(transactions_hashes)
+    }
+
+    fn read_transactions_file(transactions_file: &Path) -> MithrilResult<String> {
+        if transactions_file == Path::new("-") {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .with_context(|| "Can not read transactions hashes from the standard input")?;
+            Ok(content)
+        } else {
+            std::fs::read_to_string(transactions_file).with_context(|| {
+                format!(
+                    "Can not read transactions hashes file: '{}'",
+                    transactions_file.display()
+                )
+            })
+        }
     }
 
-    fn verify_proof_validity(
+    fn parse_transactions_hashes(content: &str) -> Vec<TransactionHash> {
+        content
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|hash| !hash.is_empty())
+            .map(|hash| hash.to_string())
+            .collect()
+    }
+
+    async fn fetch_proofs_in_batches(
+        client: &Client,
+        transactions_hashes: &[TransactionHash],
+    ) -> MithrilResult<Vec<CardanoTransactionsProofs>> {
+        let batch_size = client
+            .cardano_transaction()
+            .get_max_hashes_allowed_by_request()
+            .await?
+            .unwrap_or(transactions_hashes.len())
+            .max(1);
+
+        let mut cardano_transaction_proofs = vec![];
+        for batch in transactions_hashes.chunks(batch_size) {
+            let cardano_transaction_proof = client
+                .cardano_transaction()
+                .get_proofs(batch)
+                .await
+                .with_context(|| {
+                    format!("Can not get proof from aggregator, transactions hashes: '{batch:?}'")
+                })?;
+            debug!(
+                "Got Proof from aggregator, proof: {:?}",
+                cardano_transaction_proof
+            );
+            cardano_transaction_proofs.push(cardano_transaction_proof);
+        }
+
+        Ok(cardano_transaction_proofs)
+    }
+
+    fn verify_proofs_validity(
         step_number: u16,
         progress_printer: &ProgressPrinter,
-        cardano_transaction_proof: &CardanoTransactionsProofs,
-    ) -> MithrilResult<VerifiedCardanoTransactions> {
-        progress_printer.report_step(step_number, "Verifying the proof…")?;
-        match cardano_transaction_proof.verify() {
-            Ok(verified_transactions) => Ok(verified_transactions),
-            Err(VerifyCardanoTransactionsProofsError::NoCertifiedTransaction) => Err(anyhow!(
-                "Mithril could not certify any of the given transactions.
-
-Mithril may not have signed those transactions yet, please try again later."
-            )),
-            err => err.with_context(|| "Proof verification failed"),
+        cardano_transaction_proofs: &[CardanoTransactionsProofs],
+    ) -> MithrilResult<Vec<VerifiedCardanoTransactions>> {
+        progress_printer.report_step(step_number, "Verifying the proofs…")?;
+        let mut verified_transactions = vec![];
+        for cardano_transaction_proof in cardano_transaction_proofs {
+            match cardano_transaction_proof.verify() {
+                Ok(verified) => verified_transactions.push(verified),
+                Err(VerifyCardanoTransactionsProofsError::NoCertifiedTransaction) => {}
+                Err(err) => return Err(err).with_context(|| "Proof verification failed"),
+            }
         }
+
+        Ok(verified_transactions)
     }
 
-    fn verify_proof_match_certificate(
+    fn verify_proofs_match_certificates(
         step_number: u16,
         progress_printer: &ProgressPrinter,
-        certificate: &MithrilCertificate,
-        verified_transactions: &VerifiedCardanoTransactions,
+        certificates: &HashMap<String, MithrilCertificate>,
+        verified_transactions: &[VerifiedCardanoTransactions],
     ) -> MithrilResult<()> {
         progress_printer.report_step(
             step_number,
-            "Verify that the proof is signed in the associated certificate",
+            "Verify that the proofs are signed in the associated certificates",
         )?;
-        let message = MessageBuilder::new()
-            .compute_cardano_transactions_proofs_message(certificate, verified_transactions);
-        if !certificate.match_message(&message) {
-            return Err(anyhow!(
-                "Proof and certificate don't match (certificate hash = '{}').",
-                certificate.hash
-            ));
+        for verified in verified_transactions {
+            let certificate = certificates
+                .get(verified.certificate_hash())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No verified certificate for hash '{}'",
+                        verified.certificate_hash()
+                    )
+                })?;
+            let message = MessageBuilder::new()
+                .compute_cardano_transactions_proofs_message(certificate, verified);
+            if !certificate.match_message(&message) {
+                return Err(anyhow!(
+                    "Proof and certificate don't match (certificate hash = '{}').",
+                    certificate.hash
+                ));
+            }
         }
 
         Ok(())
     }
 
+    fn build_report(
+        transactions_hashes: &[TransactionHash],
+        verified_transactions: &[VerifiedCardanoTransactions],
+    ) -> Vec<TransactionCertification> {
+        let certified: HashMap<&str, &str> = verified_transactions
+            .iter()
+            .flat_map(|verified| {
+                verified
+                    .certified_transactions()
+                    .iter()
+                    .map(|tx| (tx.as_str(), verified.certificate_hash()))
+            })
+            .collect();
+
+        transactions_hashes
+            .iter()
+            .map(|tx| {
+                let certificate_hash = certified.get(tx.as_str()).map(|h| h.to_string());
+                TransactionCertification {
+                    transaction_hash: tx.clone(),
+                    certified: certificate_hash.is_some(),
+                    certificate_hash,
+                }
+            })
+            .collect()
+    }
+
+    fn format_csv_report(report: &[TransactionCertification]) -> String {
+        let mut csv = "transaction_hash,certified,certificate_hash\n".to_string();
+        for line in report {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                line.transaction_hash,
+                line.certified,
+                line.certificate_hash.as_deref().unwrap_or_default()
+            ));
+        }
+
+        csv
+    }
+
     fn log_certify_information(
-        verified_transactions: &VerifiedCardanoTransactions,
-        non_certified_transactions: &[TransactionHash],
+        report: &[TransactionCertification],
         json_output: bool,
     ) -> MithrilResult<()> {
+        let (certified_transactions, non_certified_transactions): (Vec<_>, Vec<_>) =
+            report.iter().partition(|line| line.certified);
+        let certified_transactions: Vec<_> = certified_transactions
+            .into_iter()
+            .map(|line| &line.transaction_hash)
+            .collect();
+        let non_certified_transactions: Vec<_> = non_certified_transactions
+            .into_iter()
+            .map(|line| &line.transaction_hash)
+            .collect();
+
         if json_output {
             println!(
-                r#"{{"certified_transactions": {}, "non_certified_transactions": {}}}"#,
-                serde_json::to_string(verified_transactions.certified_transactions())?,
-                serde_json::to_string(non_certified_transactions)?,
+                r#"{{"certified_transactions": {}, "non_certified_transactions": {}, "report": {}}}"#,
+                serde_json::to_string(&certified_transactions)?,
+                serde_json::to_string(&non_certified_transactions)?,
+                serde_json::to_string(report)?,
             );
         } else {
-            println!(
-                r###"Cardano transactions proof has been successfully signed in the associated Mithril certificate."###,
-            );
-
-            if !non_certified_transactions.is_empty() {
+            if certified_transactions.is_empty() {
                 println!(
-                    r###"
-No proof could be computed for some Cardano transactions. Mithril may not have signed those transactions yet, please try again later."###,
+                    r###"Mithril could not certify any of the given transactions. Mithril may not have signed those transactions yet, please try again later."###,
+                );
+            } else {
+                println!(
+                    r###"Cardano transactions proof has been successfully signed in the associated Mithril certificate."###,
                 );
+
+                if !non_certified_transactions.is_empty() {
+                    println!(
+                        r###"
+No proof could be computed for some Cardano transactions. Mithril may not have signed those transactions yet, please try again later."###,
+                    );
+                }
             }
 
-            let result_table = verified_transactions
-                .certified_transactions()
+            let result_table = certified_transactions
                 .iter()
                 .map(|tx| {
                     vec![
@@ -201,3 +364,164 @@ impl Source for CardanoTransactionsCertifyCommand {
         Ok(map)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mithril_client::{common::BlockNumber, CardanoTransactionsSetProof};
+
+    use super::*;
+
+    fn verified_transactions(
+        certificate_hash: &str,
+        transactions_hashes: &[&str],
+    ) -> VerifiedCardanoTransactions {
+        let set_proof = CardanoTransactionsSetProof::dummy();
+        let mut proofs = vec![];
+        for hash in transactions_hashes {
+            let mut proof = set_proof.clone();
+            proof.transactions_hashes = vec![hash.to_string()];
+            proofs.push(proof);
+        }
+
+        CardanoTransactionsProofs::new(certificate_hash, proofs, vec![], BlockNumber(99))
+            .verify()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_transactions_hashes_separated_by_commas_spaces_or_new_lines() {
+        let hashes = CardanoTransactionsCertifyCommand::parse_transactions_hashes(
+            "tx-1,tx-2\ntx-3 tx-4\r\n\n  tx-5,,\n",
+        );
+
+        assert_eq!(vec!["tx-1", "tx-2", "tx-3", "tx-4", "tx-5"], hashes);
+    }
+
+    #[test]
+    fn collect_transactions_hashes_from_arguments_and_file_without_duplicates() {
+        let transactions_file = mithril_common::test_utils::TempDir::create(
+            "cardano_transaction_certify",
+            "collect_transactions_hashes_from_arguments_and_file_without_duplicates",
+        )
+        .join("hashes.txt");
+        std::fs::write(&transactions_file, "tx-2\ntx-3\ntx-1\n").unwrap();
+        let command = CardanoTransactionsCertifyCommand {
+            json: false,
+            csv: false,
+            genesis_verification_key: None,
+            transactions_hashes: vec!["tx-1".to_string(), "tx-2".to_string()],
+            transactions_file: Some(transactions_file),
+        };
+
+        let hashes = command.collect_transactions_hashes().unwrap();
+
+        assert_eq!(vec!["tx-1", "tx-2", "tx-3"], hashes);
+    }
+
+    #[test]
+    fn build_report_for_each_given_transaction_hash() {
+        let transactions_hashes: Vec<String> = ["tx-1", "tx-2", "tx-3", "tx-4"]
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+        let verified = vec![
+            verified_transactions("cert-1", &["tx-1"]),
+            verified_transactions("cert-2", &["tx-3"]),
+        ];
+
+        let report =
+            CardanoTransactionsCertifyCommand::build_report(&transactions_hashes, &verified);
+
+        assert_eq!(
+            vec![
+                TransactionCertification {
+                    transaction_hash: "tx-1".to_string(),
+                    certified: true,
+                    certificate_hash: Some("cert-1".to_string()),
+                },
+                TransactionCertification {
+                    transaction_hash: "tx-2".to_string(),
+                    certified: false,
+                    certificate_hash: None,
+                },
+                TransactionCertification {
+                    transaction_hash: "tx-3".to_string(),
+                    certified: true,
+                    certificate_hash: Some("cert-2".to_string()),
+                },
+                TransactionCertification {
+                    transaction_hash: "tx-4".to_string(),
+                    certified: false,
+                    certificate_hash: None,
+                },
+            ],
+            report
+        );
+    }
+
+    #[test]
+    fn verify_proofs_validity_succeeds_when_no_transaction_is_certified() {
+        let proofs = vec![CardanoTransactionsProofs::new(
+            "cert-1",
+            vec![],
+            vec!["tx-1".to_string(), "tx-2".to_string()],
+            BlockNumber(99),
+        )];
+
+        let verified = CardanoTransactionsCertifyCommand::verify_proofs_validity(
+            2,
+            &ProgressPrinter::new(ProgressOutputType::Hidden, 4),
+            &proofs,
+        )
+        .unwrap();
+
+        assert!(verified.is_empty());
+    }
+
+    #[test]
+    fn build_report_with_all_transactions_non_certified_when_none_is_verified() {
+        let transactions_hashes: Vec<String> =
+            ["tx-1", "tx-2"].iter().map(|h| h.to_string()).collect();
+
+        let report = CardanoTransactionsCertifyCommand::build_report(&transactions_hashes, &[]);
+
+        assert_eq!(
+            vec![
+                TransactionCertification {
+                    transaction_hash: "tx-1".to_string(),
+                    certified: false,
+                    certificate_hash: None,
+                },
+                TransactionCertification {
+                    transaction_hash: "tx-2".to_string(),
+                    certified: false,
+                    certificate_hash: None,
+                },
+            ],
+            report
+        );
+    }
+
+    #[test]
+    fn format_report_as_csv() {
+        let report = vec![
+            TransactionCertification {
+                transaction_hash: "tx-1".to_string(),
+                certified: true,
+                certificate_hash: Some("cert-1".to_string()),
+            },
+            TransactionCertification {
+                transaction_hash: "tx-2".to_string(),
+                certified: false,
+                certificate_hash: None,
+            },
+        ];
+
+        let csv = CardanoTransactionsCertifyCommand::format_csv_report(&report);
+
+        assert_eq!(
+            "transaction_hash,certified,certificate_hash\ntx-1,true,cert-1\ntx-2,false,\n",
+            csv
+        );
+    }
+}
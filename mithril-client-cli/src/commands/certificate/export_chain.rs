@@ -0,0 +1,174 @@
+use anyhow::Context;
+use clap::Parser;
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use slog_scope::logger;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
+use crate::{commands::client_builder, configuration::ConfigParameters, utils::ExpanderUtils};
+use mithril_client::{
+    certificate_client::{CertificateVerifier, MithrilCertificateVerifier},
+    feedback::FeedbackSender,
+    MithrilResult,
+};
+
+/// Export the certificate chain starting from a given certificate back to the genesis
+/// certificate into a bundle file, that can be verified later without any access to an
+/// aggregator. If the verification of the bundle fails, the file is not persisted.
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateExportChainCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Hash of the certificate to start the export from.
+    ///
+    /// If `latest` is specified as hash, the export will start from the latest certificate.
+    hash: String,
+
+    /// Directory where the certificate chain bundle will be written.
+    #[clap(long)]
+    download_dir: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CertificateExportChainCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder
+            .set_default("download_dir", ".")?
+            .add_source(self.clone())
+            .build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let download_dir = &params.require("download_dir")?;
+        let download_dir = Path::new(download_dir);
+
+        let progress_output_type = if self.json {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 3);
+        let client = client_builder(&params)?.build()?;
+
+        let get_list_of_artifact_ids = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        progress_printer.report_step(
+            1,
+            &format!(
+                "Fetching the certificate chain of certificate '{}' …",
+                self.hash
+            ),
+        )?;
+        let certificate_hash =
+            ExpanderUtils::expand_eventual_id_alias(&self.hash, get_list_of_artifact_ids()).await?;
+        let bundle = client
+            .certificate()
+            .export_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!("Can not export the certificate chain of certificate_hash: '{certificate_hash}'")
+            })?;
+
+        progress_printer.report_step(2, "Verifying the exported certificate chain…")?;
+        let verifier = MithrilCertificateVerifier::from_bundle(
+            bundle.clone(),
+            &params.require("genesis_verification_key")?,
+            FeedbackSender::new(&[Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+            ))]),
+            logger(),
+        )?;
+        let head = bundle.head().cloned().with_context(|| {
+            format!("Exported certificate chain of certificate_hash '{certificate_hash}' is empty")
+        })?;
+        verifier.verify_chain(&head).await.with_context(|| {
+            format!("Can not verify the exported certificate chain of certificate_hash: '{certificate_hash}'")
+        })?;
+
+        progress_printer.report_step(3, "Writing the certificate chain bundle to a file")?;
+        if !download_dir.is_dir() {
+            std::fs::create_dir_all(download_dir)?;
+        }
+        let filepath = PathBuf::new()
+            .join(download_dir)
+            .join(format!("certificate_chain-{certificate_hash}.json"));
+        std::fs::write(
+            &filepath,
+            serde_json::to_string(&bundle).with_context(|| {
+                format!(
+                    "Can not serialize the certificate chain of certificate '{certificate_hash}'"
+                )
+            })?,
+        )?;
+
+        if self.json {
+            println!(
+                r#"{{"certificate_hash": "{}", "certificates": {}, "filepath": "{}"}}"#,
+                certificate_hash,
+                bundle.len(),
+                filepath.display()
+            );
+        } else {
+            println!(
+                "Certificate chain of certificate '{}' ({} certificates) has been verified and saved as '{}'.",
+                certificate_hash,
+                bundle.len(),
+                filepath.display()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Source for CertificateExportChainCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(download_dir) = self.download_dir.clone() {
+            map.insert(
+                "download_dir".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(download_dir.to_str().ok_or_else(|| {
+                        config::ConfigError::Message(format!(
+                            "Could not read download directory: '{}'.",
+                            download_dir.display()
+                        ))
+                    })?),
+                ),
+            );
+        }
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
@@ -0,0 +1,60 @@
+use clap::Parser;
+use cli_table::{format::Justify, print_stdout, Cell, Table};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use crate::{commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters};
+use mithril_client::MithrilResult;
+
+/// Clap command to list the latest certificates
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateListCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+}
+
+impl CertificateListCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+        let lines = client.certificate().list().await?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&lines)?);
+        } else {
+            let lines = lines
+                .into_iter()
+                .map(|item| {
+                    vec![
+                        format!("{}", item.epoch).cell(),
+                        item.signed_entity_type.to_string().cell(),
+                        item.hash.cell(),
+                        item.previous_hash.cell(),
+                        item.metadata.total_signers.cell(),
+                        item.metadata.sealed_at.to_string().cell(),
+                    ]
+                })
+                .collect::<Vec<_>>()
+                .table()
+                .title(vec![
+                    "Epoch".cell(),
+                    "Signed Entity Type".cell(),
+                    "Hash".cell(),
+                    "Previous Hash".cell(),
+                    "Signers".cell().justify(Justify::Right),
+                    "Sealed".cell().justify(Justify::Right),
+                ]);
+            print_stdout(lines)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+//! Commands for the certificates of the Mithril certificate chain
+mod export_chain;
+mod list;
+mod show;
+mod verify_chain;
+
+pub use export_chain::*;
+pub use list::*;
+pub use show::*;
+pub use verify_chain::*;
+
+use clap::Subcommand;
+use config::{builder::DefaultState, ConfigBuilder};
+use mithril_client::MithrilResult;
+
+/// Certificate management (alias: cert)
+#[derive(Subcommand, Debug, Clone)]
+pub enum CertificateCommands {
+    /// List the latest certificates
+    #[clap(arg_required_else_help = false)]
+    List(CertificateListCommand),
+
+    /// Show detailed information about a certificate
+    #[clap(arg_required_else_help = false)]
+    Show(CertificateShowCommand),
+
+    /// Verify the certificate chain starting from the given certificate
+    #[clap(arg_required_else_help = false)]
+    VerifyChain(CertificateVerifyChainCommand),
+
+    /// Export the certificate chain starting from the given certificate to a bundle file
+    #[clap(arg_required_else_help = false)]
+    ExportChain(CertificateExportChainCommand),
+}
+
+impl CertificateCommands {
+    /// Execute certificate command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Show(cmd) => cmd.execute(config_builder).await,
+            Self::VerifyChain(cmd) => cmd.execute(config_builder).await,
+            Self::ExportChain(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
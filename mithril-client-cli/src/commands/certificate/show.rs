@@ -0,0 +1,118 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use cli_table::{print_stdout, Cell, Table};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use crate::{
+    commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters,
+    utils::ExpanderUtils,
+};
+use mithril_client::MithrilResult;
+
+/// Clap command to show a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateShowCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Certificate hash.
+    ///
+    /// If `latest` is specified as hash, the command will return the latest certificate.
+    hash: String,
+}
+
+impl CertificateShowCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.json
+    }
+
+    /// Certificate Show command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+
+        let get_list_of_artifact_ids = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        let certificate = client
+            .certificate()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(&self.hash, get_list_of_artifact_ids())
+                    .await?,
+            )
+            .await?
+            .ok_or_else(|| anyhow!("Certificate not found for hash: '{}'", &self.hash))?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&certificate)?);
+        } else {
+            let certificate_table = vec![
+                vec!["Hash".cell(), certificate.hash.clone().cell()],
+                vec![
+                    "Previous Hash".cell(),
+                    certificate.previous_hash.clone().cell(),
+                ],
+                vec!["Epoch".cell(), format!("{}", &certificate.epoch).cell()],
+                vec![
+                    "Signed Entity Type".cell(),
+                    certificate.signed_entity_type.to_string().cell(),
+                ],
+                vec![
+                    "Network".cell(),
+                    certificate.metadata.network.clone().cell(),
+                ],
+                vec![
+                    "Protocol Version".cell(),
+                    certificate.metadata.protocol_version.clone().cell(),
+                ],
+                vec![
+                    "Protocol Parameters".cell(),
+                    format!(
+                        "k={}, m={}, phi_f={}",
+                        certificate.metadata.protocol_parameters.k,
+                        certificate.metadata.protocol_parameters.m,
+                        certificate.metadata.protocol_parameters.phi_f
+                    )
+                    .cell(),
+                ],
+                vec![
+                    "Signers".cell(),
+                    format!("{}", certificate.metadata.signers.len()).cell(),
+                ],
+                vec![
+                    "Initiated".cell(),
+                    certificate.metadata.initiated_at.to_string().cell(),
+                ],
+                vec![
+                    "Sealed".cell(),
+                    certificate.metadata.sealed_at.to_string().cell(),
+                ],
+                vec![
+                    "Signed Message".cell(),
+                    certificate.signed_message.clone().cell(),
+                ],
+                vec![
+                    "Genesis".cell(),
+                    format!("{}", !certificate.genesis_signature.is_empty()).cell(),
+                ],
+            ]
+            .table();
+
+            print_stdout(certificate_table)?
+        }
+
+        Ok(())
+    }
+}
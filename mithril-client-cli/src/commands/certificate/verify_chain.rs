@@ -0,0 +1,292 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value, ValueKind};
+use slog_scope::logger;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
+use crate::{commands::client_builder, configuration::ConfigParameters, utils::ExpanderUtils};
+use mithril_client::{
+    certificate_chain_bundle::CertificateChainBundle,
+    certificate_client::{CertificateVerifier, MithrilCertificateVerifier},
+    feedback::FeedbackSender,
+    MithrilCertificate, MithrilResult,
+};
+
+/// Clap command to verify the certificate chain starting from a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateVerifyChainCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Hash of the certificate to start the verification from.
+    ///
+    /// If `latest` is specified as hash, the verification will start from the latest certificate.
+    hash: String,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+
+    /// Certificate chain bundle file, exported with the `export-chain` command, to verify the
+    /// chain from without any access to the aggregator.
+    ///
+    /// If `latest` is specified as hash, the verification will start from the head of the bundle.
+    #[clap(long)]
+    bundle: Option<PathBuf>,
+}
+
+impl CertificateVerifyChainCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.add_source(self.clone()).build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+
+        let progress_output_type = if self.json {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 2);
+
+        let certificate = match &self.bundle {
+            Some(bundle_path) => {
+                self.verify_chain_from_bundle(
+                    bundle_path,
+                    &params,
+                    progress_output_type,
+                    &progress_printer,
+                )
+                .await?
+            }
+            None => {
+                self.verify_chain_from_aggregator(&params, progress_output_type, &progress_printer)
+                    .await?
+            }
+        };
+
+        if self.json {
+            println!(
+                r#"{{"certificate_hash": "{}", "epoch": {}, "verified": true}}"#,
+                certificate.hash, certificate.epoch
+            );
+        } else {
+            println!(
+                "Certificate chain starting from certificate '{}' (epoch {}) has been verified.",
+                certificate.hash, certificate.epoch
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn verify_chain_from_aggregator(
+        &self,
+        params: &ConfigParameters,
+        progress_output_type: ProgressOutputType,
+        progress_printer: &ProgressPrinter,
+    ) -> MithrilResult<MithrilCertificate> {
+        let client = client_builder(params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+            )))
+            .build()?;
+
+        let get_list_of_artifact_ids = || async {
+            let certificates = client.certificate().list().await.with_context(|| {
+                "Can not get the list of certificates while retrieving the latest certificate hash"
+            })?;
+
+            Ok(certificates
+                .iter()
+                .map(|certificate| certificate.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        progress_printer.report_step(1, &format!("Fetching certificate '{}' …", self.hash))?;
+        let certificate_hash =
+            ExpanderUtils::expand_eventual_id_alias(&self.hash, get_list_of_artifact_ids()).await?;
+
+        progress_printer.report_step(2, "Verifying the certificate chain…")?;
+        client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!("Can not verify the certificate chain from certificate_hash: '{certificate_hash}'")
+            })
+    }
+
+    async fn verify_chain_from_bundle(
+        &self,
+        bundle_path: &Path,
+        params: &ConfigParameters,
+        progress_output_type: ProgressOutputType,
+        progress_printer: &ProgressPrinter,
+    ) -> MithrilResult<MithrilCertificate> {
+        progress_printer.report_step(
+            1,
+            &format!(
+                "Reading certificate chain bundle '{}' …",
+                bundle_path.display()
+            ),
+        )?;
+        let bundle: CertificateChainBundle =
+            serde_json::from_str(&std::fs::read_to_string(bundle_path).with_context(|| {
+                format!(
+                    "Can not read certificate chain bundle file: '{}'",
+                    bundle_path.display()
+                )
+            })?)
+            .with_context(|| {
+                format!(
+                    "Can not deserialize certificate chain bundle file: '{}'",
+                    bundle_path.display()
+                )
+            })?;
+
+        let certificate = if self.hash.to_lowercase() == "latest" {
+            bundle.head()
+        } else {
+            bundle.get(&self.hash)
+        }
+        .cloned()
+        .ok_or_else(|| {
+            anyhow!(
+                "Certificate '{}' not found in certificate chain bundle: '{}'",
+                self.hash,
+                bundle_path.display()
+            )
+        })?;
+
+        progress_printer.report_step(2, "Verifying the certificate chain…")?;
+        let verifier = MithrilCertificateVerifier::from_bundle(
+            bundle,
+            &params.require("genesis_verification_key")?,
+            FeedbackSender::new(&[Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+            ))]),
+            logger(),
+        )?;
+        verifier.verify_chain(&certificate).await.with_context(|| {
+            format!(
+                "Can not verify the certificate chain from certificate_hash: '{}'",
+                certificate.hash
+            )
+        })?;
+
+        Ok(certificate)
+    }
+}
+
+impl Source for CertificateVerifyChainCommand {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        let mut map = Map::new();
+        let namespace = "clap arguments".to_string();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(genesis_verification_key)),
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use config::Config;
+    use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn write_bundle(
+        test_name: &str,
+        certificates_to_keep: usize,
+    ) -> (PathBuf, String, MithrilCertificate) {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let genesis_verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let certificates: Vec<MithrilCertificate> = chain
+            .into_iter()
+            .take(certificates_to_keep)
+            .map(|c| c.try_into().unwrap())
+            .collect();
+        let head = certificates[0].clone();
+        let bundle_path =
+            TempDir::create("certificate_verify_chain", test_name).join("bundle.json");
+        std::fs::write(
+            &bundle_path,
+            serde_json::to_string(&CertificateChainBundle::new(certificates)).unwrap(),
+        )
+        .unwrap();
+
+        (bundle_path, genesis_verification_key, head)
+    }
+
+    fn command(
+        hash: &str,
+        genesis_verification_key: String,
+        bundle: PathBuf,
+    ) -> CertificateVerifyChainCommand {
+        CertificateVerifyChainCommand {
+            json: true,
+            hash: hash.to_string(),
+            genesis_verification_key: Some(genesis_verification_key),
+            bundle: Some(bundle),
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_chain_from_bundle_without_aggregator() {
+        let (bundle_path, genesis_verification_key, head) =
+            write_bundle("verify_chain_from_bundle_without_aggregator", 20);
+
+        for hash in ["latest", &head.hash] {
+            command(hash, genesis_verification_key.clone(), bundle_path.clone())
+                .execute(Config::builder())
+                .await
+                .unwrap_or_else(|e| panic!("verification from '{hash}' should succeed: {e:?}"));
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_chain_from_incomplete_bundle_fails() {
+        let (bundle_path, genesis_verification_key, _head) =
+            write_bundle("verify_chain_from_incomplete_bundle_fails", 3);
+
+        command("latest", genesis_verification_key, bundle_path)
+            .execute(Config::builder())
+            .await
+            .expect_err("verification of an incomplete bundle should fail");
+    }
+
+    #[tokio::test]
+    async fn verify_chain_from_bundle_fails_if_hash_is_not_in_bundle() {
+        let (bundle_path, genesis_verification_key, _head) = write_bundle(
+            "verify_chain_from_bundle_fails_if_hash_is_not_in_bundle",
+            20,
+        );
+
+        command("unknown-hash", genesis_verification_key, bundle_path)
+            .execute(Config::builder())
+            .await
+            .expect_err("verification from a hash missing from the bundle should fail");
+    }
+}
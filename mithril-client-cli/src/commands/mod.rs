@@ -4,7 +4,9 @@
 //!
 
 pub mod cardano_db;
+pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
+pub mod certificate;
 mod deprecation;
 pub mod mithril_stake_distribution;
 
@@ -20,6 +22,7 @@ pub(crate) fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientB
         &params.require("aggregator_endpoint")?,
         &params.require("genesis_verification_key")?,
     )
+    .with_client_type("CLI")
     .with_logger(logger());
 
     Ok(builder)
@@ -41,6 +44,7 @@ pub(crate) fn client_builder_with_fallback_genesis_key(
             fallback_genesis_verification_key,
         ),
     )
+    .with_client_type("CLI")
     .with_logger(logger());
 
     Ok(builder)
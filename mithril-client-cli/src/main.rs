@@ -15,7 +15,8 @@ use mithril_client::MithrilResult;
 use mithril_doc::{Documenter, GenerateDocCommands, StructDoc};
 
 use mithril_client_cli::commands::{
-    cardano_db::CardanoDbCommands, cardano_transaction::CardanoTransactionCommands,
+    cardano_db::CardanoDbCommands, cardano_stake_distribution::CardanoStakeDistributionCommands,
+    cardano_transaction::CardanoTransactionCommands, certificate::CertificateCommands,
     mithril_stake_distribution::MithrilStakeDistributionCommands, DeprecatedCommand, Deprecation,
 };
 use mithril_client_cli::ClapError;
@@ -78,7 +79,7 @@ pub struct Args {
     #[example = "`./mithril-client.log`"]
     log_output: Option<String>,
 
-    /// Enable unstable commands (such as Cardano Transactions)
+    /// Enable unstable commands (such as Cardano Transactions or Cardano Stake Distribution)
     #[clap(long)]
     unstable: bool,
 }
@@ -190,6 +191,12 @@ enum ArtifactCommands {
     #[clap(subcommand, alias("ctx"))]
     CardanoTransaction(CardanoTransactionCommands),
 
+    #[clap(subcommand, alias("csd"))]
+    CardanoStakeDistribution(CardanoStakeDistributionCommands),
+
+    #[clap(subcommand, alias("cert"))]
+    Certificate(CertificateCommands),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -215,6 +222,19 @@ impl ArtifactCommands {
                     ctx.execute(config_builder).await
                 }
             }
+            Self::CardanoStakeDistribution(cmd) => {
+                if !unstable_enabled {
+                    Err(anyhow::anyhow!(
+                        "The \"cardano-stake-distribution\" subcommand is only accepted using the \
+                        --unstable flag.\n \
+                    \n \
+                    ie: \"mithril-client --unstable cardano-stake-distribution list\""
+                    ))
+                } else {
+                    cmd.execute(config_builder).await
+                }
+            }
+            Self::Certificate(cmd) => cmd.execute(config_builder).await,
             Self::GenerateDoc(cmd) => cmd
                 .execute(&mut Args::command())
                 .map_err(|message| anyhow!(message)),
@@ -253,4 +273,42 @@ mod tests {
             .await
             .expect_err("Should fail if unstable flag missing");
     }
+
+    #[tokio::test]
+    async fn fail_if_cardano_stake_distribution_command_is_used_without_unstable_flag() {
+        let args =
+            Args::try_parse_from(["mithril-client", "cardano-stake-distribution", "list"]).unwrap();
+
+        args.execute()
+            .await
+            .expect_err("Should fail if unstable flag missing");
+    }
+
+    #[test]
+    fn cardano_stake_distribution_download_requires_either_a_hash_or_an_epoch() {
+        Args::try_parse_from(["mithril-client", "csd", "download"])
+            .expect_err("Should fail without hash or epoch");
+        Args::try_parse_from(["mithril-client", "csd", "download", "hash", "--epoch", "12"])
+            .expect_err("Should fail with both hash and epoch");
+
+        Args::try_parse_from(["mithril-client", "csd", "download", "latest"]).unwrap();
+        Args::try_parse_from(["mithril-client", "csd", "download", "--epoch", "12"]).unwrap();
+    }
+
+    #[test]
+    fn certificate_commands_are_available_with_their_alias() {
+        for command in ["certificate", "cert"] {
+            let args = Args::try_parse_from(["mithril-client", command, "verify-chain", "latest"])
+                .unwrap();
+
+            assert!(
+                matches!(
+                    args.command,
+                    ArtifactCommands::Certificate(CertificateCommands::VerifyChain(_))
+                ),
+                "unexpected command parsed for '{command}': {:?}",
+                args.command
+            );
+        }
+    }
 }
@@ -58,6 +58,22 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                     progress_reporter.report(downloaded_bytes);
                 }
             }
+            MithrilEvent::SnapshotDownloadLocationSelected {
+                download_id: _,
+                location,
+            } => {
+                slog_scope::debug!("Downloading snapshot from location '{location}'");
+            }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id: _,
+                downloaded_bytes,
+                size: _,
+            } => {
+                let download_progress_reporter = self.download_progress_reporter.read().await;
+                if let Some(progress_reporter) = download_progress_reporter.as_ref() {
+                    progress_reporter.report(downloaded_bytes);
+                }
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id: _ } => {
                 let mut download_progress_reporter = self.download_progress_reporter.write().await;
                 if let Some(progress_reporter) = download_progress_reporter.as_ref() {
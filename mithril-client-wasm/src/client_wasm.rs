@@ -73,6 +73,7 @@ impl MithrilClient {
     pub fn new(aggregator_endpoint: &str, genesis_verification_key: &str) -> MithrilClient {
         let feedback_receiver = Arc::new(JSBroadcastChannelFeedbackReceiver::new("mithril-client"));
         let client = ClientBuilder::aggregator(aggregator_endpoint, genesis_verification_key)
+            .with_client_type("WASM")
             .add_feedback_receiver(feedback_receiver)
             .build()
             .map_err(|err| format!("{err:?}"))
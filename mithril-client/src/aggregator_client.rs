@@ -12,6 +12,11 @@ use std::sync::Arc;
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
 use async_trait::async_trait;
+#[cfg(not(target_family = "wasm"))]
+use futures::stream::BoxStream;
+#[cfg(target_family = "wasm")]
+use futures::stream::LocalBoxStream;
+use futures::StreamExt;
 use reqwest::header::HeaderMap;
 use reqwest::{Response, StatusCode, Url};
 use semver::Version;
@@ -20,10 +25,10 @@ use thiserror::Error;
 use tokio::sync::RwLock;
 
 use mithril_common::entities::{ClientError, ServerError};
-use mithril_common::MITHRIL_API_VERSION_HEADER;
+use mithril_common::{MITHRIL_API_VERSION_HEADER, MITHRIL_CLIENT_TYPE_HEADER};
 
 use crate::common::Epoch;
-use crate::{MithrilError, MithrilResult};
+use crate::{ListFilters, MithrilError, MithrilResult};
 
 /// Error tied with the Aggregator client
 #[derive(Error, Debug)]
@@ -48,6 +53,9 @@ pub enum AggregatorClientError {
 /// What can be read from an [AggregatorClient].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AggregatorRequest {
+    /// Get the features advertised by the aggregator
+    GetAggregatorFeatures,
+
     /// Get a specific [certificate][crate::MithrilCertificate] from the aggregator
     GetCertificate {
         /// Hash of the certificate to retrieve
@@ -55,6 +63,11 @@ pub enum AggregatorRequest {
     },
     /// Lists the aggregator [certificates][crate::MithrilCertificate]
     ListCertificates,
+    /// Lists a page of the aggregator [certificates][crate::MithrilCertificate] matching the given filters
+    ListCertificatesWithFilters {
+        /// Pagination and filters of the list
+        filters: ListFilters,
+    },
     /// Get a specific [Mithril stake distribution][crate::MithrilStakeDistribution] from the aggregator
     GetMithrilStakeDistribution {
         /// Hash of the Mithril stake distribution to retrieve
@@ -62,6 +75,11 @@ pub enum AggregatorRequest {
     },
     /// Lists the aggregator [Mithril stake distribution][crate::MithrilStakeDistribution]
     ListMithrilStakeDistributions,
+    /// Lists a page of the aggregator [Mithril stake distributions][crate::MithrilStakeDistribution] matching the given filters
+    ListMithrilStakeDistributionsWithFilters {
+        /// Pagination and filters of the list
+        filters: ListFilters,
+    },
     /// Get a specific [snapshot][crate::Snapshot] from the aggregator
     GetSnapshot {
         /// Digest of the snapshot to retrieve
@@ -69,6 +87,14 @@ pub enum AggregatorRequest {
     },
     /// Lists the aggregator [snapshots][crate::Snapshot]
     ListSnapshots,
+    /// Lists a page of the aggregator [snapshots][crate::Snapshot] matching the given filters
+    ListSnapshotsWithFilters {
+        /// Pagination and filters of the list
+        filters: ListFilters,
+    },
+
+    /// Subscribe to the [events][crate::AggregatorEvent] produced by the aggregator
+    SubscribeEvents,
 
     /// Increments the aggregator snapshot download statistics
     IncrementSnapshotStatistic {
@@ -94,6 +120,13 @@ pub enum AggregatorRequest {
     #[cfg(feature = "unstable")]
     ListCardanoTransactionSnapshots,
 
+    /// Lists a page of the aggregator [Cardano transaction snapshots][crate::CardanoTransactionSnapshot] matching the given filters
+    #[cfg(feature = "unstable")]
+    ListCardanoTransactionSnapshotsWithFilters {
+        /// Pagination and filters of the list
+        filters: ListFilters,
+    },
+
     /// Get a specific [Cardano stake distribution][crate::CardanoStakeDistribution] from the aggregator by hash
     #[cfg(feature = "unstable")]
     GetCardanoStakeDistribution {
@@ -111,26 +144,47 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [Cardano stake distribution][crate::CardanoStakeDistribution]
     #[cfg(feature = "unstable")]
     ListCardanoStakeDistributions,
+
+    /// Lists a page of the aggregator [Cardano stake distributions][crate::CardanoStakeDistribution] matching the given filters
+    #[cfg(feature = "unstable")]
+    ListCardanoStakeDistributionsWithFilters {
+        /// Pagination and filters of the list
+        filters: ListFilters,
+    },
 }
 
 impl AggregatorRequest {
     /// Get the request route relative to the aggregator root endpoint.
     pub fn route(&self) -> String {
         match self {
+            AggregatorRequest::GetAggregatorFeatures => "".to_string(),
             AggregatorRequest::GetCertificate { hash } => {
                 format!("certificate/{hash}")
             }
             AggregatorRequest::ListCertificates => "certificates".to_string(),
+            AggregatorRequest::ListCertificatesWithFilters { filters } => {
+                format!("certificates{}", filters.to_query_string())
+            }
             AggregatorRequest::GetMithrilStakeDistribution { hash } => {
                 format!("artifact/mithril-stake-distribution/{hash}")
             }
             AggregatorRequest::ListMithrilStakeDistributions => {
                 "artifact/mithril-stake-distributions".to_string()
             }
+            AggregatorRequest::ListMithrilStakeDistributionsWithFilters { filters } => {
+                format!(
+                    "artifact/mithril-stake-distributions{}",
+                    filters.to_query_string()
+                )
+            }
             AggregatorRequest::GetSnapshot { digest } => {
                 format!("artifact/snapshot/{}", digest)
             }
             AggregatorRequest::ListSnapshots => "artifact/snapshots".to_string(),
+            AggregatorRequest::ListSnapshotsWithFilters { filters } => {
+                format!("artifact/snapshots{}", filters.to_query_string())
+            }
+            AggregatorRequest::SubscribeEvents => "events".to_string(),
             AggregatorRequest::IncrementSnapshotStatistic { snapshot: _ } => {
                 "statistics/snapshot".to_string()
             }
@@ -150,6 +204,10 @@ impl AggregatorRequest {
                 "artifact/cardano-transactions".to_string()
             }
             #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoTransactionSnapshotsWithFilters { filters } => {
+                format!("artifact/cardano-transactions{}", filters.to_query_string())
+            }
+            #[cfg(feature = "unstable")]
             AggregatorRequest::GetCardanoStakeDistribution { hash } => {
                 format!("artifact/cardano-stake-distribution/{hash}")
             }
@@ -161,6 +219,13 @@ impl AggregatorRequest {
             AggregatorRequest::ListCardanoStakeDistributions => {
                 "artifact/cardano-stake-distributions".to_string()
             }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoStakeDistributionsWithFilters { filters } => {
+                format!(
+                    "artifact/cardano-stake-distributions{}",
+                    filters.to_query_string()
+                )
+            }
         }
     }
 
@@ -175,6 +240,14 @@ impl AggregatorRequest {
     }
 }
 
+/// Stream of the chunks of bytes of a content sent back by the Aggregator
+#[cfg(not(target_family = "wasm"))]
+pub type AggregatorContentStream = BoxStream<'static, Result<Vec<u8>, AggregatorClientError>>;
+
+/// Stream of the chunks of bytes of a content sent back by the Aggregator
+#[cfg(target_family = "wasm")]
+pub type AggregatorContentStream = LocalBoxStream<'static, Result<Vec<u8>, AggregatorClientError>>;
+
 /// API that defines a client for the Aggregator
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
 #[cfg_attr(not(target_family = "wasm"), async_trait)]
@@ -185,6 +258,19 @@ pub trait AggregatorClient: Sync + Send {
         request: AggregatorRequest,
     ) -> Result<String, AggregatorClientError>;
 
+    /// Get the content back from the Aggregator as a stream, for contents that are sent
+    /// continuously (ie: server-sent events)
+    ///
+    /// The default implementation returns an error as streaming is not supported.
+    async fn get_content_stream(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<AggregatorContentStream, AggregatorClientError> {
+        Err(AggregatorClientError::SubsystemError(anyhow!(
+            "Streaming content is not supported by this aggregator client, request: {request:?}"
+        )))
+    }
+
     /// Post information to the Aggregator
     async fn post_content(
         &self,
@@ -192,11 +278,15 @@ pub trait AggregatorClient: Sync + Send {
     ) -> Result<String, AggregatorClientError>;
 }
 
+/// Type of client sent by default to the aggregator.
+pub const DEFAULT_CLIENT_TYPE: &str = "LIBRARY";
+
 /// Responsible for HTTP transport and API version check.
 pub struct AggregatorHTTPClient {
     http_client: reqwest::Client,
     aggregator_endpoint: Url,
     api_versions: Arc<RwLock<Vec<Version>>>,
+    client_type: String,
     logger: Logger,
 }
 
@@ -226,10 +316,17 @@ impl AggregatorHTTPClient {
             http_client,
             aggregator_endpoint,
             api_versions: Arc::new(RwLock::new(api_versions)),
+            client_type: DEFAULT_CLIENT_TYPE.to_string(),
             logger,
         })
     }
 
+    /// Set the type of client sent to the aggregator (default to [DEFAULT_CLIENT_TYPE]).
+    pub fn with_client_type(mut self, client_type: &str) -> Self {
+        self.client_type = client_type.to_string();
+        self
+    }
+
     /// Computes the current api version
     async fn compute_current_api_version(&self) -> Option<Version> {
         self.api_versions.read().await.first().cloned()
@@ -269,8 +366,9 @@ impl AggregatorHTTPClient {
             self.logger,
             "Prepare request with version: {current_api_version}"
         );
-        let request_builder =
-            request_builder.header(MITHRIL_API_VERSION_HEADER, current_api_version);
+        let request_builder = request_builder
+            .header(MITHRIL_API_VERSION_HEADER, current_api_version)
+            .header(MITHRIL_CLIENT_TYPE_HEADER, &self.client_type);
         let response = request_builder.send().await.map_err(|e| {
             AggregatorClientError::SubsystemError(anyhow!(e).context(format!(
                 "Cannot perform a GET against the Aggregator HTTP server (url='{url}')"
@@ -310,8 +408,9 @@ impl AggregatorHTTPClient {
             self.logger,
             "Prepare request with version: {current_api_version}"
         );
-        let request_builder =
-            request_builder.header(MITHRIL_API_VERSION_HEADER, current_api_version);
+        let request_builder = request_builder
+            .header(MITHRIL_API_VERSION_HEADER, current_api_version)
+            .header(MITHRIL_CLIENT_TYPE_HEADER, &self.client_type);
 
         let response = request_builder.send().await.map_err(|e| {
             AggregatorClientError::SubsystemError(
@@ -412,6 +511,25 @@ impl AggregatorClient for AggregatorHTTPClient {
         })
     }
 
+    async fn get_content_stream(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<AggregatorContentStream, AggregatorClientError> {
+        let response = self.get(self.get_url_for_route(&request.route())?).await?;
+        let stream = response.bytes_stream().map(|chunk| {
+            chunk.map(|bytes| bytes.to_vec()).map_err(|e| {
+                AggregatorClientError::SubsystemError(
+                    anyhow!(e).context("Could not read the content stream of the response."),
+                )
+            })
+        });
+
+        #[cfg(not(target_family = "wasm"))]
+        return Ok(stream.boxed());
+        #[cfg(target_family = "wasm")]
+        return Ok(stream.boxed_local());
+    }
+
     async fn post_content(
         &self,
         request: AggregatorRequest,
@@ -494,8 +612,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deduce_list_routes_with_filters_from_request() {
+        assert_eq!(
+            "certificates?limit=5&signed_entity_type=MithrilStakeDistribution".to_string(),
+            AggregatorRequest::ListCertificatesWithFilters {
+                filters: ListFilters::default()
+                    .with_limit(5)
+                    .with_signed_entity_type(
+                        crate::common::SignedEntityTypeDiscriminants::MithrilStakeDistribution
+                    )
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/snapshots?offset=20&epoch_from=3".to_string(),
+            AggregatorRequest::ListSnapshotsWithFilters {
+                filters: ListFilters::default()
+                    .with_offset(20)
+                    .with_epoch_from(Epoch(3))
+            }
+            .route()
+        );
+    }
+
     #[test]
     fn deduce_routes_from_request() {
+        assert_eq!(
+            "".to_string(),
+            AggregatorRequest::GetAggregatorFeatures.route()
+        );
+
+        assert_eq!(
+            "events".to_string(),
+            AggregatorRequest::SubscribeEvents.route()
+        );
+
         assert_eq!(
             "certificate/abc".to_string(),
             AggregatorRequest::GetCertificate {
@@ -677,6 +830,43 @@ mod tests {
         assert_error_eq!(post_content_error, expected_error);
     }
 
+    #[tokio::test]
+    async fn test_client_sends_the_default_client_type_header() {
+        let (aggregator, client) = setup_server_and_client();
+        aggregator.mock(|when, then| {
+            when.header(MITHRIL_CLIENT_TYPE_HEADER, DEFAULT_CLIENT_TYPE);
+            then.status(StatusCode::OK.as_u16());
+        });
+
+        client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect("GET request should have the default client type header");
+        client
+            .post_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect("POST request should have the default client type header");
+    }
+
+    #[tokio::test]
+    async fn test_client_sends_the_configured_client_type_header() {
+        let (aggregator, client) = setup_server_and_client();
+        let client = client.with_client_type("CLI");
+        aggregator.mock(|when, then| {
+            when.header(MITHRIL_CLIENT_TYPE_HEADER, "CLI");
+            then.status(StatusCode::OK.as_u16());
+        });
+
+        client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect("GET request should have the configured client type header");
+        client
+            .post_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect("POST request should have the configured client type header");
+    }
+
     #[tokio::test]
     async fn test_client_handle_412_api_version_mismatch_with_version_in_response_header() {
         let version = "0.0.0";
@@ -765,4 +955,35 @@ mod tests {
             .await
             .expect("should have run with a fallback version");
     }
+
+    #[tokio::test]
+    async fn test_get_content_stream_is_not_supported_by_default() {
+        struct NonStreamingAggregatorClient;
+
+        #[async_trait]
+        impl AggregatorClient for NonStreamingAggregatorClient {
+            async fn get_content(
+                &self,
+                _request: AggregatorRequest,
+            ) -> Result<String, AggregatorClientError> {
+                Ok(String::new())
+            }
+
+            async fn post_content(
+                &self,
+                _request: AggregatorRequest,
+            ) -> Result<String, AggregatorClientError> {
+                Ok(String::new())
+            }
+        }
+
+        let result = NonStreamingAggregatorClient
+            .get_content_stream(AggregatorRequest::SubscribeEvents)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AggregatorClientError::SubsystemError(_))
+        ));
+    }
 }
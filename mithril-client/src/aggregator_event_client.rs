@@ -0,0 +1,239 @@
+//! A client to subscribe to the events produced by an Aggregator.
+//!
+//! In order to do so it defines a [AggregatorEventClient] which exposes the following feature:
+//!  - [subscribe][AggregatorEventClient::subscribe]: get a stream of the
+//!    [events][AggregatorEvent] produced by the aggregator (new certificates, new artifacts and
+//!    epoch changes), instead of polling the aggregator
+//!
+//! # Subscribe to the aggregator events
+//!
+//! To subscribe to the aggregator events using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use futures::StreamExt;
+//! use mithril_client::{AggregatorEvent, ClientBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let mut events = client.aggregator_event().subscribe().await?;
+//!
+//! while let Some(event) = events.next().await {
+//!     match event? {
+//!         AggregatorEvent::NewCertificate { certificate_hash, .. } => {
+//!             println!("New certificate: {certificate_hash}");
+//!         }
+//!         event => println!("Event: {event:?}"),
+//!     }
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+#[cfg(not(target_family = "wasm"))]
+use futures::stream::BoxStream;
+#[cfg(target_family = "wasm")]
+use futures::stream::LocalBoxStream;
+use futures::StreamExt;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorRequest};
+use crate::{AggregatorEvent, MithrilResult};
+
+/// Stream of the [events][AggregatorEvent] produced by an Aggregator
+#[cfg(not(target_family = "wasm"))]
+pub type AggregatorEventStream = BoxStream<'static, MithrilResult<AggregatorEvent>>;
+
+/// Stream of the [events][AggregatorEvent] produced by an Aggregator
+#[cfg(target_family = "wasm")]
+pub type AggregatorEventStream = LocalBoxStream<'static, MithrilResult<AggregatorEvent>>;
+
+/// HTTP client for the events produced by an Aggregator
+pub struct AggregatorEventClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl AggregatorEventClient {
+    /// Constructs a new `AggregatorEventClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Subscribe to the events produced by the aggregator from now on.
+    ///
+    /// The stream ends when the aggregator closes the connection.
+    pub async fn subscribe(&self) -> MithrilResult<AggregatorEventStream> {
+        let content_stream = self
+            .aggregator_client
+            .get_content_stream(AggregatorRequest::SubscribeEvents)
+            .await
+            .with_context(|| "Could not subscribe to the aggregator events")?;
+
+        let stream = content_stream
+            .scan(ServerSentEventsParser::default(), |parser, chunk| {
+                let events: Vec<MithrilResult<AggregatorEvent>> = match chunk {
+                    Ok(bytes) => parser
+                        .push(&bytes)
+                        .into_iter()
+                        .map(|data| {
+                            serde_json::from_str(&data).with_context(|| {
+                                format!("Could not deserialize the aggregator event: '{data}'")
+                            })
+                        })
+                        .collect(),
+                    Err(error) => vec![Err(anyhow!(error))],
+                };
+
+                futures::future::ready(Some(futures::stream::iter(events)))
+            })
+            .flatten();
+
+        #[cfg(not(target_family = "wasm"))]
+        return Ok(stream.boxed());
+        #[cfg(target_family = "wasm")]
+        return Ok(stream.boxed_local());
+    }
+}
+
+/// Incremental parser of a server-sent events stream, yielding the data of each event.
+#[derive(Default)]
+struct ServerSentEventsParser {
+    buffer: Vec<u8>,
+}
+
+impl ServerSentEventsParser {
+    /// Push a chunk of the stream and return the data of the events completed by this chunk.
+    fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend(chunk.iter().filter(|b| **b != b'\r'));
+
+        let mut events_data = vec![];
+        while let Some(position) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..position + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+            let data: Vec<&str> = block
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect();
+
+            // Blocks without data are comments or keep-alive messages
+            if !data.is_empty() {
+                events_data.push(data.join("\n"));
+            }
+        }
+
+        events_data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::Epoch;
+
+    use crate::aggregator_client::{AggregatorClientError, MockAggregatorHTTPClient};
+
+    use super::*;
+
+    fn content_stream(
+        chunks: Vec<Result<&'static str, AggregatorClientError>>,
+    ) -> crate::aggregator_client::AggregatorContentStream {
+        futures::stream::iter(
+            chunks
+                .into_iter()
+                .map(|chunk| chunk.map(|c| c.as_bytes().to_vec())),
+        )
+        .boxed()
+    }
+
+    #[test]
+    fn parser_yields_data_of_complete_events_only() {
+        let mut parser = ServerSentEventsParser::default();
+
+        assert!(parser.push(b"event:epoch_changed\ndata:{\"ty").is_empty());
+        assert_eq!(
+            vec![
+                r#"{"type":"epoch_changed","epoch":3}"#.to_string(),
+                "second".to_string()
+            ],
+            parser.push(b"pe\":\"epoch_changed\",\"epoch\":3}\n\ndata: second\r\n\r\ndata:")
+        );
+    }
+
+    #[test]
+    fn parser_skips_comments_and_joins_multi_lines_data() {
+        let mut parser = ServerSentEventsParser::default();
+
+        assert_eq!(
+            vec!["line 1\nline 2".to_string()],
+            parser.push(b":keep-alive\n\ndata:line 1\ndata: line 2\n\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe_yields_the_aggregator_events() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        aggregator_client
+            .expect_get_content_stream()
+            .withf(|request| *request == AggregatorRequest::SubscribeEvents)
+            .return_once(|_| {
+                Ok(content_stream(vec![
+                    Ok("event:epoch_changed\ndata:{\"type\":\"epoch_changed\","),
+                    Ok("\"epoch\":3}\n\n:\n\nevent:epoch_changed\n"),
+                    Ok("data:{\"type\":\"epoch_changed\",\"epoch\":4}\n\n"),
+                ]))
+            });
+        let client = AggregatorEventClient::new(Arc::new(aggregator_client));
+
+        let events: Vec<AggregatorEvent> = client
+            .subscribe()
+            .await
+            .unwrap()
+            .map(|event| event.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            vec![
+                AggregatorEvent::EpochChanged { epoch: Epoch(3) },
+                AggregatorEvent::EpochChanged { epoch: Epoch(4) },
+            ],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe_yields_an_error_for_an_invalid_event() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        aggregator_client
+            .expect_get_content_stream()
+            .return_once(|_| Ok(content_stream(vec![Ok("data:invalid\n\n")])));
+        let client = AggregatorEventClient::new(Arc::new(aggregator_client));
+
+        let events: Vec<MithrilResult<AggregatorEvent>> =
+            client.subscribe().await.unwrap().collect().await;
+
+        assert_eq!(1, events.len());
+        events[0]
+            .as_ref()
+            .expect_err("an invalid event should yield an error");
+    }
+
+    #[tokio::test]
+    async fn subscribe_fails_if_the_aggregator_can_not_be_reached() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        aggregator_client
+            .expect_get_content_stream()
+            .return_once(|_| {
+                Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                    "an error"
+                )))
+            });
+        let client = AggregatorEventClient::new(Arc::new(aggregator_client));
+
+        assert!(
+            client.subscribe().await.is_err(),
+            "subscribe should fail if the aggregator can not be reached"
+        );
+    }
+}
@@ -72,7 +72,9 @@ use std::sync::Arc;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::common::Epoch;
-use crate::{CardanoStakeDistribution, CardanoStakeDistributionListItem, MithrilResult};
+use crate::{
+    CardanoStakeDistribution, CardanoStakeDistributionListItem, ListFilters, MithrilResult,
+};
 
 /// HTTP client for CardanoStakeDistribution API from the Aggregator
 pub struct CardanoStakeDistributionClient {
@@ -87,9 +89,30 @@ impl CardanoStakeDistributionClient {
 
     /// Fetch a list of signed CardanoStakeDistribution
     pub async fn list(&self) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
+        self.fetch_list(AggregatorRequest::ListCardanoStakeDistributions)
+            .await
+    }
+
+    /// Fetch a page of the list of signed CardanoStakeDistribution matching the given filters
+    pub async fn list_with_filters(
+        &self,
+        filters: &ListFilters,
+    ) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
+        self.fetch_list(
+            AggregatorRequest::ListCardanoStakeDistributionsWithFilters {
+                filters: filters.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListCardanoStakeDistributions)
+            .get_content(request)
             .await
             .with_context(|| "CardanoStakeDistribution client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<CardanoStakeDistributionListItem>>(&response)
@@ -3,6 +3,8 @@
 //! In order to do so it defines a [CardanoTransactionClient] which exposes the following features:
 //!  - [get_proofs][CardanoTransactionClient::get_proofs]: get a [cryptographic proof][CardanoTransactionsProofs]
 //!    that the transactions with given hash are included in the global Cardano transactions set.
+//!  - [get_max_hashes_allowed_by_request][CardanoTransactionClient::get_max_hashes_allowed_by_request]: get the
+//!    maximum number of transactions hashes that the aggregator accepts in a single proof request.
 //!  - [get][CardanoTransactionClient::get_snapshot]: get a [Cardano transaction snapshot][CardanoTransactionSnapshot]
 //!    data from its hash.
 //!  - [list][CardanoTransactionClient::list_snapshots]: get the list of the latest available Cardano transaction
@@ -77,9 +79,10 @@
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::{
     CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
-    MithrilResult,
+    ListFilters, MithrilResult,
 };
 use anyhow::Context;
+use mithril_common::messages::AggregatorFeaturesMessage;
 use std::sync::Arc;
 
 /// HTTP client for CardanoTransactionsAPI from the Aggregator
@@ -117,11 +120,54 @@ impl CardanoTransactionClient {
         }
     }
 
+    /// Get the maximum number of transactions hashes that the aggregator accepts in a single
+    /// [proof request][CardanoTransactionClient::get_proofs], if the aggregator can compute
+    /// Cardano transactions proofs.
+    pub async fn get_max_hashes_allowed_by_request(&self) -> MithrilResult<Option<usize>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetAggregatorFeatures)
+            .await
+            .with_context(|| {
+                "CardanoTransactionClient Client can not get the aggregator features"
+            })?;
+        let features =
+            serde_json::from_str::<AggregatorFeaturesMessage>(&response).with_context(|| {
+                "CardanoTransactionClient Client can not deserialize the aggregator features"
+            })?;
+
+        Ok(features
+            .capabilities
+            .cardano_transactions_prover
+            .map(|prover| prover.max_hashes_allowed_by_request))
+    }
+
     /// Fetch a list of signed Cardano transaction snapshots.
     pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
+        self.fetch_list(AggregatorRequest::ListCardanoTransactionSnapshots)
+            .await
+    }
+
+    /// Fetch a page of the list of signed Cardano transaction snapshots matching the given filters
+    pub async fn list_snapshots_with_filters(
+        &self,
+        filters: &ListFilters,
+    ) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
+        self.fetch_list(
+            AggregatorRequest::ListCardanoTransactionSnapshotsWithFilters {
+                filters: filters.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListCardanoTransactionSnapshots)
+            .get_content(request)
             .await
             .with_context(|| "CardanoTransactionClient Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<CardanoTransactionSnapshotListItem>>(&response)
@@ -158,6 +204,7 @@ impl CardanoTransactionClient {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
     use std::sync::Arc;
 
     use anyhow::anyhow;
@@ -170,6 +217,7 @@ mod tests {
         CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
         CardanoTransactionsSetProof,
     };
+    use mithril_common::messages::{AggregatorCapabilities, CardanoTransactionsProverCapabilities};
 
     use super::*;
 
@@ -243,6 +291,42 @@ mod tests {
         assert_eq!(expected, cardano_transaction_snapshot);
     }
 
+    #[tokio::test]
+    async fn get_max_hashes_allowed_by_request_from_aggregator_features() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        let message = AggregatorFeaturesMessage {
+            capabilities: AggregatorCapabilities {
+                signed_entity_types: BTreeSet::new(),
+                cardano_transactions_prover: Some(CardanoTransactionsProverCapabilities {
+                    max_hashes_allowed_by_request: 42,
+                }),
+            },
+            ..AggregatorFeaturesMessage::dummy()
+        };
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetAggregatorFeatures))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoTransactionClient::new(Arc::new(http_client));
+
+        let max_hashes = client.get_max_hashes_allowed_by_request().await.unwrap();
+
+        assert_eq!(Some(42), max_hashes);
+    }
+
+    #[tokio::test]
+    async fn get_max_hashes_allowed_by_request_is_none_if_aggregator_is_not_a_prover() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Ok(serde_json::to_string(&AggregatorFeaturesMessage::dummy()).unwrap())
+        });
+        let client = CardanoTransactionClient::new(Arc::new(http_client));
+
+        let max_hashes = client.get_max_hashes_allowed_by_request().await.unwrap();
+
+        assert_eq!(None, max_hashes);
+    }
+
     #[tokio::test]
     async fn test_get_proof_ok() {
         let mut aggregator_client = MockAggregatorHTTPClient::new();
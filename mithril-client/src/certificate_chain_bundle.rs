@@ -0,0 +1,181 @@
+//! A self-contained bundle of a certificate chain, allowing to verify it without any
+//! access to an Aggregator.
+//!
+//! It defines a [CertificateChainBundle] that holds all the certificates of a chain, from a
+//! given certificate back to the genesis certificate, and a [BundleCertificateRetriever] that
+//! reads the certificates from such a bundle.
+//!
+//! # Export a certificate chain bundle
+//!
+//! To export the certificate chain of a certificate using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let bundle = client.certificate().export_chain("CERTIFICATE_HASH").await?;
+//!
+//! std::fs::write("certificate_chain.json", serde_json::to_string(&bundle)?)?;
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Verify a certificate chain bundle offline
+//!
+//! To verify a certificate chain bundle without any network access using the
+//! [MithrilCertificateVerifier][crate::certificate_client::MithrilCertificateVerifier].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::certificate_chain_bundle::CertificateChainBundle;
+//! use mithril_client::certificate_client::{CertificateVerifier, MithrilCertificateVerifier};
+//! use mithril_client::feedback::FeedbackSender;
+//!
+//! let bundle: CertificateChainBundle =
+//!     serde_json::from_str(&std::fs::read_to_string("certificate_chain.json")?)?;
+//! let head = bundle.head().expect("the bundle should not be empty").clone();
+//! let verifier = MithrilCertificateVerifier::from_bundle(
+//!     bundle,
+//!     "YOUR_GENESIS_VERIFICATION_KEY",
+//!     FeedbackSender::new(&[]),
+//!     slog::Logger::root(slog::Discard, slog::o!()),
+//! )?;
+//! verifier.verify_chain(&head).await?;
+//!
+//! println!("Chain of Certificate (hash: {}) is valid", head.hash);
+//! #    Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::MithrilCertificate;
+use mithril_common::{
+    certificate_chain::{CertificateRetriever, CertificateRetrieverError},
+    entities::Certificate,
+};
+
+/// A certificate chain, ordered from its head certificate back to the genesis certificate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateChainBundle {
+    /// Certificates of the chain, the first one being the head of the chain
+    pub certificates: Vec<MithrilCertificate>,
+}
+
+impl CertificateChainBundle {
+    /// Constructs a new `CertificateChainBundle`.
+    pub fn new(certificates: Vec<MithrilCertificate>) -> Self {
+        Self { certificates }
+    }
+
+    /// Get the head certificate of the chain, if any.
+    pub fn head(&self) -> Option<&MithrilCertificate> {
+        self.certificates.first()
+    }
+
+    /// Get the certificate of the bundle with the given hash, if any.
+    pub fn get(&self, certificate_hash: &str) -> Option<&MithrilCertificate> {
+        self.certificates
+            .iter()
+            .find(|certificate| certificate.hash == certificate_hash)
+    }
+
+    /// Number of certificates in the bundle.
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Check if the bundle holds no certificate.
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+}
+
+/// A [CertificateRetriever] that reads the certificates from a [CertificateChainBundle]
+/// instead of an Aggregator.
+pub struct BundleCertificateRetriever {
+    certificates: HashMap<String, MithrilCertificate>,
+}
+
+impl BundleCertificateRetriever {
+    /// Constructs a new `BundleCertificateRetriever`.
+    pub fn new(bundle: CertificateChainBundle) -> Self {
+        Self {
+            certificates: bundle
+                .certificates
+                .into_iter()
+                .map(|certificate| (certificate.hash.clone(), certificate))
+                .collect(),
+        }
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateRetriever for BundleCertificateRetriever {
+    async fn get_certificate_details(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Certificate, CertificateRetrieverError> {
+        self.certificates
+            .get(certificate_hash)
+            .cloned()
+            .ok_or(CertificateRetrieverError(anyhow!(
+                "Certificate does not exist in the bundle: '{certificate_hash}'"
+            )))?
+            .try_into()
+            .map_err(CertificateRetrieverError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::fake_data;
+
+    use super::*;
+
+    fn certificate_message(hash: &str) -> MithrilCertificate {
+        fake_data::certificate(hash.to_string()).try_into().unwrap()
+    }
+
+    #[test]
+    fn bundle_head_is_the_first_certificate() {
+        let bundle = CertificateChainBundle::new(vec![
+            certificate_message("hash-2"),
+            certificate_message("hash-1"),
+        ]);
+
+        assert_eq!(Some("hash-2"), bundle.head().map(|c| c.hash.as_str()));
+        assert_eq!(None, CertificateChainBundle::new(vec![]).head());
+    }
+
+    #[tokio::test]
+    async fn retriever_get_certificate_from_the_bundle() {
+        let expected: Certificate = fake_data::certificate("hash-1".to_string());
+        let retriever = BundleCertificateRetriever::new(CertificateChainBundle::new(vec![
+            certificate_message("hash-2"),
+            expected.clone().try_into().unwrap(),
+        ]));
+
+        let certificate = retriever.get_certificate_details("hash-1").await.unwrap();
+
+        assert_eq!(expected, certificate);
+    }
+
+    #[tokio::test]
+    async fn retriever_fails_if_the_certificate_is_not_in_the_bundle() {
+        let retriever = BundleCertificateRetriever::new(CertificateChainBundle::new(vec![
+            certificate_message("hash-1"),
+        ]));
+
+        retriever
+            .get_certificate_details("hash-unknown")
+            .await
+            .expect_err("should fail for a certificate missing from the bundle");
+    }
+}
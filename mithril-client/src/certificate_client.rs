@@ -4,6 +4,8 @@
 //!  - [get][CertificateClient::get]: get a certificate data from its hash
 //!  - [list][CertificateClient::list]: get the list of available certificates
 //!  - [verify_chain][CertificateClient::verify_chain]: verify a certificate chain
+//!  - [export_chain][CertificateClient::export_chain]: export a certificate chain to a
+//!    [bundle][crate::certificate_chain_bundle::CertificateChainBundle] that can be verified offline
 //!
 //! # Get a certificate
 //!
@@ -55,6 +57,7 @@
 //! # }
 //! ```
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
@@ -62,8 +65,10 @@ use async_trait::async_trait;
 use slog::{crit, debug, Logger};
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::certificate_chain_bundle::{BundleCertificateRetriever, CertificateChainBundle};
+use crate::certificate_verifier_cache::CertificateVerifierCache;
 use crate::feedback::{FeedbackSender, MithrilEvent};
-use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult};
+use crate::{ListFilters, MithrilCertificate, MithrilCertificateListItem, MithrilResult};
 use mithril_common::crypto_helper::ProtocolGenesisVerificationKey;
 use mithril_common::{
     certificate_chain::{
@@ -115,9 +120,27 @@ impl CertificateClient {
 
     /// Fetch a list of certificates
     pub async fn list(&self) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+        self.fetch_list(AggregatorRequest::ListCertificates).await
+    }
+
+    /// Fetch a page of the list of certificates matching the given filters
+    pub async fn list_with_filters(
+        &self,
+        filters: &ListFilters,
+    ) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+        self.fetch_list(AggregatorRequest::ListCertificatesWithFilters {
+            filters: filters.clone(),
+        })
+        .await
+    }
+
+    async fn fetch_list(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<MithrilCertificateListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListCertificates)
+            .get_content(request)
             .await
             .with_context(|| "CertificateClient can not get the certificate list")?;
         let items = serde_json::from_str::<Vec<MithrilCertificateListItem>>(&response)
@@ -149,6 +172,41 @@ impl CertificateClient {
 
         Ok(certificate)
     }
+
+    /// Export the chain starting with the certificate with given `certificate_hash` back to the
+    /// genesis certificate into a [CertificateChainBundle].
+    ///
+    /// The certificates are not verified, use a [MithrilCertificateVerifier] built with
+    /// [MithrilCertificateVerifier::from_bundle] to verify the bundle.
+    pub async fn export_chain(
+        &self,
+        certificate_hash: &str,
+    ) -> MithrilResult<CertificateChainBundle> {
+        let mut certificates = vec![];
+        let mut visited_hashes = HashSet::new();
+        let mut current_hash = certificate_hash.to_string();
+
+        loop {
+            if !visited_hashes.insert(current_hash.clone()) {
+                return Err(anyhow!(
+                    "Certificate chain of certificate '{certificate_hash}' loops on certificate '{current_hash}'"
+                ));
+            }
+
+            let certificate = self
+                .retriever
+                .get(&current_hash)
+                .await?
+                .ok_or(anyhow!("No certificate exist for hash '{current_hash}'"))?;
+            let is_genesis = !certificate.genesis_signature.is_empty();
+            current_hash = certificate.previous_hash.clone();
+            certificates.push(certificate);
+
+            if is_genesis {
+                return Ok(CertificateChainBundle::new(certificates));
+            }
+        }
+    }
 }
 
 /// Internal type to implement the [InternalCertificateRetriever] trait and avoid a circular
@@ -190,10 +248,15 @@ impl InternalCertificateRetriever {
 
 /// Implementation of a [CertificateVerifier] that can send feedbacks using
 /// the [feedback][crate::feedback] mechanism.
+///
+/// If a [CertificateVerifierCache] is set, the verification of a chain stops at the first
+/// certificate that was already verified.
 pub struct MithrilCertificateVerifier {
     internal_verifier: Arc<dyn CommonCertificateVerifier>,
     genesis_verification_key: ProtocolGenesisVerificationKey,
     feedback_sender: FeedbackSender,
+    verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
+    logger: Logger,
 }
 
 impl MithrilCertificateVerifier {
@@ -208,9 +271,32 @@ impl MithrilCertificateVerifier {
             aggregator_client: aggregator_client.clone(),
             logger: logger.clone(),
         });
+
+        Self::new_with_retriever(retriever, genesis_verification_key, feedback_sender, logger)
+    }
+
+    /// Constructs a new `MithrilCertificateVerifier` that reads the certificates from the given
+    /// bundle, allowing to verify its chain without any access to an Aggregator.
+    pub fn from_bundle(
+        bundle: CertificateChainBundle,
+        genesis_verification_key: &str,
+        feedback_sender: FeedbackSender,
+        logger: Logger,
+    ) -> MithrilResult<MithrilCertificateVerifier> {
+        let retriever = Arc::new(BundleCertificateRetriever::new(bundle));
+
+        Self::new_with_retriever(retriever, genesis_verification_key, feedback_sender, logger)
+    }
+
+    fn new_with_retriever(
+        retriever: Arc<dyn CertificateRetriever>,
+        genesis_verification_key: &str,
+        feedback_sender: FeedbackSender,
+        logger: Logger,
+    ) -> MithrilResult<MithrilCertificateVerifier> {
         let internal_verifier = Arc::new(CommonMithrilCertificateVerifier::new(
-            logger,
-            retriever.clone(),
+            logger.clone(),
+            retriever,
         ));
         let genesis_verification_key =
             ProtocolGenesisVerificationKey::try_from(genesis_verification_key)
@@ -220,8 +306,29 @@ impl MithrilCertificateVerifier {
             internal_verifier,
             genesis_verification_key,
             feedback_sender,
+            verifier_cache: None,
+            logger,
         })
     }
+
+    /// Set the [CertificateVerifierCache] used to skip the certificates that were already
+    /// verified.
+    pub fn with_cache(mut self, verifier_cache: Arc<dyn CertificateVerifierCache>) -> Self {
+        self.verifier_cache = Some(verifier_cache);
+        self
+    }
+
+    /// Check if the given certificate was already verified according to the cache.
+    ///
+    /// The hash of the certificate is recomputed so a certificate with an altered content can't
+    /// be trusted because it claims the hash of a verified certificate.
+    async fn is_trusted(&self, certificate: &Certificate) -> MithrilResult<bool> {
+        match &self.verifier_cache {
+            Some(cache) => Ok(certificate.hash == certificate.compute_hash()
+                && cache.contains(&certificate.hash).await?),
+            None => Ok(false),
+        }
+    }
 }
 
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
@@ -238,8 +345,18 @@ impl CertificateVerifier for MithrilCertificateVerifier {
             })
             .await;
 
-        let mut current_certificate = certificate.clone().try_into()?;
+        let mut current_certificate: Certificate = certificate.clone().try_into()?;
+        let mut verified_certificate_hashes = vec![];
         loop {
+            if self.is_trusted(&current_certificate).await? {
+                debug!(
+                    self.logger,
+                    "Certificate chain verification stopped at already verified certificate";
+                    "certificate_hash" => &current_certificate.hash
+                );
+                break;
+            }
+
             let previous_or_none = self
                 .internal_verifier
                 .verify_certificate(&current_certificate, &self.genesis_verification_key)
@@ -251,6 +368,7 @@ impl CertificateVerifier for MithrilCertificateVerifier {
                     certificate_chain_validation_id: certificate_chain_validation_id.clone(),
                 })
                 .await;
+            verified_certificate_hashes.push(current_certificate.hash.clone());
 
             match previous_or_none {
                 Some(previous_certificate) => current_certificate = previous_certificate,
@@ -258,6 +376,13 @@ impl CertificateVerifier for MithrilCertificateVerifier {
             }
         }
 
+        if let Some(cache) = &self.verifier_cache {
+            cache
+                .store_verified_certificates(&verified_certificate_hashes)
+                .await
+                .with_context(|| "Can not store the verified certificates in the cache")?;
+        }
+
         self.feedback_sender
             .send_event(MithrilEvent::CertificateChainValidated {
                 certificate_chain_validation_id,
@@ -295,6 +420,7 @@ mod tests {
     use mockall::predicate::eq;
 
     use crate::aggregator_client::MockAggregatorHTTPClient;
+    use crate::certificate_verifier_cache::MemoryCertificateVerifierCache;
     use crate::feedback::StackFeedbackReceiver;
     use crate::test_utils;
 
@@ -516,4 +642,203 @@ mod tests {
 
         assert_eq!(certificate.hash, last_certificate_hash);
     }
+
+    fn aggregator_client_serving_chain(chain: &[Certificate]) -> MockAggregatorHTTPClient {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        for certificate in chain.iter().cloned() {
+            let hash = certificate.hash.clone();
+            let message = serde_json::to_string(
+                &TryInto::<CertificateMessage>::try_into(certificate).unwrap(),
+            )
+            .unwrap();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetCertificate { hash }))
+                .returning(move |_| Ok(message.to_owned()));
+        }
+
+        aggregator_client
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_cache_stops_at_already_verified_certificate() {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let verifier_cache = Arc::new(MemoryCertificateVerifierCache::new());
+        verifier_cache
+            .store_verified_certificates(&[chain[2].hash.clone()])
+            .await
+            .unwrap();
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let certificate_verifier = MithrilCertificateVerifier::new(
+            Arc::new(aggregator_client_serving_chain(&chain)),
+            &verification_key,
+            FeedbackSender::new(&[feedback_receiver.clone()]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_cache(verifier_cache.clone());
+
+        certificate_verifier
+            .verify_chain(&chain[0].clone().try_into().unwrap())
+            .await
+            .expect("Chain validation should succeed");
+
+        let validated_hashes: Vec<_> = feedback_receiver
+            .stacked_events()
+            .into_iter()
+            .filter_map(|e| match e {
+                MithrilEvent::CertificateValidated {
+                    certificate_hash, ..
+                } => Some(certificate_hash),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            vec![chain[0].hash.clone(), chain[1].hash.clone()],
+            validated_hashes
+        );
+        let mut expected_cache: Vec<_> = chain[..3].iter().map(|c| c.hash.clone()).collect();
+        expected_cache.sort();
+        assert_eq!(expected_cache, verifier_cache.list().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_cache_store_all_verified_certificates() {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let verifier_cache = Arc::new(MemoryCertificateVerifierCache::new());
+        let certificate_verifier = MithrilCertificateVerifier::new(
+            Arc::new(aggregator_client_serving_chain(&chain)),
+            &verification_key,
+            FeedbackSender::new(&[]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_cache(verifier_cache.clone());
+
+        certificate_verifier
+            .verify_chain(&chain[0].clone().try_into().unwrap())
+            .await
+            .expect("Chain validation should succeed");
+
+        let mut expected_cache: Vec<_> = chain.iter().map(|c| c.hash.clone()).collect();
+        expected_cache.sort();
+        assert_eq!(expected_cache, verifier_cache.list().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_cache_does_not_trust_altered_certificate_with_a_cached_hash() {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let verifier_cache = Arc::new(MemoryCertificateVerifierCache::new());
+        verifier_cache
+            .store_verified_certificates(&[chain[0].hash.clone()])
+            .await
+            .unwrap();
+        let certificate_verifier = MithrilCertificateVerifier::new(
+            Arc::new(aggregator_client_serving_chain(&chain)),
+            &verification_key,
+            FeedbackSender::new(&[]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_cache(verifier_cache);
+        let mut altered_certificate: MithrilCertificate = chain[0].clone().try_into().unwrap();
+        altered_certificate.signed_message = "altered".to_string();
+
+        certificate_verifier
+            .verify_chain(&altered_certificate)
+            .await
+            .expect_err("Chain validation of an altered certificate should fail");
+    }
+
+    #[tokio::test]
+    async fn export_chain_fetch_all_certificates_back_to_genesis() {
+        let (chain, _) = setup_certificate_chain(5, 2);
+        let certificate_client =
+            build_client(Arc::new(aggregator_client_serving_chain(&chain)), None);
+
+        let bundle = certificate_client
+            .export_chain(&chain[0].hash)
+            .await
+            .expect("Chain export should succeed");
+
+        let expected_hashes: Vec<_> = chain.iter().map(|c| c.hash.clone()).collect();
+        let bundle_hashes: Vec<_> = bundle.certificates.iter().map(|c| c.hash.clone()).collect();
+        assert_eq!(expected_hashes, bundle_hashes);
+    }
+
+    #[tokio::test]
+    async fn export_chain_fails_if_a_certificate_is_missing() {
+        let (chain, _) = setup_certificate_chain(5, 2);
+        let mut aggregator_client = aggregator_client_serving_chain(&chain[..3]);
+        aggregator_client.expect_get_content().returning(|_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let certificate_client = build_client(Arc::new(aggregator_client), None);
+
+        certificate_client
+            .export_chain(&chain[0].hash)
+            .await
+            .expect_err("Chain export should fail when a certificate is missing");
+    }
+
+    #[tokio::test]
+    async fn verify_exported_chain_offline() {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let certificate_client =
+            build_client(Arc::new(aggregator_client_serving_chain(&chain)), None);
+        let bundle = certificate_client
+            .export_chain(&chain[0].hash)
+            .await
+            .unwrap();
+        let head = bundle.head().unwrap().clone();
+
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let offline_verifier = MithrilCertificateVerifier::from_bundle(
+            bundle,
+            &verification_key,
+            FeedbackSender::new(&[feedback_receiver.clone()]),
+            test_utils::test_logger(),
+        )
+        .unwrap();
+
+        offline_verifier
+            .verify_chain(&head)
+            .await
+            .expect("Offline chain validation should succeed");
+        assert!(matches!(
+            feedback_receiver.stacked_events().last(),
+            Some(MithrilEvent::CertificateChainValidated { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_offline_fails_with_an_incomplete_bundle() {
+        let (chain, verifier) = setup_certificate_chain(5, 2);
+        let verification_key: String = verifier.to_verification_key().try_into().unwrap();
+        let truncated_chain: Vec<MithrilCertificate> = chain[..3]
+            .iter()
+            .cloned()
+            .map(|c| c.try_into().unwrap())
+            .collect();
+        let head = truncated_chain[0].clone();
+
+        let offline_verifier = MithrilCertificateVerifier::from_bundle(
+            CertificateChainBundle::new(truncated_chain),
+            &verification_key,
+            FeedbackSender::new(&[]),
+            test_utils::test_logger(),
+        )
+        .unwrap();
+
+        offline_verifier
+            .verify_chain(&head)
+            .await
+            .expect_err("Offline chain validation should fail with an incomplete bundle");
+    }
 }
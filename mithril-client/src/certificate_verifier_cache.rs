@@ -0,0 +1,263 @@
+//! A cache of the certificates already verified by a
+//! [MithrilCertificateVerifier][crate::certificate_client::MithrilCertificateVerifier].
+//!
+//! When a cache is set, the verification of a certificate chain stops as soon as it reaches a
+//! certificate that was verified before, instead of walking the chain back to the genesis
+//! certificate.
+//!
+//! It defines a [CertificateVerifierCache] trait with the following implementations:
+//!  - [MemoryCertificateVerifierCache]: keep the verified certificates in memory
+//!  - [FileCertificateVerifierCache]: persist the verified certificates in a JSON file (requires
+//!    the `fs` feature)
+//!
+//! # Use a certificate verifier cache
+//!
+//! To use a cache with the [ClientBuilder][crate::client::ClientBuilder] and inspect it.
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use std::sync::Arc;
+//! use mithril_client::ClientBuilder;
+//! use mithril_client::certificate_verifier_cache::{CertificateVerifierCache, MemoryCertificateVerifierCache};
+//!
+//! let cache = Arc::new(MemoryCertificateVerifierCache::new());
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY")
+//!     .with_certificate_verifier_cache(cache.clone())
+//!     .build()?;
+//! let certificate = client.certificate().verify_chain("CERTIFICATE_HASH").await?;
+//!
+//! println!("Trusted certificates: {:?}", cache.list().await?);
+//! cache.clear().await?;
+//! #    Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::MithrilResult;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// API that defines how to store the hashes of the certificates that have already been verified.
+#[cfg_attr(test, automock)]
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+pub trait CertificateVerifierCache: Sync + Send {
+    /// Store the hashes of certificates whose chain has been verified.
+    async fn store_verified_certificates(&self, certificate_hashes: &[String])
+        -> MithrilResult<()>;
+
+    /// Check if the certificate with the given hash has already been verified.
+    async fn contains(&self, certificate_hash: &str) -> MithrilResult<bool>;
+
+    /// List the hashes of the certificates that have already been verified.
+    async fn list(&self) -> MithrilResult<Vec<String>>;
+
+    /// Remove all the certificates from the cache.
+    async fn clear(&self) -> MithrilResult<()>;
+}
+
+/// A [CertificateVerifierCache] that keeps the verified certificates in memory.
+#[derive(Default)]
+pub struct MemoryCertificateVerifierCache {
+    certificate_hashes: RwLock<BTreeSet<String>>,
+}
+
+impl MemoryCertificateVerifierCache {
+    /// Constructs a new empty `MemoryCertificateVerifierCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateVerifierCache for MemoryCertificateVerifierCache {
+    async fn store_verified_certificates(
+        &self,
+        certificate_hashes: &[String],
+    ) -> MithrilResult<()> {
+        let mut hashes = self.certificate_hashes.write().await;
+        hashes.extend(certificate_hashes.iter().cloned());
+
+        Ok(())
+    }
+
+    async fn contains(&self, certificate_hash: &str) -> MithrilResult<bool> {
+        Ok(self
+            .certificate_hashes
+            .read()
+            .await
+            .contains(certificate_hash))
+    }
+
+    async fn list(&self) -> MithrilResult<Vec<String>> {
+        Ok(self
+            .certificate_hashes
+            .read()
+            .await
+            .iter()
+            .cloned()
+            .collect())
+    }
+
+    async fn clear(&self) -> MithrilResult<()> {
+        self.certificate_hashes.write().await.clear();
+
+        Ok(())
+    }
+}
+
+cfg_fs! {
+    use std::path::{Path, PathBuf};
+
+    use anyhow::Context;
+
+    /// A [CertificateVerifierCache] that persists the verified certificates in a JSON file, so
+    /// they are still trusted after a restart.
+    pub struct FileCertificateVerifierCache {
+        path: PathBuf,
+        memory_cache: MemoryCertificateVerifierCache,
+    }
+
+    impl FileCertificateVerifierCache {
+        /// Constructs a new `FileCertificateVerifierCache` backed by the file at the given path.
+        ///
+        /// The certificates already stored in the file, if it exists, are loaded.
+        pub async fn new(path: &Path) -> MithrilResult<Self> {
+            let memory_cache = MemoryCertificateVerifierCache::new();
+            if path.exists() {
+                let content = tokio::fs::read_to_string(path).await.with_context(|| {
+                    format!("Can not read certificate verifier cache file: '{}'", path.display())
+                })?;
+                let certificate_hashes: Vec<String> = serde_json::from_str(&content)
+                    .with_context(|| {
+                        format!(
+                            "Can not deserialize certificate verifier cache file: '{}'",
+                            path.display()
+                        )
+                    })?;
+                memory_cache
+                    .store_verified_certificates(&certificate_hashes)
+                    .await?;
+            }
+
+            Ok(Self {
+                path: path.to_path_buf(),
+                memory_cache,
+            })
+        }
+
+        async fn persist(&self) -> MithrilResult<()> {
+            let certificate_hashes = self.memory_cache.list().await?;
+            if let Some(parent) = self.path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&self.path, serde_json::to_string(&certificate_hashes)?)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Can not write certificate verifier cache file: '{}'",
+                        self.path.display()
+                    )
+                })?;
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl CertificateVerifierCache for FileCertificateVerifierCache {
+        async fn store_verified_certificates(
+            &self,
+            certificate_hashes: &[String],
+        ) -> MithrilResult<()> {
+            self.memory_cache
+                .store_verified_certificates(certificate_hashes)
+                .await?;
+            self.persist().await
+        }
+
+        async fn contains(&self, certificate_hash: &str) -> MithrilResult<bool> {
+            self.memory_cache.contains(certificate_hash).await
+        }
+
+        async fn list(&self) -> MithrilResult<Vec<String>> {
+            self.memory_cache.list().await
+        }
+
+        async fn clear(&self) -> MithrilResult<()> {
+            self.memory_cache.clear().await?;
+            self.persist().await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(values: &[&str]) -> Vec<String> {
+        values.iter().map(|h| h.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn memory_cache_store_list_and_clear() {
+        let cache = MemoryCertificateVerifierCache::new();
+        cache
+            .store_verified_certificates(&hashes(&["hash-2", "hash-1"]))
+            .await
+            .unwrap();
+
+        assert!(cache.contains("hash-1").await.unwrap());
+        assert!(!cache.contains("hash-3").await.unwrap());
+        assert_eq!(hashes(&["hash-1", "hash-2"]), cache.list().await.unwrap());
+
+        cache.clear().await.unwrap();
+        assert!(cache.list().await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "fs")]
+    mod file_cache {
+        use mithril_common::test_utils::TempDir;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn verified_certificates_are_loaded_after_a_restart() {
+            let path = TempDir::create("certificate_verifier_cache", "loaded_after_a_restart")
+                .join("cache.json");
+            {
+                let cache = FileCertificateVerifierCache::new(&path).await.unwrap();
+                cache
+                    .store_verified_certificates(&hashes(&["hash-1", "hash-2"]))
+                    .await
+                    .unwrap();
+            }
+
+            let cache = FileCertificateVerifierCache::new(&path).await.unwrap();
+
+            assert_eq!(hashes(&["hash-1", "hash-2"]), cache.list().await.unwrap());
+        }
+
+        #[tokio::test]
+        async fn clear_is_persisted() {
+            let path = TempDir::create("certificate_verifier_cache", "clear_is_persisted")
+                .join("cache.json");
+            let cache = FileCertificateVerifierCache::new(&path).await.unwrap();
+            cache
+                .store_verified_certificates(&hashes(&["hash-1"]))
+                .await
+                .unwrap();
+
+            cache.clear().await.unwrap();
+
+            let cache = FileCertificateVerifierCache::new(&path).await.unwrap();
+            assert!(cache.list().await.unwrap().is_empty());
+        }
+    }
+}
@@ -2,9 +2,12 @@ use anyhow::{anyhow, Context};
 use mithril_common::api_version::APIVersionProvider;
 use reqwest::Url;
 use slog::{o, Logger};
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorHTTPClient};
+use crate::aggregator_event_client::AggregatorEventClient;
 #[cfg(feature = "unstable")]
 use crate::cardano_stake_distribution_client::CardanoStakeDistributionClient;
 #[cfg(feature = "unstable")]
@@ -12,11 +15,12 @@ use crate::cardano_transaction_client::CardanoTransactionClient;
 use crate::certificate_client::{
     CertificateClient, CertificateVerifier, MithrilCertificateVerifier,
 };
+use crate::certificate_verifier_cache::CertificateVerifierCache;
 use crate::feedback::{FeedbackReceiver, FeedbackSender};
 use crate::mithril_stake_distribution_client::MithrilStakeDistributionClient;
 use crate::snapshot_client::SnapshotClient;
 #[cfg(feature = "fs")]
-use crate::snapshot_downloader::{HttpSnapshotDownloader, SnapshotDownloader};
+use crate::snapshot_downloader::{DownloadRetryPolicy, HttpSnapshotDownloader, SnapshotDownloader};
 use crate::MithrilResult;
 
 /// Structure that aggregates the available clients for each of the Mithril types of certified data.
@@ -24,6 +28,7 @@ use crate::MithrilResult;
 /// Use the [ClientBuilder] to instantiate it easily.
 #[derive(Clone)]
 pub struct Client {
+    aggregator_event_client: Arc<AggregatorEventClient>,
     #[cfg(feature = "unstable")]
     cardano_transaction_client: Arc<CardanoTransactionClient>,
     #[cfg(feature = "unstable")]
@@ -34,6 +39,11 @@ pub struct Client {
 }
 
 impl Client {
+    /// Get the client that subscribes to the events produced by the aggregator.
+    pub fn aggregator_event(&self) -> Arc<AggregatorEventClient> {
+        self.aggregator_event_client.clone()
+    }
+
     /// Get the client that fetches and verifies Mithril Cardano transaction proof.
     #[cfg(feature = "unstable")]
     pub fn cardano_transaction(&self) -> Arc<CardanoTransactionClient> {
@@ -67,9 +77,17 @@ pub struct ClientBuilder {
     aggregator_endpoint: Option<String>,
     genesis_verification_key: String,
     aggregator_client: Option<Arc<dyn AggregatorClient>>,
+    client_type: Option<String>,
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
+    certificate_verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
     #[cfg(feature = "fs")]
     snapshot_downloader: Option<Arc<dyn SnapshotDownloader>>,
+    #[cfg(feature = "fs")]
+    snapshot_download_retry_policy: Option<DownloadRetryPolicy>,
+    #[cfg(feature = "fs")]
+    snapshot_partial_downloads_dir: Option<PathBuf>,
+    #[cfg(feature = "fs")]
+    snapshot_location_probe_timeout: Option<std::time::Duration>,
     logger: Option<Logger>,
     feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
 }
@@ -82,9 +100,17 @@ impl ClientBuilder {
             aggregator_endpoint: Some(endpoint.to_string()),
             genesis_verification_key: genesis_verification_key.to_string(),
             aggregator_client: None,
+            client_type: None,
             certificate_verifier: None,
+            certificate_verifier_cache: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_download_retry_policy: None,
+            #[cfg(feature = "fs")]
+            snapshot_partial_downloads_dir: None,
+            #[cfg(feature = "fs")]
+            snapshot_location_probe_timeout: None,
             logger: None,
             feedback_receivers: vec![],
         }
@@ -99,9 +125,17 @@ impl ClientBuilder {
             aggregator_endpoint: None,
             genesis_verification_key: genesis_verification_key.to_string(),
             aggregator_client: None,
+            client_type: None,
             certificate_verifier: None,
+            certificate_verifier_cache: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_download_retry_policy: None,
+            #[cfg(feature = "fs")]
+            snapshot_partial_downloads_dir: None,
+            #[cfg(feature = "fs")]
+            snapshot_location_probe_timeout: None,
             logger: None,
             feedback_receivers: vec![],
         }
@@ -127,42 +161,63 @@ impl ClientBuilder {
                 let endpoint_url = Url::parse(&endpoint)
                     .with_context(|| format!("Invalid aggregator endpoint, it must be a correctly formed url: '{endpoint}'"))?;
 
-                Arc::new(
-                    AggregatorHTTPClient::new(
-                        endpoint_url,
-                        APIVersionProvider::compute_all_versions_sorted()
-                            .with_context(|| "Could not compute aggregator api versions")?,
-                        logger.clone(),
-                    )
-                    .with_context(|| "Building aggregator client failed")?,
+                let mut aggregator_client = AggregatorHTTPClient::new(
+                    endpoint_url,
+                    APIVersionProvider::compute_all_versions_sorted()
+                        .with_context(|| "Could not compute aggregator api versions")?,
+                    logger.clone(),
                 )
+                .with_context(|| "Building aggregator client failed")?;
+                if let Some(client_type) = &self.client_type {
+                    aggregator_client = aggregator_client.with_client_type(client_type);
+                }
+
+                Arc::new(aggregator_client)
             }
             Some(client) => client,
         };
 
         #[cfg(feature = "fs")]
         let snapshot_downloader = match self.snapshot_downloader {
-            None => Arc::new(
-                HttpSnapshotDownloader::new(feedback_sender.clone(), logger.clone())
-                    .with_context(|| "Building snapshot downloader failed")?,
-            ),
+            None => {
+                let mut snapshot_downloader =
+                    HttpSnapshotDownloader::new(feedback_sender.clone(), logger.clone())
+                        .with_context(|| "Building snapshot downloader failed")?;
+                if let Some(retry_policy) = self.snapshot_download_retry_policy {
+                    snapshot_downloader = snapshot_downloader.with_retry_policy(retry_policy);
+                }
+                if let Some(partial_downloads_dir) = &self.snapshot_partial_downloads_dir {
+                    snapshot_downloader =
+                        snapshot_downloader.with_partial_downloads_dir(partial_downloads_dir);
+                }
+
+                Arc::new(snapshot_downloader)
+            }
             Some(snapshot_downloader) => snapshot_downloader,
         };
 
+        let aggregator_event_client =
+            Arc::new(AggregatorEventClient::new(aggregator_client.clone()));
+
         #[cfg(feature = "unstable")]
         let cardano_transaction_client =
             Arc::new(CardanoTransactionClient::new(aggregator_client.clone()));
 
         let certificate_verifier = match self.certificate_verifier {
-            None => Arc::new(
-                MithrilCertificateVerifier::new(
+            None => {
+                let mut certificate_verifier = MithrilCertificateVerifier::new(
                     aggregator_client.clone(),
                     &self.genesis_verification_key,
                     feedback_sender.clone(),
                     logger.clone(),
                 )
-                .with_context(|| "Building certificate verifier failed")?,
-            ),
+                .with_context(|| "Building certificate verifier failed")?;
+                if let Some(verifier_cache) = self.certificate_verifier_cache {
+                    certificate_verifier = certificate_verifier.with_cache(verifier_cache);
+                }
+
+                Arc::new(certificate_verifier)
+            }
             Some(verifier) => verifier,
         };
         let certificate_client = Arc::new(CertificateClient::new(
@@ -174,7 +229,8 @@ impl ClientBuilder {
         let mithril_stake_distribution_client = Arc::new(MithrilStakeDistributionClient::new(
             aggregator_client.clone(),
         ));
-        let snapshot_client = Arc::new(SnapshotClient::new(
+        #[allow(unused_mut)]
+        let mut snapshot_client = SnapshotClient::new(
             aggregator_client.clone(),
             #[cfg(feature = "fs")]
             snapshot_downloader,
@@ -182,13 +238,19 @@ impl ClientBuilder {
             feedback_sender,
             #[cfg(feature = "fs")]
             logger,
-        ));
+        );
+        #[cfg(feature = "fs")]
+        if let Some(probe_timeout) = self.snapshot_location_probe_timeout {
+            snapshot_client = snapshot_client.with_probe_timeout(probe_timeout);
+        }
+        let snapshot_client = Arc::new(snapshot_client);
 
         #[cfg(feature = "unstable")]
         let cardano_stake_distribution_client =
             Arc::new(CardanoStakeDistributionClient::new(aggregator_client));
 
         Ok(Client {
+            aggregator_event_client,
             #[cfg(feature = "unstable")]
             cardano_transaction_client,
             #[cfg(feature = "unstable")]
@@ -208,6 +270,15 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the type of client sent to the aggregator, used to compute its statistics
+    /// (default to [LIBRARY][crate::aggregator_client::DEFAULT_CLIENT_TYPE]).
+    ///
+    /// Ignored if a custom [AggregatorClient] is set.
+    pub fn with_client_type(mut self, client_type: &str) -> ClientBuilder {
+        self.client_type = Some(client_type.to_string());
+        self
+    }
+
     /// Set the [CertificateVerifier] that will be used to validate certificates.
     pub fn with_certificate_verifier(
         mut self,
@@ -217,6 +288,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the [CertificateVerifierCache] used by the default certificate verifier to stop the
+    /// verification of a certificate chain at the first certificate already verified.
+    ///
+    /// Ignored if a custom [CertificateVerifier] is set.
+    pub fn with_certificate_verifier_cache(
+        mut self,
+        certificate_verifier_cache: Arc<dyn CertificateVerifierCache>,
+    ) -> ClientBuilder {
+        self.certificate_verifier_cache = Some(certificate_verifier_cache);
+        self
+    }
+
     cfg_fs! {
     /// Set the [SnapshotDownloader] that will be used to download snapshots.
     pub fn with_snapshot_downloader(
@@ -226,6 +309,42 @@ impl ClientBuilder {
         self.snapshot_downloader = Some(snapshot_downloader);
         self
     }
+
+    /// Set the [DownloadRetryPolicy] used by the default snapshot downloader to resume
+    /// interrupted downloads.
+    ///
+    /// Ignored if a custom [SnapshotDownloader] is set.
+    pub fn with_snapshot_download_retry_policy(
+        mut self,
+        retry_policy: DownloadRetryPolicy,
+    ) -> ClientBuilder {
+        self.snapshot_download_retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Set the directory where the default snapshot downloader persists the downloaded bytes,
+    /// allowing to resume a download interrupted by a process restart (see
+    /// [HttpSnapshotDownloader::with_partial_downloads_dir]).
+    ///
+    /// Ignored if a custom [SnapshotDownloader] is set.
+    pub fn with_snapshot_partial_downloads_dir(
+        mut self,
+        partial_downloads_dir: &std::path::Path,
+    ) -> ClientBuilder {
+        self.snapshot_partial_downloads_dir = Some(partial_downloads_dir.to_path_buf());
+        self
+    }
+
+    /// Set the time given to each snapshot location to answer the probe done before a
+    /// download, locations that do not answer in time are not used (default:
+    /// [DEFAULT_SNAPSHOT_LOCATION_PROBE_TIMEOUT][crate::snapshot_client::DEFAULT_SNAPSHOT_LOCATION_PROBE_TIMEOUT]).
+    pub fn with_snapshot_location_probe_timeout(
+        mut self,
+        probe_timeout: std::time::Duration,
+    ) -> ClientBuilder {
+        self.snapshot_location_probe_timeout = Some(probe_timeout);
+        self
+    }
     }
 
     /// Set the [Logger] to use.
@@ -72,6 +72,14 @@ pub enum MithrilEvent {
         /// Size of the downloaded archive
         size: u64,
     },
+    /// A location has been selected to download a snapshot, sent again each time the download
+    /// fails over to another location
+    SnapshotDownloadLocationSelected {
+        /// Unique identifier used to track this specific snapshot download
+        download_id: String,
+        /// Location from which the snapshot is downloaded
+        location: String,
+    },
     /// A snapshot download is in progress
     SnapshotDownloadProgress {
         /// Unique identifier used to track this specific snapshot download
@@ -81,6 +89,16 @@ pub enum MithrilEvent {
         /// Size of the downloaded archive
         size: u64,
     },
+    /// A snapshot download has been resumed after an interruption, either from bytes
+    /// persisted by a previous run or after a network failure
+    SnapshotDownloadResumed {
+        /// Unique identifier used to track this specific snapshot download
+        download_id: String,
+        /// Number of bytes already downloaded when the download was resumed
+        downloaded_bytes: u64,
+        /// Size of the downloaded archive
+        size: u64,
+    },
     /// A snapshot download has completed
     SnapshotDownloadCompleted {
         /// Unique identifier used to track this specific snapshot download
@@ -120,7 +138,9 @@ impl MithrilEvent {
     pub(crate) fn event_id(&self) -> &str {
         match self {
             MithrilEvent::SnapshotDownloadStarted { download_id, .. } => download_id,
+            MithrilEvent::SnapshotDownloadLocationSelected { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadProgress { download_id, .. } => download_id,
+            MithrilEvent::SnapshotDownloadResumed { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadCompleted { download_id } => download_id,
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
@@ -198,6 +218,17 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
                     "download_id" => download_id,
                 );
             }
+            MithrilEvent::SnapshotDownloadLocationSelected {
+                download_id,
+                location,
+            } => {
+                info!(
+                    self.logger,
+                    "Snapshot download location selected";
+                    "location" => location,
+                    "download_id" => download_id,
+                );
+            }
             MithrilEvent::SnapshotDownloadProgress {
                 download_id,
                 downloaded_bytes,
@@ -211,6 +242,19 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
                     "download_id" => download_id,
                 );
             }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id,
+                downloaded_bytes,
+                size,
+            } => {
+                info!(
+                    self.logger,
+                    "Snapshot download resumed";
+                    "downloaded bytes" => downloaded_bytes,
+                    "size" => size,
+                    "download_id" => download_id,
+                );
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id } => {
                 info!(self.logger, "Snapshot download completed"; "download_id" => download_id);
             }
@@ -11,6 +11,7 @@
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs
 //!   _(available using crate feature_ **unstable**_)_.
 //! - [Certificates][certificate_client] list, get, and chain validation.
+//! - [Aggregator events][aggregator_event_client] subscription, as a stream.
 //!
 //! The [Client] aggregates the queries of all of those types.
 //!
@@ -83,13 +84,17 @@ macro_rules! cfg_unstable {
 }
 
 pub mod aggregator_client;
+pub mod aggregator_event_client;
 cfg_unstable! {
     pub mod cardano_stake_distribution_client;
     pub mod cardano_transaction_client;
 }
+pub mod certificate_chain_bundle;
 pub mod certificate_client;
+pub mod certificate_verifier_cache;
 mod client;
 pub mod feedback;
+mod list_filters;
 mod message;
 pub mod mithril_stake_distribution_client;
 pub mod snapshot_client;
@@ -101,6 +106,7 @@ mod type_alias;
 mod utils;
 
 pub use client::*;
+pub use list_filters::*;
 pub use message::*;
 pub use type_alias::*;
 
@@ -0,0 +1,120 @@
+use crate::common::{Epoch, SignedEntityTypeDiscriminants};
+
+/// Pagination and filters sent to the aggregator when listing certificates or artifacts.
+///
+/// The aggregator applies its own defaults for the unset values (ie: it returns the 20 most
+/// recent items if no limit is given) and caps the limit to 100 items.
+///
+/// ```
+/// use mithril_client::{common::Epoch, ListFilters};
+///
+/// // Second page of 10 items from epoch 320
+/// let filters = ListFilters::default()
+///     .with_limit(10)
+///     .with_offset(10)
+///     .with_epoch_from(Epoch(320));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListFilters {
+    /// Maximum number of items to return
+    pub limit: Option<usize>,
+
+    /// Number of the most recent items to skip
+    pub offset: Option<usize>,
+
+    /// Only return the items from this epoch (inclusive)
+    pub epoch_from: Option<Epoch>,
+
+    /// Only return the items up to this epoch (inclusive)
+    pub epoch_to: Option<Epoch>,
+
+    /// Only return the items of this signed entity type (only supported when listing certificates)
+    pub signed_entity_type: Option<SignedEntityTypeDiscriminants>,
+}
+
+impl ListFilters {
+    /// Set the maximum number of items to return
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of the most recent items to skip
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Only return the items from the given epoch (inclusive)
+    pub fn with_epoch_from(mut self, epoch: Epoch) -> Self {
+        self.epoch_from = Some(epoch);
+        self
+    }
+
+    /// Only return the items up to the given epoch (inclusive)
+    pub fn with_epoch_to(mut self, epoch: Epoch) -> Self {
+        self.epoch_to = Some(epoch);
+        self
+    }
+
+    /// Only return the items of the given signed entity type
+    pub fn with_signed_entity_type(
+        mut self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+    ) -> Self {
+        self.signed_entity_type = Some(signed_entity_type);
+        self
+    }
+
+    /// Query string to append to a list route, empty if no filter is set.
+    pub fn to_query_string(&self) -> String {
+        let parameters: Vec<String> = [
+            self.limit.map(|v| format!("limit={v}")),
+            self.offset.map(|v| format!("offset={v}")),
+            self.epoch_from.map(|v| format!("epoch_from={v}")),
+            self.epoch_to.map(|v| format!("epoch_to={v}")),
+            self.signed_entity_type
+                .map(|v| format!("signed_entity_type={v}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if parameters.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", parameters.join("&"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_string_is_empty_without_filters() {
+        assert_eq!("", ListFilters::default().to_query_string());
+    }
+
+    #[test]
+    fn query_string_contains_only_the_set_filters() {
+        assert_eq!(
+            "?limit=5&epoch_to=12",
+            ListFilters::default()
+                .with_limit(5)
+                .with_epoch_to(Epoch(12))
+                .to_query_string()
+        );
+        assert_eq!(
+            "?limit=5&offset=10&epoch_from=3&epoch_to=12&signed_entity_type=CardanoTransactions",
+            ListFilters::default()
+                .with_limit(5)
+                .with_offset(10)
+                .with_epoch_from(Epoch(3))
+                .with_epoch_to(Epoch(12))
+                .with_signed_entity_type(SignedEntityTypeDiscriminants::CardanoTransactions)
+                .to_query_string()
+        );
+    }
+}
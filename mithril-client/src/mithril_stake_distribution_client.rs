@@ -43,7 +43,9 @@ use std::sync::Arc;
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use anyhow::Context;
 
-use crate::{MithrilResult, MithrilStakeDistribution, MithrilStakeDistributionListItem};
+use crate::{
+    ListFilters, MithrilResult, MithrilStakeDistribution, MithrilStakeDistributionListItem,
+};
 
 /// HTTP client for MithrilStakeDistribution API from the Aggregator
 pub struct MithrilStakeDistributionClient {
@@ -58,9 +60,30 @@ impl MithrilStakeDistributionClient {
 
     /// Fetch a list of signed MithrilStakeDistribution
     pub async fn list(&self) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
+        self.fetch_list(AggregatorRequest::ListMithrilStakeDistributions)
+            .await
+    }
+
+    /// Fetch a page of the list of signed MithrilStakeDistribution matching the given filters
+    pub async fn list_with_filters(
+        &self,
+        filters: &ListFilters,
+    ) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
+        self.fetch_list(
+            AggregatorRequest::ListMithrilStakeDistributionsWithFilters {
+                filters: filters.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListMithrilStakeDistributions)
+            .get_content(request)
             .await
             .with_context(|| "MithrilStakeDistribution Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<MithrilStakeDistributionListItem>>(&response)
@@ -3,7 +3,8 @@
 //! In order to do so it defines a [SnapshotClient] which exposes the following features:
 //!  - [get][SnapshotClient::get]: get a single snapshot data from its digest
 //!  - [list][SnapshotClient::list]: get the list of available snapshots
-//!  - [download_unpack][SnapshotClient::download_unpack]: download and unpack the tarball of a snapshot to a directory
+//!  - [download_unpack][SnapshotClient::download_unpack]: download and unpack the tarball of a snapshot to a directory,
+//!    failing over to the next fastest location if a download fails
 //!
 //! # Get a single snapshot
 //!
@@ -102,7 +103,13 @@ use crate::aggregator_client::{AggregatorClient, AggregatorClientError, Aggregat
 use crate::feedback::FeedbackSender;
 #[cfg(feature = "fs")]
 use crate::snapshot_downloader::SnapshotDownloader;
-use crate::{MithrilResult, Snapshot, SnapshotListItem};
+use crate::{ListFilters, MithrilResult, Snapshot, SnapshotListItem};
+
+/// Default time given to a snapshot location to answer a probe before being considered as
+/// not working.
+#[cfg(feature = "fs")]
+pub const DEFAULT_SNAPSHOT_LOCATION_PROBE_TIMEOUT: std::time::Duration =
+    std::time::Duration::from_secs(10);
 
 /// Error for the Snapshot client
 #[derive(Error, Debug)]
@@ -116,6 +123,16 @@ pub enum SnapshotClientError {
         /// list of locations tried
         locations: String,
     },
+
+    /// Download failed from all the working locations
+    #[error("Could not download the snapshot digest '{digest}' from any of the working locations, tried locations: {{'{locations}'}}.")]
+    AllLocationsFailed {
+        /// given digest
+        digest: String,
+
+        /// list of locations tried, from the fastest to the slowest to answer
+        locations: String,
+    },
 }
 
 /// Aggregator client for the snapshot artifact
@@ -126,6 +143,8 @@ pub struct SnapshotClient {
     #[cfg(feature = "fs")]
     feedback_sender: FeedbackSender,
     #[cfg(feature = "fs")]
+    probe_timeout: std::time::Duration,
+    #[cfg(feature = "fs")]
     logger: Logger,
 }
 
@@ -144,15 +163,41 @@ impl SnapshotClient {
             #[cfg(feature = "fs")]
             feedback_sender,
             #[cfg(feature = "fs")]
+            probe_timeout: DEFAULT_SNAPSHOT_LOCATION_PROBE_TIMEOUT,
+            #[cfg(feature = "fs")]
             logger,
         }
     }
 
+    cfg_fs! {
+        /// Set the time given to a snapshot location to answer a probe, locations that do not
+        /// answer in time are considered as not working.
+        pub fn with_probe_timeout(mut self, probe_timeout: std::time::Duration) -> Self {
+            self.probe_timeout = probe_timeout;
+            self
+        }
+    }
+
     /// Return a list of available snapshots
     pub async fn list(&self) -> MithrilResult<Vec<SnapshotListItem>> {
+        self.fetch_list(AggregatorRequest::ListSnapshots).await
+    }
+
+    /// Fetch a page of the list of snapshots matching the given filters
+    pub async fn list_with_filters(
+        &self,
+        filters: &ListFilters,
+    ) -> MithrilResult<Vec<SnapshotListItem>> {
+        self.fetch_list(AggregatorRequest::ListSnapshotsWithFilters {
+            filters: filters.clone(),
+        })
+        .await
+    }
+
+    async fn fetch_list(&self, request: AggregatorRequest) -> MithrilResult<Vec<SnapshotListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListSnapshots)
+            .get_content(request)
             .await
             .with_context(|| "Snapshot Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<SnapshotListItem>>(&response)
@@ -184,6 +229,9 @@ impl SnapshotClient {
     cfg_fs! {
         /// Download and unpack the given snapshot to the given directory
         ///
+        /// All the snapshot locations are probed and the download is done from the fastest
+        /// one to answer. If the download fails, it fails over to the next location.
+        ///
         /// **NOTE**: The directory should already exist, and the user running the binary
         /// must have read/write access to it.
         pub async fn download_unpack(
@@ -193,51 +241,110 @@ impl SnapshotClient {
         ) -> MithrilResult<()> {
             use crate::feedback::MithrilEvent;
 
-            for location in snapshot.locations.as_slice() {
-                if self.snapshot_downloader.probe(location).await.is_ok() {
-                    let download_id = MithrilEvent::new_snapshot_download_id();
-                    self.feedback_sender
-                        .send_event(MithrilEvent::SnapshotDownloadStarted {
-                            digest: snapshot.digest.clone(),
-                            download_id: download_id.clone(),
-                            size: snapshot.size,
-                        })
-                        .await;
-                    return match self
-                        .snapshot_downloader
-                        .download_unpack(
-                            location,
-                            target_dir,
-                            snapshot.compression_algorithm.unwrap_or_default(),
-                            &download_id,
-                            snapshot.size,
-                        )
-                        .await
-                    {
-                        Ok(()) => {
-                            self.feedback_sender
-                                .send_event(MithrilEvent::SnapshotDownloadCompleted { download_id })
-                                .await;
-                            Ok(())
-                        }
-                        Err(e) => {
-                            slog::warn!(
-                                self.logger,
-                                "Failed downloading snapshot from '{location}' Error: {e}."
-                            );
-                            Err(e)
-                        }
-                    };
+            let ranked_locations = self.rank_locations_by_latency(&snapshot.locations).await;
+            if ranked_locations.is_empty() {
+                return Err(SnapshotClientError::NoWorkingLocation {
+                    digest: snapshot.digest.clone(),
+                    locations: snapshot.locations.join(", "),
                 }
+                .into());
             }
 
-            let locations = snapshot.locations.join(", ");
+            let download_id = MithrilEvent::new_snapshot_download_id();
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadStarted {
+                    digest: snapshot.digest.clone(),
+                    download_id: download_id.clone(),
+                    size: snapshot.size,
+                })
+                .await;
+
+            let mut last_error = None;
+            for location in &ranked_locations {
+                self.feedback_sender
+                    .send_event(MithrilEvent::SnapshotDownloadLocationSelected {
+                        download_id: download_id.clone(),
+                        location: location.to_string(),
+                    })
+                    .await;
 
-            Err(SnapshotClientError::NoWorkingLocation {
-                digest: snapshot.digest.clone(),
-                locations,
+                match self
+                    .snapshot_downloader
+                    .download_unpack(
+                        location,
+                        target_dir,
+                        snapshot.compression_algorithm.unwrap_or_default(),
+                        &download_id,
+                        snapshot.size,
+                    )
+                    .await
+                {
+                    Ok(()) => {
+                        self.feedback_sender
+                            .send_event(MithrilEvent::SnapshotDownloadCompleted { download_id })
+                            .await;
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        slog::warn!(
+                            self.logger,
+                            "Failed downloading snapshot from '{location}' Error: {e}."
+                        );
+                        last_error = Some(e);
+                    }
+                }
             }
-            .into())
+
+            Err(last_error
+                .unwrap()
+                .context(SnapshotClientError::AllLocationsFailed {
+                    digest: snapshot.digest.clone(),
+                    locations: ranked_locations.join(", "),
+                }))
+        }
+
+        /// Probe the given locations concurrently and return the working ones sorted from the
+        /// fastest to the slowest to answer.
+        ///
+        /// A location that does not answer before the probe timeout is not working.
+        async fn rank_locations_by_latency<'a>(&self, locations: &'a [String]) -> Vec<&'a str> {
+            let probes = locations.iter().map(|location| async move {
+                let start = tokio::time::Instant::now();
+                match tokio::time::timeout(
+                    self.probe_timeout,
+                    self.snapshot_downloader.probe(location),
+                )
+                .await
+                {
+                    Ok(Ok(())) => Some((location.as_str(), start.elapsed())),
+                    Ok(Err(e)) => {
+                        slog::debug!(
+                            self.logger,
+                            "Snapshot location '{location}' is not available: {e}."
+                        );
+                        None
+                    }
+                    Err(_) => {
+                        slog::debug!(
+                            self.logger,
+                            "Snapshot location '{location}' did not answer within {:?}.",
+                            self.probe_timeout
+                        );
+                        None
+                    }
+                }
+            });
+            let mut working_locations: Vec<_> = futures::future::join_all(probes)
+                .await
+                .into_iter()
+                .flatten()
+                .collect();
+            working_locations.sort_by_key(|(_, latency)| *latency);
+
+            working_locations
+                .into_iter()
+                .map(|(location, _)| location)
+                .collect()
         }
     }
 
@@ -262,7 +369,13 @@ mod tests_download {
         snapshot_downloader::MockHttpSnapshotDownloader,
         test_utils,
     };
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use mithril_common::entities::CompressionAlgorithm;
+    use std::collections::HashMap;
     use std::path::Path;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     use super::*;
 
@@ -295,6 +408,10 @@ mod tests_download {
                 download_id: id.to_string(),
                 size: snapshot.size,
             },
+            MithrilEvent::SnapshotDownloadLocationSelected {
+                download_id: id.to_string(),
+                location: snapshot.locations[0].clone(),
+            },
             MithrilEvent::SnapshotDownloadCompleted {
                 download_id: id.to_string(),
             },
@@ -302,4 +419,297 @@ mod tests_download {
 
         assert_eq!(actual, expected);
     }
+
+    /// A snapshot downloader that answers probes after a per-location delay (or never) and
+    /// fails the downloads of some locations, recording the locations it downloads from.
+    ///
+    /// The tests using it run with a paused clock, so the probe delays are deterministic.
+    struct FakeSnapshotDownloader {
+        probe_delays: HashMap<String, Option<Duration>>,
+        never_answering_probes: Vec<String>,
+        failing_downloads: Vec<String>,
+        downloaded_locations: Mutex<Vec<String>>,
+    }
+
+    impl FakeSnapshotDownloader {
+        fn new(probe_delays: &[(&str, Option<u64>)], failing_downloads: &[&str]) -> Self {
+            Self {
+                probe_delays: probe_delays
+                    .iter()
+                    .map(|(location, delay)| {
+                        (location.to_string(), delay.map(Duration::from_millis))
+                    })
+                    .collect(),
+                never_answering_probes: vec![],
+                failing_downloads: failing_downloads.iter().map(|l| l.to_string()).collect(),
+                downloaded_locations: Mutex::new(vec![]),
+            }
+        }
+
+        fn with_never_answering_probes(mut self, locations: &[&str]) -> Self {
+            self.never_answering_probes = locations.iter().map(|l| l.to_string()).collect();
+            self
+        }
+
+        fn downloaded_locations(&self) -> Vec<String> {
+            self.downloaded_locations.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl SnapshotDownloader for FakeSnapshotDownloader {
+        async fn download_unpack(
+            &self,
+            location: &str,
+            _target_dir: &Path,
+            _compression_algorithm: CompressionAlgorithm,
+            _download_id: &str,
+            _snapshot_size: u64,
+        ) -> MithrilResult<()> {
+            self.downloaded_locations
+                .lock()
+                .unwrap()
+                .push(location.to_string());
+
+            if self.failing_downloads.contains(&location.to_string()) {
+                Err(anyhow!("download failed from {location}"))
+            } else {
+                Ok(())
+            }
+        }
+
+        async fn probe(&self, location: &str) -> MithrilResult<()> {
+            if self.never_answering_probes.contains(&location.to_string()) {
+                return std::future::pending().await;
+            }
+            match self.probe_delays.get(location).cloned().flatten() {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    Ok(())
+                }
+                None => Err(anyhow!("location {location} unreachable")),
+            }
+        }
+    }
+
+    fn build_client(
+        snapshot_downloader: Arc<FakeSnapshotDownloader>,
+        feedback_receiver: Arc<StackFeedbackReceiver>,
+    ) -> SnapshotClient {
+        SnapshotClient::new(
+            Arc::new(MockAggregatorHTTPClient::new()),
+            snapshot_downloader,
+            FeedbackSender::new(&[feedback_receiver]),
+            test_utils::test_logger(),
+        )
+    }
+
+    fn snapshot_with_locations(locations: &[&str]) -> Snapshot {
+        Snapshot {
+            locations: locations.iter().map(|l| l.to_string()).collect(),
+            ..Snapshot::dummy()
+        }
+    }
+
+    fn selected_locations(events: &[MithrilEvent]) -> Vec<String> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                MithrilEvent::SnapshotDownloadLocationSelected { location, .. } => {
+                    Some(location.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_from_the_fastest_location_first() {
+        let snapshot_downloader = Arc::new(FakeSnapshotDownloader::new(
+            &[("slow", Some(80)), ("fast", Some(0)), ("medium", Some(40))],
+            &[],
+        ));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone());
+
+        client
+            .download_unpack(
+                &snapshot_with_locations(&["slow", "fast", "medium"]),
+                Path::new(""),
+            )
+            .await
+            .expect("download should succeed");
+
+        assert_eq!(vec!["fast"], snapshot_downloader.downloaded_locations());
+        assert_eq!(
+            vec!["fast"],
+            selected_locations(&feedback_receiver.stacked_events())
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_fail_over_to_the_next_fastest_location() {
+        let snapshot_downloader = Arc::new(FakeSnapshotDownloader::new(
+            &[("slow", Some(80)), ("fast", Some(0)), ("medium", Some(40))],
+            &["fast"],
+        ));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone());
+
+        client
+            .download_unpack(
+                &snapshot_with_locations(&["slow", "fast", "medium"]),
+                Path::new(""),
+            )
+            .await
+            .expect("download should succeed");
+
+        assert_eq!(
+            vec!["fast", "medium"],
+            snapshot_downloader.downloaded_locations()
+        );
+        let events = feedback_receiver.stacked_events();
+        assert_eq!(vec!["fast", "medium"], selected_locations(&events));
+        assert!(
+            matches!(
+                events.last(),
+                Some(MithrilEvent::SnapshotDownloadCompleted { .. })
+            ),
+            "last event should be a download completed, got: {events:?}"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_skip_unreachable_locations() {
+        let snapshot_downloader = Arc::new(FakeSnapshotDownloader::new(
+            &[("unreachable", None), ("reachable", Some(10))],
+            &[],
+        ));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone());
+
+        client
+            .download_unpack(
+                &snapshot_with_locations(&["unreachable", "reachable"]),
+                Path::new(""),
+            )
+            .await
+            .expect("download should succeed");
+
+        assert_eq!(
+            vec!["reachable"],
+            snapshot_downloader.downloaded_locations()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_if_no_location_is_reachable() {
+        let snapshot_downloader = Arc::new(FakeSnapshotDownloader::new(
+            &[("unreachable-1", None), ("unreachable-2", None)],
+            &[],
+        ));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone());
+
+        let error = client
+            .download_unpack(
+                &snapshot_with_locations(&["unreachable-1", "unreachable-2"]),
+                Path::new(""),
+            )
+            .await
+            .expect_err("download should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<SnapshotClientError>(),
+                Some(SnapshotClientError::NoWorkingLocation { .. })
+            ),
+            "unexpected error: {error:?}"
+        );
+        assert!(snapshot_downloader.downloaded_locations().is_empty());
+        assert!(feedback_receiver.stacked_events().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_fails_if_all_locations_fail() {
+        let snapshot_downloader = Arc::new(FakeSnapshotDownloader::new(
+            &[("location-1", Some(0)), ("location-2", Some(20))],
+            &["location-1", "location-2"],
+        ));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone());
+
+        let error = client
+            .download_unpack(
+                &snapshot_with_locations(&["location-1", "location-2"]),
+                Path::new(""),
+            )
+            .await
+            .expect_err("download should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<SnapshotClientError>(),
+                Some(SnapshotClientError::AllLocationsFailed { .. })
+            ),
+            "unexpected error: {error:?}"
+        );
+        assert_eq!(
+            vec!["location-1", "location-2"],
+            snapshot_downloader.downloaded_locations()
+        );
+        assert!(!feedback_receiver
+            .stacked_events()
+            .iter()
+            .any(|e| matches!(e, MithrilEvent::SnapshotDownloadCompleted { .. })));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_skip_locations_that_do_not_answer_before_the_probe_timeout() {
+        let snapshot_downloader = Arc::new(
+            FakeSnapshotDownloader::new(&[("slow", Some(2_000)), ("reachable", Some(10))], &[])
+                .with_never_answering_probes(&["hanging"]),
+        );
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader.clone(), feedback_receiver.clone())
+            .with_probe_timeout(Duration::from_secs(1));
+
+        client
+            .download_unpack(
+                &snapshot_with_locations(&["hanging", "slow", "reachable"]),
+                Path::new(""),
+            )
+            .await
+            .expect("download should succeed");
+
+        assert_eq!(
+            vec!["reachable"],
+            selected_locations(&feedback_receiver.stacked_events())
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn download_unpack_fails_if_no_location_answers_before_the_probe_timeout() {
+        let snapshot_downloader = Arc::new(
+            FakeSnapshotDownloader::new(&[], &[]).with_never_answering_probes(&["hanging"]),
+        );
+        let client = build_client(
+            snapshot_downloader.clone(),
+            Arc::new(StackFeedbackReceiver::new()),
+        );
+
+        let error = client
+            .download_unpack(&snapshot_with_locations(&["hanging"]), Path::new(""))
+            .await
+            .expect_err("download should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<SnapshotClientError>(),
+                Some(SnapshotClientError::NoWorkingLocation { .. })
+            ),
+            "unexpected error: {error:?}"
+        );
+        assert!(snapshot_downloader.downloaded_locations().is_empty());
+    }
 }
@@ -6,13 +6,25 @@
 //! Snapshots locations can be of various kinds, right now we only support HTTP
 //! download (using the [HttpSnapshotDownloader]) but other types may be added in
 //! the future.
+//!
+//! The [HttpSnapshotDownloader] resumes interrupted downloads using HTTP `Range` requests,
+//! retrying with an exponential backoff as defined by its [DownloadRetryPolicy].
+//! If a [partial downloads directory][HttpSnapshotDownloader::with_partial_downloads_dir] is
+//! set, the downloaded bytes are also persisted to disk so a download interrupted by a
+//! process restart can be resumed. They are stored with the validator (`ETag` or
+//! `Last-Modified`) of the remote snapshot, so they are only resumed if the remote snapshot
+//! did not change.
 
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use futures::StreamExt;
-use reqwest::{Response, StatusCode};
-use slog::{debug, Logger};
-use std::path::Path;
+use reqwest::{header, header::HeaderMap, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use slog::{debug, warn, Logger};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 #[cfg(test)]
 use mockall::automock;
@@ -22,11 +34,20 @@ use crate::feedback::{FeedbackSender, MithrilEvent};
 use crate::utils::SnapshotUnpacker;
 use crate::MithrilResult;
 
+/// Size of the chunks read when replaying a partial download from the disk.
+const PARTIAL_DOWNLOAD_READ_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Extension appended to the path of a partial download to store its [PartialDownloadMetadata].
+const PARTIAL_DOWNLOAD_METADATA_EXTENSION: &str = "metadata";
+
 /// API that defines a snapshot downloader
 #[async_trait]
 pub trait SnapshotDownloader: Sync + Send {
     /// Download and unpack a snapshot archive on the disk.
     ///
+    /// Implementations may resume the download if it is interrupted, in which case a
+    /// [MithrilEvent::SnapshotDownloadResumed] event is sent.
+    ///
     /// The `download_id` is a unique identifier that allow
     /// [feedback receivers][crate::feedback::FeedbackReceiver] to track concurrent downloads.
     ///
@@ -44,10 +65,98 @@ pub trait SnapshotDownloader: Sync + Send {
     async fn probe(&self, location: &str) -> MithrilResult<()>;
 }
 
+/// Define how many times and how often an interrupted download is resumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadRetryPolicy {
+    /// Maximum number of attempts to download a snapshot, including the first one.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, doubled after each failed attempt.
+    pub initial_delay: Duration,
+
+    /// Maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl DownloadRetryPolicy {
+    /// A policy that never retries a failed download.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    /// Compute the delay to wait before the given retry (starting at 1).
+    pub fn delay_before_retry(&self, retry: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+impl Default for DownloadRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Errors of a download attempt that can not be fixed by resuming the download.
+#[derive(Error, Debug)]
+enum DownloadError {
+    /// The download can not succeed, ie: the location does not exist.
+    #[error(transparent)]
+    Fatal(anyhow::Error),
+
+    /// The unpacker stopped reading the downloaded bytes, it most likely failed.
+    #[error(transparent)]
+    UnpackerStopped(anyhow::Error),
+}
+
+/// Metadata stored next to a partial download, used to check that the persisted bytes are
+/// the beginning of the remote snapshot before resuming its download.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PartialDownloadMetadata {
+    /// Location the bytes were downloaded from
+    location: String,
+
+    /// Validator of the remote snapshot: its `ETag`, or its `Last-Modified` date if it has none
+    validator: String,
+}
+
+/// File where the downloaded bytes are persisted.
+struct PartialDownloadFile {
+    path: PathBuf,
+    file: tokio::fs::File,
+}
+
+fn partial_download_metadata_path(partial_download_path: &Path) -> PathBuf {
+    let mut path = partial_download_path.as_os_str().to_owned();
+    path.push(format!(".{PARTIAL_DOWNLOAD_METADATA_EXTENSION}"));
+    PathBuf::from(path)
+}
+
+/// Validator of a remote content that can be sent in an `If-Range` header
+fn remote_validator(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::ETAG)
+        .or_else(|| headers.get(header::LAST_MODIFIED))
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
 /// A snapshot downloader that only handles download through HTTP.
 pub struct HttpSnapshotDownloader {
     http_client: reqwest::Client,
     feedback_sender: FeedbackSender,
+    retry_policy: DownloadRetryPolicy,
+    partial_downloads_dir: Option<PathBuf>,
     logger: Logger,
 }
 
@@ -61,21 +170,455 @@ impl HttpSnapshotDownloader {
         Ok(Self {
             http_client,
             feedback_sender,
+            retry_policy: DownloadRetryPolicy::default(),
+            partial_downloads_dir: None,
             logger,
         })
     }
 
-    async fn get(&self, location: &str) -> MithrilResult<Response> {
-        debug!(self.logger, "GET Snapshot location='{location}'.");
-        let request_builder = self.http_client.get(location);
-        let response = request_builder.send().await.with_context(|| {
+    /// Set the [DownloadRetryPolicy] used to resume interrupted downloads.
+    pub fn with_retry_policy(mut self, retry_policy: DownloadRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set a directory where the downloaded bytes are persisted until the snapshot is fully
+    /// unpacked, allowing to resume a download interrupted by a process restart.
+    ///
+    /// **NOTE**: The directory should already exist, should not be the directory where the
+    /// snapshot is unpacked, and needs enough free space to store the whole archive.
+    pub fn with_partial_downloads_dir(mut self, partial_downloads_dir: &Path) -> Self {
+        self.partial_downloads_dir = Some(partial_downloads_dir.to_path_buf());
+        self
+    }
+
+    fn partial_download_path(&self, location: &str) -> Option<PathBuf> {
+        let file_name = reqwest::Url::parse(location)
+            .ok()
+            .and_then(|url| {
+                url.path_segments()
+                    .and_then(|mut segments| segments.next_back().map(str::to_string))
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "snapshot".to_string());
+
+        self.partial_downloads_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{file_name}.part")))
+    }
+
+    async fn send_get(
+        &self,
+        location: &str,
+        offset: u64,
+        validator: Option<&str>,
+    ) -> MithrilResult<Response> {
+        debug!(self.logger, "GET Snapshot location='{location}'."; "offset" => offset);
+        let mut request_builder = self.http_client.get(location);
+        if offset > 0 {
+            request_builder = request_builder.header(header::RANGE, format!("bytes={offset}-"));
+            // The server sends the whole snapshot if it does not match the validator
+            if let Some(validator) = validator {
+                request_builder = request_builder.header(header::IF_RANGE, validator);
+            }
+        }
+
+        request_builder.send().await.with_context(|| {
             format!("Cannot perform a GET for the snapshot (location='{location}')")
-        })?;
+        })
+    }
+
+    async fn send_head(&self, location: &str) -> MithrilResult<Response> {
+        debug!(self.logger, "HEAD Snapshot location='{location}'.");
+
+        self.http_client
+            .head(location)
+            .send()
+            .await
+            .with_context(|| format!("Cannot perform a HEAD for snapshot at location='{location}'"))
+    }
+
+    async fn get(
+        &self,
+        location: &str,
+        offset: u64,
+        validator: Option<&str>,
+    ) -> MithrilResult<Response> {
+        let mut response = self.send_get(location, offset, validator).await?;
+        // The requested range is exhausted (ie: the snapshot size is not the expected one):
+        // the whole archive is requested again, the bytes already received will be skipped.
+        if offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            warn!(
+                self.logger, "Range not satisfiable, restarting snapshot download from the beginning";
+                "offset" => offset, "location" => location
+            );
+            response = self.send_get(location, 0, None).await?;
+        }
 
         match response.status() {
-            StatusCode::OK => Ok(response),
-            StatusCode::NOT_FOUND => Err(anyhow!("Location='{location} not found")),
-            status_code => Err(anyhow!("Unhandled error {status_code}")),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok(response),
+            StatusCode::NOT_FOUND => Err(DownloadError::Fatal(anyhow!(
+                "Location='{location} not found"
+            )))?,
+            status_code if status_code.is_server_error() => {
+                Err(anyhow!("Unhandled error {status_code}"))
+            }
+            status_code => Err(DownloadError::Fatal(anyhow!(
+                "Unhandled error {status_code}"
+            )))?,
+        }
+    }
+
+    /// Check that the bytes persisted by a previous run are the beginning of the remote
+    /// snapshot, returns the validator of the remote snapshot if so.
+    ///
+    /// Persisted bytes that can not be resumed are removed.
+    async fn check_partial_download(
+        &self,
+        location: &str,
+        partial_download_path: &Path,
+        snapshot_size: u64,
+    ) -> MithrilResult<Option<String>> {
+        if !partial_download_path.exists() {
+            self.remove_partial_download(partial_download_path).await;
+            return Ok(None);
+        }
+
+        let metadata = tokio::fs::read(partial_download_metadata_path(partial_download_path))
+            .await
+            .ok()
+            .and_then(|content| serde_json::from_slice::<PartialDownloadMetadata>(&content).ok())
+            .filter(|metadata| metadata.location == location);
+        let Some(metadata) = metadata else {
+            warn!(
+                self.logger, "Persisted snapshot bytes were not downloaded from this location, discarding them";
+                "path" => partial_download_path.display(), "location" => location
+            );
+            self.remove_partial_download(partial_download_path).await;
+            return Ok(None);
+        };
+        let persisted_bytes = tokio::fs::metadata(partial_download_path)
+            .await
+            .with_context(|| {
+                format!(
+                    "Could not read partial download file '{}'",
+                    partial_download_path.display()
+                )
+            })?
+            .len();
+
+        let response = self.send_head(location).await?;
+        match response.status() {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => Err(DownloadError::Fatal(anyhow!(
+                "Location='{location} not found"
+            )))?,
+            status_code if status_code.is_server_error() => {
+                Err(anyhow!("Unhandled error {status_code}"))?
+            }
+            status_code => Err(DownloadError::Fatal(anyhow!(
+                "Unhandled error {status_code}"
+            )))?,
+        }
+        let remote_size = response
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        let is_larger_than = |size: Option<u64>| size.is_some_and(|size| persisted_bytes > size);
+
+        if remote_validator(response.headers()).as_ref() != Some(&metadata.validator)
+            || is_larger_than(remote_size)
+            || is_larger_than(Some(snapshot_size).filter(|size| *size > 0))
+        {
+            warn!(
+                self.logger, "Remote snapshot changed since the persisted bytes were downloaded, discarding them";
+                "path" => partial_download_path.display(), "location" => location
+            );
+            self.remove_partial_download(partial_download_path).await;
+            return Ok(None);
+        }
+
+        Ok(Some(metadata.validator))
+    }
+
+    /// Remove the persisted bytes of a download and their metadata.
+    async fn remove_partial_download(&self, partial_download_path: &Path) {
+        for path in [
+            partial_download_path.to_path_buf(),
+            partial_download_metadata_path(partial_download_path),
+        ] {
+            if path.exists() {
+                if let Err(error) = tokio::fs::remove_file(&path).await {
+                    warn!(
+                        self.logger, "Could not remove partial download file";
+                        "path" => path.display(), "error" => ?error
+                    );
+                }
+            }
+        }
+    }
+
+    /// Store the metadata of the bytes persisted to the given partial download file.
+    async fn write_partial_download_metadata(
+        &self,
+        partial_download_path: &Path,
+        metadata: &PartialDownloadMetadata,
+    ) -> MithrilResult<()> {
+        let metadata_path = partial_download_metadata_path(partial_download_path);
+        tokio::fs::write(&metadata_path, serde_json::to_vec(metadata)?)
+            .await
+            .with_context(|| {
+                format!(
+                    "Could not write partial download metadata file '{}'",
+                    metadata_path.display()
+                )
+            })
+    }
+
+    /// Send the bytes persisted by a previous run to the unpacker, returns the number of
+    /// bytes sent.
+    async fn replay_partial_download(
+        &self,
+        partial_download_path: &Path,
+        sender: &flume::Sender<Vec<u8>>,
+    ) -> MithrilResult<u64> {
+        if !partial_download_path.exists() {
+            return Ok(0);
+        }
+
+        let mut file = tokio::fs::File::open(partial_download_path)
+            .await
+            .with_context(|| {
+                format!(
+                    "Could not open partial download file '{}'",
+                    partial_download_path.display()
+                )
+            })?;
+        let mut replayed_bytes = 0;
+        loop {
+            let mut chunk = vec![0; PARTIAL_DOWNLOAD_READ_CHUNK_SIZE];
+            let read = file.read(&mut chunk).await.with_context(|| {
+                format!(
+                    "Could not read partial download file '{}'",
+                    partial_download_path.display()
+                )
+            })?;
+            if read == 0 {
+                break;
+            }
+            chunk.truncate(read);
+            sender.send_async(chunk).await.map_err(|e| {
+                DownloadError::UnpackerStopped(
+                    anyhow!(e).context("Download: could not replay partial download to stream."),
+                )
+            })?;
+            replayed_bytes += read as u64;
+        }
+
+        Ok(replayed_bytes)
+    }
+
+    /// Stream the snapshot from the given offset to the unpacker, persisting the received
+    /// bytes to the partial download file if any.
+    ///
+    /// The given validator is the one of the snapshot the already downloaded bytes belong to,
+    /// it is set by the first response if unknown.
+    #[allow(clippy::too_many_arguments)]
+    async fn download_from(
+        &self,
+        location: &str,
+        download_id: &str,
+        snapshot_size: u64,
+        downloaded_bytes: &mut u64,
+        validator: &mut Option<String>,
+        partial_download_file: &mut Option<PartialDownloadFile>,
+        sender: &flume::Sender<Vec<u8>>,
+    ) -> MithrilResult<()> {
+        if snapshot_size > 0 && *downloaded_bytes >= snapshot_size {
+            debug!(
+                self.logger, "Snapshot already fully downloaded, skipping download";
+                "downloaded_bytes" => *downloaded_bytes, "location" => location
+            );
+            return Ok(());
+        }
+        let response = self
+            .get(location, *downloaded_bytes, validator.as_deref())
+            .await?;
+        let response_validator = remote_validator(response.headers());
+        let mut bytes_to_skip = match response.status() {
+            StatusCode::PARTIAL_CONTENT => 0,
+            // The bytes already sent to the unpacker can not be taken back
+            _ if *downloaded_bytes > 0
+                && validator.is_some()
+                && response_validator != *validator =>
+            {
+                return Err(DownloadError::Fatal(anyhow!(
+                    "Snapshot at location='{location}' changed during its download"
+                )))?;
+            }
+            // A server that does not support range requests sends the whole archive: the
+            // bytes already sent to the unpacker must be skipped.
+            _ => *downloaded_bytes,
+        };
+        if validator.is_none() {
+            *validator = response_validator;
+            if let Some(partial_download) = partial_download_file.take() {
+                match validator {
+                    Some(validator) => {
+                        self.write_partial_download_metadata(
+                            &partial_download.path,
+                            &PartialDownloadMetadata {
+                                location: location.to_string(),
+                                validator: validator.clone(),
+                            },
+                        )
+                        .await?;
+                        *partial_download_file = Some(partial_download);
+                    }
+                    None => {
+                        warn!(
+                            self.logger, "Remote snapshot has no validator, the downloaded bytes will not be persisted";
+                            "location" => location
+                        );
+                        drop(partial_download.file);
+                        self.remove_partial_download(&partial_download.path).await;
+                    }
+                }
+            }
+        }
+        let mut remote_stream = response.bytes_stream();
+
+        while let Some(item) = remote_stream.next().await {
+            let mut chunk = item.with_context(|| "Download: Could not read from byte stream")?;
+            if bytes_to_skip >= chunk.len() as u64 {
+                bytes_to_skip -= chunk.len() as u64;
+                continue;
+            }
+            chunk = chunk.slice(bytes_to_skip as usize..);
+            bytes_to_skip = 0;
+
+            if let Some(partial_download) = partial_download_file {
+                partial_download.file.write_all(&chunk).await.map_err(|e| {
+                    DownloadError::Fatal(
+                        anyhow!(e).context("Download: could not persist downloaded bytes."),
+                    )
+                })?;
+            }
+            sender.send_async(chunk.to_vec()).await.map_err(|e| {
+                DownloadError::UnpackerStopped(anyhow!(e).context(format!(
+                    "Download: could not write {} bytes to stream.",
+                    chunk.len()
+                )))
+            })?;
+
+            *downloaded_bytes += chunk.len() as u64;
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadProgress {
+                    download_id: download_id.to_owned(),
+                    downloaded_bytes: *downloaded_bytes,
+                    size: snapshot_size,
+                })
+                .await
+        }
+
+        Ok(())
+    }
+
+    /// Download the snapshot and send it to the unpacker, resuming the download after a
+    /// failure as long as the retry policy allows it.
+    ///
+    /// The given sender is dropped when this function returns, signaling EOF to the unpacker.
+    async fn download_with_retries(
+        &self,
+        location: &str,
+        download_id: &str,
+        snapshot_size: u64,
+        partial_download_path: Option<&Path>,
+        sender: flume::Sender<Vec<u8>>,
+    ) -> MithrilResult<()> {
+        let mut validator = match partial_download_path {
+            Some(path) => {
+                self.check_partial_download(location, path, snapshot_size)
+                    .await?
+            }
+            None => None,
+        };
+        let mut downloaded_bytes = match (partial_download_path, &validator) {
+            (Some(path), Some(_)) => self.replay_partial_download(path, &sender).await?,
+            _ => 0,
+        };
+        if downloaded_bytes > 0 {
+            debug!(
+                self.logger, "Resuming snapshot download from a previous run";
+                "downloaded_bytes" => downloaded_bytes, "location" => location
+            );
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadResumed {
+                    download_id: download_id.to_owned(),
+                    downloaded_bytes,
+                    size: snapshot_size,
+                })
+                .await;
+        }
+        let mut partial_download_file = match partial_download_path {
+            Some(path) => Some(PartialDownloadFile {
+                path: path.to_path_buf(),
+                file: tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .await
+                    .with_context(|| {
+                        format!("Could not open partial download file '{}'", path.display())
+                    })?,
+            }),
+            None => None,
+        };
+
+        let mut attempt = 1;
+        loop {
+            let result = self
+                .download_from(
+                    location,
+                    download_id,
+                    snapshot_size,
+                    &mut downloaded_bytes,
+                    &mut validator,
+                    &mut partial_download_file,
+                    &sender,
+                )
+                .await;
+
+            match result {
+                Ok(()) => return Ok(()),
+                Err(error)
+                    if error.downcast_ref::<DownloadError>().is_none()
+                        && attempt < self.retry_policy.max_attempts =>
+                {
+                    let delay = self.retry_policy.delay_before_retry(attempt);
+                    warn!(
+                        self.logger, "Snapshot download interrupted, resuming in {delay:?}";
+                        "attempt" => attempt, "downloaded_bytes" => downloaded_bytes,
+                        "location" => location, "error" => ?error
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    self.feedback_sender
+                        .send_event(MithrilEvent::SnapshotDownloadResumed {
+                            download_id: download_id.to_owned(),
+                            downloaded_bytes,
+                            size: snapshot_size,
+                        })
+                        .await;
+                }
+                Err(error) if error.downcast_ref::<DownloadError>().is_none() => {
+                    return Err(
+                        error.context(format!("Download: giving up after {attempt} attempt(s)"))
+                    );
+                }
+                Err(error) => return Err(error),
+            }
         }
     }
 }
@@ -97,8 +640,7 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
                     .context("Download-Unpack: prerequisite error"),
             )?;
         }
-        let mut downloaded_bytes: u64 = 0;
-        let mut remote_stream = self.get(location).await?.bytes_stream();
+        let partial_download_path = self.partial_download_path(location);
         let (sender, receiver) = flume::bounded(5);
 
         let dest_dir = target_dir.to_path_buf();
@@ -107,46 +649,53 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
             unpacker.unpack_snapshot(receiver, compression_algorithm, &dest_dir)
         });
 
-        while let Some(item) = remote_stream.next().await {
-            let chunk = item.with_context(|| "Download: Could not read from byte stream")?;
-
-            sender.send_async(chunk.to_vec()).await.with_context(|| {
-                format!("Download: could not write {} bytes to stream.", chunk.len())
-            })?;
-
-            downloaded_bytes += chunk.len() as u64;
-            self.feedback_sender
-                .send_event(MithrilEvent::SnapshotDownloadProgress {
-                    download_id: download_id.to_owned(),
-                    downloaded_bytes,
-                    size: snapshot_size,
-                })
-                .await
-        }
-
-        drop(sender); // Signal EOF
-        unpack_thread
+        let download_result = self
+            .download_with_retries(
+                location,
+                download_id,
+                snapshot_size,
+                partial_download_path.as_deref(),
+                sender,
+            )
+            .await;
+        let unpack_result = unpack_thread
             .await
             .with_context(|| {
                 format!(
                     "Unpack: panic while unpacking to dir '{}'",
                     target_dir.display()
                 )
-            })?
-            .with_context(|| {
-                format!("Unpack: could not unpack to dir '{}'", target_dir.display())
-            })?;
+            })
+            .and_then(|result| {
+                result.with_context(|| {
+                    format!("Unpack: could not unpack to dir '{}'", target_dir.display())
+                })
+            });
+        let download_error = download_result
+            .as_ref()
+            .err()
+            .and_then(|e| e.downcast_ref::<DownloadError>());
+        let unpacker_stopped = matches!(download_error, Some(DownloadError::UnpackerStopped(_)));
 
-        Ok(())
+        // Persisted bytes are kept only if the download was interrupted: once the whole
+        // archive has been read by the unpacker they are either useless or corrupted, and
+        // they can not be used to resume a download that can not succeed.
+        if let Some(path) = partial_download_path {
+            if download_result.is_ok() || download_error.is_some() {
+                self.remove_partial_download(&path).await;
+            }
+        }
+
+        // If the unpacker stopped, its error explains why the download failed
+        if unpacker_stopped && unpack_result.is_err() {
+            return unpack_result;
+        }
+        download_result?;
+        unpack_result
     }
 
     async fn probe(&self, location: &str) -> MithrilResult<()> {
-        debug!(self.logger, "HEAD Snapshot location='{location}'.");
-
-        let request_builder = self.http_client.head(location);
-        let response = request_builder.send().await.with_context(|| {
-            format!("Cannot perform a HEAD for snapshot at location='{location}'")
-        })?;
+        let response = self.send_head(location).await?;
 
         match response.status() {
             StatusCode::OK => Ok(()),
@@ -155,3 +704,730 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use flate2::{write::GzEncoder, Compression};
+    use std::sync::{Arc, Mutex};
+    use warp::http::Method;
+    use warp::Filter;
+
+    use mithril_common::test_utils::{
+        test_http_server::{test_http_server, TestHttpServer},
+        TempDir,
+    };
+
+    use crate::feedback::StackFeedbackReceiver;
+    use crate::test_utils;
+
+    use super::*;
+
+    const ARCHIVE_NAME: &str = "snapshot.tar.gz";
+    const FILE_IN_ARCHIVE: &str = "immutable/00001.chunk";
+
+    fn file_content() -> Vec<u8> {
+        // Pseudo random content so the compressed archive is not too small
+        (0..200_000_u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect()
+    }
+
+    fn build_archive() -> Vec<u8> {
+        let content = file_content();
+        let mut tar = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::fast()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, FILE_IN_ARCHIVE, content.as_slice())
+            .unwrap();
+
+        tar.into_inner().unwrap().finish().unwrap()
+    }
+
+    const ARCHIVE_ETAG: &str = "\"archive-etag\"";
+    const CHANGED_ARCHIVE_ETAG: &str = "\"changed-archive-etag\"";
+
+    /// How the fake snapshot server answers to the n-th request (starting at 0)
+    #[derive(Clone, Copy)]
+    struct ServerBehavior {
+        /// Number of requests that are interrupted after sending half of the archive
+        interrupted_requests: usize,
+        /// If false the `Range` header is ignored and the whole archive is always sent
+        support_range: bool,
+        /// If true the `ETag` of the archive changes once the interrupted requests are sent
+        changed_after_interruptions: bool,
+    }
+
+    /// Spawn a server serving the given archive, returns it with the list of the `Range`
+    /// headers of the GET requests it received.
+    fn spawn_snapshot_server(
+        archive: Vec<u8>,
+        behavior: ServerBehavior,
+    ) -> (TestHttpServer, Arc<Mutex<Vec<Option<String>>>>) {
+        let received_ranges = Arc::new(Mutex::new(vec![]));
+        let received_ranges_clone = received_ranges.clone();
+        let routes = warp::path(ARCHIVE_NAME)
+            .and(warp::method())
+            .and(warp::header::optional::<String>("range"))
+            .and(warp::header::optional::<String>("if-range"))
+            .map(
+                move |method: Method, range: Option<String>, if_range: Option<String>| {
+                    let mut received_ranges = received_ranges_clone.lock().unwrap();
+                    let request_number = received_ranges.len();
+                    let etag = if behavior.changed_after_interruptions
+                        && request_number >= behavior.interrupted_requests
+                    {
+                        CHANGED_ARCHIVE_ETAG
+                    } else {
+                        ARCHIVE_ETAG
+                    };
+                    if method == Method::HEAD {
+                        return warp::http::Response::builder()
+                            .header("etag", etag)
+                            .body(warp::hyper::Body::from(archive.clone()))
+                            .unwrap();
+                    }
+                    received_ranges.push(range.clone());
+
+                    let is_if_range_matching = if_range.as_ref().is_none_or(|tag| tag == etag);
+                    let offset = match (&range, behavior.support_range && is_if_range_matching) {
+                        (Some(range), true) => range
+                            .trim_start_matches("bytes=")
+                            .trim_end_matches('-')
+                            .parse::<usize>()
+                            .unwrap(),
+                        _ => 0,
+                    };
+                    if offset > 0 && offset >= archive.len() {
+                        return warp::http::Response::builder()
+                            .status(416)
+                            .body(warp::hyper::Body::empty())
+                            .unwrap();
+                    }
+                    let status = if offset > 0 { 206 } else { 200 };
+                    let body = if request_number < behavior.interrupted_requests {
+                        let half = archive.len() / 2;
+                        let first_half = archive[offset.min(half)..half].to_vec();
+                        // Wait before failing so the first half is received by the client
+                        let stream = futures::stream::once(async { Ok(first_half) }).chain(
+                            futures::stream::once(async {
+                                tokio::time::sleep(Duration::from_millis(100)).await;
+                                Err(std::io::Error::other("connection lost"))
+                            }),
+                        );
+                        warp::hyper::Body::wrap_stream(stream)
+                    } else {
+                        warp::hyper::Body::from(archive[offset..].to_vec())
+                    };
+
+                    warp::http::Response::builder()
+                        .status(status)
+                        .header("etag", etag)
+                        .body(body)
+                        .unwrap()
+                },
+            );
+
+        (test_http_server(routes), received_ranges)
+    }
+
+    fn retry_policy(max_attempts: u32) -> DownloadRetryPolicy {
+        DownloadRetryPolicy {
+            max_attempts,
+            initial_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    fn resumed_events(events: &[MithrilEvent]) -> Vec<u64> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                MithrilEvent::SnapshotDownloadResumed {
+                    downloaded_bytes, ..
+                } => Some(*downloaded_bytes),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Persist the given bytes as if they were downloaded from the given location by a previous
+    /// run, returns the path of the partial download file.
+    fn write_partial_download_file(
+        partial_downloads_dir: &Path,
+        metadata: PartialDownloadMetadata,
+        bytes: &[u8],
+    ) -> PathBuf {
+        std::fs::create_dir_all(partial_downloads_dir).unwrap();
+        let partial_download_file = partial_downloads_dir.join(format!("{ARCHIVE_NAME}.part"));
+        std::fs::write(&partial_download_file, bytes).unwrap();
+        std::fs::write(
+            partial_download_metadata_path(&partial_download_file),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+
+        partial_download_file
+    }
+
+    fn archive_metadata(location: &str) -> PartialDownloadMetadata {
+        PartialDownloadMetadata {
+            location: location.to_string(),
+            validator: ARCHIVE_ETAG.to_string(),
+        }
+    }
+
+    fn archive_location(server: &TestHttpServer) -> String {
+        format!("{}/{ARCHIVE_NAME}", server.url())
+    }
+
+    async fn download_unpack(
+        downloader: &HttpSnapshotDownloader,
+        server: &TestHttpServer,
+        target_dir: &Path,
+        archive_size: usize,
+    ) -> MithrilResult<()> {
+        downloader
+            .download_unpack(
+                &archive_location(server),
+                target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive_size as u64,
+            )
+            .await
+    }
+
+    #[test]
+    fn retry_delay_doubles_until_max_delay() {
+        let policy = DownloadRetryPolicy {
+            max_attempts: 10,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+        };
+
+        assert_eq!(
+            vec![1, 2, 4, 5, 5],
+            (1..=5)
+                .map(|retry| policy.delay_before_retry(retry).as_secs())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn partial_download_path_is_computed_from_location_file_name() {
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(Path::new("/tmp/partial"));
+
+        assert_eq!(
+            Some(PathBuf::from("/tmp/partial/archive.tar.zst.part")),
+            downloader.partial_download_path("https://cdn.mithril.network/archive.tar.zst")
+        );
+        assert_eq!(
+            Some(PathBuf::from("/tmp/partial/snapshot.part")),
+            downloader.partial_download_path("https://cdn.mithril.network/")
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_resume_with_range_request_after_an_interruption() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 1,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_resume_with_range_request_after_an_interruption",
+        );
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = HttpSnapshotDownloader::new(
+            FeedbackSender::new(&[feedback_receiver.clone()]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_retry_policy(retry_policy(3));
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        let half = archive.len() / 2;
+        assert_eq!(
+            vec![None, Some(format!("bytes={half}-"))],
+            received_ranges.lock().unwrap().clone()
+        );
+        assert_eq!(
+            vec![half as u64],
+            resumed_events(&feedback_receiver.stacked_events())
+        );
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_skip_already_received_bytes_if_server_does_not_support_range() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 1,
+                support_range: false,
+                changed_after_interruptions: false,
+            },
+        );
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_skip_already_received_bytes_if_server_does_not_support_range",
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_retry_policy(retry_policy(3));
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        assert_eq!(2, received_ranges.lock().unwrap().len());
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_when_max_attempts_is_reached() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: usize::MAX,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_fails_when_max_attempts_is_reached",
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_retry_policy(retry_policy(3));
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .expect_err("Download should fail after 3 attempts");
+
+        assert_eq!(3, received_ranges.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_does_not_retry_if_location_is_not_found() {
+        let server = httpmock::MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path(format!("/{ARCHIVE_NAME}"));
+            then.status(404);
+        });
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_does_not_retry_if_location_is_not_found",
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_retry_policy(retry_policy(3));
+
+        downloader
+            .download_unpack(
+                &server.url(format!("/{ARCHIVE_NAME}")),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                0,
+            )
+            .await
+            .expect_err("Download should fail if location is not found");
+
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn download_unpack_resume_from_bytes_persisted_by_a_previous_run() {
+        let archive = build_archive();
+        let half = archive.len() / 2;
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_resume_from_bytes_persisted_by_a_previous_run",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&archive_location(&server)),
+            &archive[..half],
+        );
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = HttpSnapshotDownloader::new(
+            FeedbackSender::new(&[feedback_receiver.clone()]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![Some(format!("bytes={half}-"))],
+            received_ranges.lock().unwrap().clone()
+        );
+        assert_eq!(
+            vec![half as u64],
+            resumed_events(&feedback_receiver.stacked_events())
+        );
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+        assert!(
+            !partial_download_file.exists(),
+            "Partial download file should be removed once the download is completed"
+        );
+        assert!(!partial_download_metadata_path(&partial_download_file).exists());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_keeps_persisted_bytes_if_the_download_fails() {
+        let archive = build_archive();
+        let (server, _) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: usize::MAX,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_keeps_persisted_bytes_if_the_download_fails",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        std::fs::create_dir_all(&partial_downloads_dir).unwrap();
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_retry_policy(DownloadRetryPolicy::never())
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .expect_err("Download should fail");
+
+        let partial_download_file = partial_downloads_dir.join(format!("{ARCHIVE_NAME}.part"));
+        assert_eq!(
+            archive[..archive.len() / 2].to_vec(),
+            std::fs::read(&partial_download_file).unwrap()
+        );
+        let metadata: PartialDownloadMetadata = serde_json::from_slice(
+            &std::fs::read(partial_download_metadata_path(&partial_download_file)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(archive_metadata(&archive_location(&server)), metadata);
+    }
+
+    #[tokio::test]
+    async fn download_unpack_removes_persisted_bytes_if_they_are_corrupted() {
+        let archive = build_archive();
+        let (server, _) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_removes_persisted_bytes_if_they_are_corrupted",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&archive_location(&server)),
+            &vec![0; archive.len() / 2],
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .expect_err("Unpack should fail with corrupted persisted bytes");
+
+        assert!(
+            !partial_download_file.exists(),
+            "Corrupted partial download file should be removed"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_does_not_request_bytes_if_persisted_bytes_are_complete() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_does_not_request_bytes_if_persisted_bytes_are_complete",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&archive_location(&server)),
+            &archive,
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        assert!(received_ranges.lock().unwrap().is_empty());
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+        assert!(!partial_download_file.exists());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_restart_from_the_beginning_if_range_is_not_satisfiable() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_restart_from_the_beginning_if_range_is_not_satisfiable",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&archive_location(&server)),
+            &archive,
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        // The expected snapshot size is greater than the size of the archive served
+        download_unpack(&downloader, &server, &target_dir, archive.len() + 1)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![Some(format!("bytes={}-", archive.len())), None],
+            received_ranges.lock().unwrap().clone()
+        );
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+        assert!(!partial_download_file.exists());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_removes_persisted_bytes_if_the_download_can_not_succeed() {
+        let archive = build_archive();
+        let server = httpmock::MockServer::start();
+        server.mock(|when, then| {
+            when.path(format!("/{ARCHIVE_NAME}"));
+            then.status(404);
+        });
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_removes_persisted_bytes_if_the_download_can_not_succeed",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&server.url(format!("/{ARCHIVE_NAME}"))),
+            &archive[..archive.len() / 2],
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        downloader
+            .download_unpack(
+                &server.url(format!("/{ARCHIVE_NAME}")),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive.len() as u64,
+            )
+            .await
+            .expect_err("Download should fail if location is not found");
+
+        assert!(
+            !partial_download_file.exists(),
+            "Partial download file should be removed if the download can not succeed"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_does_not_resume_persisted_bytes_of_another_version_of_the_snapshot() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_does_not_resume_persisted_bytes_of_another_version_of_the_snapshot",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        let partial_download_file = write_partial_download_file(
+            &partial_downloads_dir,
+            PartialDownloadMetadata {
+                location: archive_location(&server),
+                validator: "\"stale-archive-etag\"".to_string(),
+            },
+            &vec![0; archive.len() / 2],
+        );
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = HttpSnapshotDownloader::new(
+            FeedbackSender::new(&[feedback_receiver.clone()]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        assert_eq!(vec![None], received_ranges.lock().unwrap().clone());
+        assert!(resumed_events(&feedback_receiver.stacked_events()).is_empty());
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+        assert!(!partial_download_file.exists());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_does_not_resume_persisted_bytes_downloaded_from_another_location() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 0,
+                support_range: true,
+                changed_after_interruptions: false,
+            },
+        );
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_does_not_resume_persisted_bytes_downloaded_from_another_location",
+        );
+        let (target_dir, partial_downloads_dir) = (test_dir.join("db"), test_dir.join("partial"));
+        std::fs::create_dir_all(&target_dir).unwrap();
+        write_partial_download_file(
+            &partial_downloads_dir,
+            archive_metadata(&format!("https://another-location/{ARCHIVE_NAME}")),
+            &vec![0; archive.len() / 2],
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_partial_downloads_dir(&partial_downloads_dir);
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .unwrap();
+
+        assert_eq!(vec![None], received_ranges.lock().unwrap().clone());
+        assert_eq!(
+            file_content(),
+            std::fs::read(target_dir.join(FILE_IN_ARCHIVE)).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_if_the_snapshot_changes_during_its_download() {
+        let archive = build_archive();
+        let (server, received_ranges) = spawn_snapshot_server(
+            archive.clone(),
+            ServerBehavior {
+                interrupted_requests: 1,
+                support_range: true,
+                changed_after_interruptions: true,
+            },
+        );
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_fails_if_the_snapshot_changes_during_its_download",
+        );
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_utils::test_logger())
+                .unwrap()
+                .with_retry_policy(retry_policy(3));
+
+        download_unpack(&downloader, &server, &target_dir, archive.len())
+            .await
+            .expect_err("Download should fail if the snapshot changed");
+
+        let half = archive.len() / 2;
+        assert_eq!(
+            vec![None, Some(format!("bytes={half}-"))],
+            received_ranges.lock().unwrap().clone()
+        );
+    }
+}
@@ -36,6 +36,10 @@ pub use mithril_common::messages::CertificateListItemMessageMetadata as MithrilC
 ///
 pub use mithril_common::messages::SignerWithStakeMessagePart as MithrilSigner;
 
+/// An event produced by a Mithril aggregator.
+///
+pub use mithril_common::messages::AggregatorEventMessage as AggregatorEvent;
+
 cfg_unstable! {
     /// A Cardano stake distribution.
     pub use mithril_common::messages::CardanoStakeDistributionMessage as CardanoStakeDistribution;
@@ -62,7 +66,7 @@ cfg_unstable! {
 pub mod common {
     pub use mithril_common::entities::{
         CardanoDbBeacon, CompressionAlgorithm, Epoch, ImmutableFileNumber, ProtocolMessage,
-        ProtocolMessagePartKey, ProtocolParameters,
+        ProtocolMessagePartKey, ProtocolParameters, SignedEntityTypeDiscriminants,
     };
     cfg_unstable! {
         pub use mithril_common::entities::{ChainPoint, TransactionHash, SlotNumber, BlockHash, BlockNumber};
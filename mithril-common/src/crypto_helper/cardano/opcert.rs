@@ -1,7 +1,7 @@
 //! Module to (de)serialise, OpCert using the same structure as used in Cardano.  
 
-use super::SerDeShelleyFileFormat;
-use crate::crypto_helper::cardano::ProtocolRegistrationErrorWrapper;
+use super::{SerDeShelleyFileFormat, Sum6KesBytes};
+use crate::crypto_helper::cardano::{KESPeriod, ProtocolRegistrationErrorWrapper};
 use crate::crypto_helper::{encode_bech32, ProtocolPartyId};
 
 use blake2::{digest::consts::U28, Blake2b, Digest};
@@ -9,6 +9,8 @@ use ed25519_dalek::{
     Signature as EdSignature, Signer, SigningKey as EdSecretKey, Verifier,
     VerifyingKey as EdVerificationKey,
 };
+use kes_summed_ed25519::kes::Sum6Kes;
+use kes_summed_ed25519::traits::KesSk;
 use kes_summed_ed25519::PublicKey as KesPublicKey;
 use nom::AsBytes;
 use serde::de::Error;
@@ -22,6 +24,16 @@ pub enum OpCertError {
     /// Error raised when a pool address encoding fails
     #[error("pool address encoding error")]
     PoolAddressEncoding,
+
+    /// Error raised when a KES secret key can not be decoded
+    #[error("invalid KES secret key")]
+    KesSecretKeyInvalid,
+
+    /// Error raised when a KES secret key is not the one certified by the operational certificate
+    #[error(
+        "KES secret key does not match the KES verification key of the operational certificate"
+    )]
+    KesSecretKeyMismatch,
 }
 
 /// Raw Fields of the operational certificates (without including the cold VK)
@@ -126,6 +138,21 @@ impl OpCert {
         hex::encode(hasher.finalize())
     }
 
+    /// Check that the given KES secret key is the one certified by this operational certificate,
+    /// returns the KES period the secret key has been evolved to.
+    pub fn check_kes_secret_key(
+        &self,
+        mut kes_secret_key: Sum6KesBytes,
+    ) -> Result<KESPeriod, OpCertError> {
+        let kes_secret_key =
+            Sum6Kes::try_from(&mut kes_secret_key).map_err(|_| OpCertError::KesSecretKeyInvalid)?;
+        if kes_secret_key.to_pk() != self.kes_vk {
+            return Err(OpCertError::KesSecretKeyMismatch);
+        }
+
+        Ok(kes_secret_key.get_period())
+    }
+
     /// Compute the hash of an OpCert
     pub fn compute_hash(&self) -> String {
         let mut hasher = Sha256::new();
@@ -181,7 +208,6 @@ mod tests {
     use crate::crypto_helper::cardano::ColdKeyGenerator;
     use crate::test_utils::TempDir;
 
-    use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
     use std::path::PathBuf;
 
     fn setup_temp_directory(test_name: &str) -> PathBuf {
@@ -221,4 +247,42 @@ mod tests {
             party_id_as_hash
         );
     }
+
+    fn generate_kes_keys(seed: u8) -> (Sum6KesBytes, KesPublicKey) {
+        let mut key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [seed; 32];
+        let (kes_secret_key, kes_verification_key) =
+            Sum6Kes::keygen(&mut key_buffer, &mut dummy_seed);
+        let mut kes_bytes = Sum6KesBytes([0u8; Sum6Kes::SIZE + 4]);
+        kes_bytes.0.copy_from_slice(&kes_secret_key.clone_sk());
+
+        (kes_bytes, kes_verification_key)
+    }
+
+    #[test]
+    fn check_kes_secret_key_certified_by_the_operational_certificate() {
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let (kes_secret_key, kes_verification_key) = generate_kes_keys(0);
+        let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+
+        let kes_period = operational_certificate
+            .check_kes_secret_key(kes_secret_key)
+            .expect("KES secret key check should not fail");
+
+        assert_eq!(0, kes_period);
+    }
+
+    #[test]
+    fn check_kes_secret_key_not_certified_by_the_operational_certificate() {
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let (_, kes_verification_key) = generate_kes_keys(0);
+        let (other_kes_secret_key, _) = generate_kes_keys(1);
+        let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+
+        let error = operational_certificate
+            .check_kes_secret_key(other_kes_secret_key)
+            .expect_err("KES secret key check should fail");
+
+        assert_eq!(OpCertError::KesSecretKeyMismatch, error);
+    }
 }
@@ -88,6 +88,9 @@ pub type StdResult<T> = anyhow::Result<T, StdError>;
 /// Mithril API protocol version header name
 pub const MITHRIL_API_VERSION_HEADER: &str = "mithril-api-version";
 
+/// Mithril client type header name, used by the aggregator to compute its statistics
+pub const MITHRIL_CLIENT_TYPE_HEADER: &str = "mithril-client-type";
+
 /// Mithril Signer node version header name
 pub const MITHRIL_SIGNER_VERSION_HEADER: &str = "signer-node-version";
 
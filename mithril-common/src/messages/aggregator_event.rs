@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, SignedEntityType};
+
+/// Message streamed by an Aggregator to inform about the data it has produced
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AggregatorEventMessage {
+    /// A new certificate has been created
+    NewCertificate {
+        /// Hash of the certificate
+        certificate_hash: String,
+
+        /// Epoch of the certificate
+        epoch: Epoch,
+
+        /// Signed entity type certified by the certificate
+        signed_entity_type: SignedEntityType,
+    },
+
+    /// A new artifact has been created
+    NewArtifact {
+        /// Signed entity type of the artifact
+        signed_entity_type: SignedEntityType,
+
+        /// Hash of the certificate that certifies the artifact
+        certificate_hash: String,
+    },
+
+    /// The Aggregator has entered a new epoch
+    EpochChanged {
+        /// The new epoch
+        epoch: Epoch,
+    },
+}
+
+impl AggregatorEventMessage {
+    /// Name of the event, used as the event type when the message is streamed
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::NewCertificate { .. } => "new_certificate",
+            Self::NewArtifact { .. } => "new_artifact",
+            Self::EpochChanged { .. } => "epoch_changed",
+        }
+    }
+
+    /// Create a dummy AggregatorEventMessage
+    pub fn dummy() -> Self {
+        Self::NewCertificate {
+            certificate_hash: "certificate-hash-123".to_string(),
+            epoch: Epoch(10),
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(10)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_messages() -> Vec<(AggregatorEventMessage, &'static str)> {
+        vec![
+            (
+                AggregatorEventMessage::NewCertificate {
+                    certificate_hash: "certificate-hash-123".to_string(),
+                    epoch: Epoch(10),
+                    signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(10)),
+                },
+                r#"{"type":"new_certificate","certificate_hash":"certificate-hash-123","epoch":10,"signed_entity_type":{"MithrilStakeDistribution":10}}"#,
+            ),
+            (
+                AggregatorEventMessage::NewArtifact {
+                    signed_entity_type: SignedEntityType::CardanoStakeDistribution(Epoch(9)),
+                    certificate_hash: "certificate-hash-456".to_string(),
+                },
+                r#"{"type":"new_artifact","signed_entity_type":{"CardanoStakeDistribution":9},"certificate_hash":"certificate-hash-456"}"#,
+            ),
+            (
+                AggregatorEventMessage::EpochChanged { epoch: Epoch(11) },
+                r#"{"type":"epoch_changed","epoch":11}"#,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_v1() {
+        for (message, json) in golden_messages() {
+            assert_eq!(json, serde_json::to_string(&message).unwrap());
+            assert_eq!(
+                message,
+                serde_json::from_str::<AggregatorEventMessage>(json).unwrap()
+            );
+        }
+    }
+}
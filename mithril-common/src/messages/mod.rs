@@ -1,5 +1,6 @@
 //! Messages module
 //! This module aims at providing shared structures for API communications.
+mod aggregator_event;
 mod aggregator_features;
 mod cardano_stake_distribution;
 mod cardano_stake_distribution_list;
@@ -16,10 +17,13 @@ mod mithril_stake_distribution;
 mod mithril_stake_distribution_list;
 mod register_signature;
 mod register_signer;
+mod signer_participation;
+mod signing_opportunity_list;
 mod snapshot;
 mod snapshot_download;
 mod snapshot_list;
 
+pub use aggregator_event::AggregatorEventMessage;
 pub use aggregator_features::{
     AggregatorCapabilities, AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
 };
@@ -49,6 +53,10 @@ pub use mithril_stake_distribution_list::{
 };
 pub use register_signature::RegisterSignatureMessage;
 pub use register_signer::RegisterSignerMessage;
+pub use signer_participation::{SignerParticipationEpochMessage, SignerParticipationMessage};
+pub use signing_opportunity_list::{
+    SigningOpportunityListItemMessage, SigningOpportunityListMessage,
+};
 pub use snapshot::SnapshotMessage;
 pub use snapshot_download::SnapshotDownloadMessage;
 pub use snapshot_list::{SnapshotListItemMessage, SnapshotListMessage};
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, PartyId};
+
+/// Message structure of the participation of a signer in the certificates production
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerParticipationMessage {
+    /// The unique identifier of the signer
+    pub party_id: PartyId,
+
+    /// Participation of the signer for each epoch it sent signatures, most recent first
+    pub epochs: Vec<SignerParticipationEpochMessage>,
+}
+
+/// Participation of a signer during one epoch
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerParticipationEpochMessage {
+    /// Epoch of the messages signed by the signer
+    pub epoch: Epoch,
+
+    /// Number of single signatures sent by the signer
+    pub signatures_sent: u64,
+
+    /// Number of single signatures of the signer included in a certificate
+    pub signatures_included: u64,
+
+    /// Number of lottery wins of the signer
+    pub lottery_wins: u64,
+}
+
+impl SignerParticipationMessage {
+    /// Return a dummy test entity (test-only).
+    pub fn dummy() -> Self {
+        Self {
+            party_id: "pool1m8crhnqj5k2kyszf5j2scshupystyxc887zdfrpzh6ty6eun4fx".to_string(),
+            epochs: vec![SignerParticipationEpochMessage {
+                epoch: Epoch(10),
+                signatures_sent: 4,
+                signatures_included: 3,
+                lottery_wins: 52,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "party_id": "pool1m8crhnqj5k2kyszf5j2scshupystyxc887zdfrpzh6ty6eun4fx",
+            "epochs": [
+                {
+                    "epoch": 10,
+                    "signatures_sent": 4,
+                    "signatures_included": 3,
+                    "lottery_wins": 52
+                }
+            ]
+        }"#;
+        let message: SignerParticipationMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a SignerParticipationMessage instance.",
+        );
+
+        assert_eq!(SignerParticipationMessage::dummy(), message);
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, ProtocolMessage, ProtocolMessagePartKey, SignedEntityType};
+
+/// Message structure of a signing opportunity list
+pub type SigningOpportunityListMessage = Vec<SigningOpportunityListItemMessage>;
+
+/// SigningOpportunityListItemMessage represents a message open for signatures on the aggregator
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningOpportunityListItemMessage {
+    /// Epoch at which the message was opened
+    pub epoch: Epoch,
+
+    /// Signed entity type of the message
+    pub signed_entity_type: SignedEntityType,
+
+    /// Protocol message that the signers are expected to sign
+    pub protocol_message: ProtocolMessage,
+
+    /// Date and time after which the message does not accept signatures anymore, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl SigningOpportunityListItemMessage {
+    /// Return a dummy test entity (test-only).
+    pub fn dummy() -> Self {
+        let epoch = Epoch(10);
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::NextAggregateVerificationKey,
+            "next-avk-123".to_string(),
+        );
+
+        Self {
+            epoch,
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(epoch),
+            protocol_message,
+            expires_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> SigningOpportunityListMessage {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::NextAggregateVerificationKey,
+            "next-avk-123".to_string(),
+        );
+
+        vec![SigningOpportunityListItemMessage {
+            epoch: Epoch(10),
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(10)),
+            protocol_message,
+            expires_at: Some(
+                DateTime::parse_from_rfc3339("2024-02-12T13:11:47Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            ),
+        }]
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"[{
+            "epoch": 10,
+            "signed_entity_type": { "MithrilStakeDistribution": 10 },
+            "protocol_message": {
+                "message_parts": {
+                    "next_aggregate_verification_key": "next-avk-123"
+                }
+            },
+            "expires_at": "2024-02-12T13:11:47Z"
+        }]"#;
+        let message: SigningOpportunityListMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a SigningOpportunityListMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+
+    #[test]
+    fn test_v1_without_expiry() {
+        let json = r#"[{
+            "epoch": 10,
+            "signed_entity_type": { "MithrilStakeDistribution": 10 },
+            "protocol_message": {
+                "message_parts": {
+                    "next_aggregate_verification_key": "next-avk-123"
+                }
+            }
+        }]"#;
+        let message: SigningOpportunityListMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a SigningOpportunityListMessage instance.",
+        );
+
+        assert_eq!(vec![SigningOpportunityListItemMessage::dummy()], message);
+    }
+}
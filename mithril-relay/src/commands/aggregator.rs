@@ -3,8 +3,10 @@ use config::{builder::DefaultState, ConfigBuilder};
 use libp2p::Multiaddr;
 use mithril_common::StdResult;
 use slog_scope::error;
+use std::path::PathBuf;
 
-use crate::AggregatorRelay;
+use super::{MetricsServerArgs, PeerArgs};
+use crate::{AggregatorRelay, NotificationQueueConfig};
 
 #[derive(Parser, Debug, Clone)]
 pub struct AggregatorCommand {
@@ -19,6 +21,25 @@ pub struct AggregatorCommand {
     /// Aggregator endpoint URL.
     #[clap(long, env = "AGGREGATOR_ENDPOINT")]
     aggregator_endpoint: String,
+
+    /// File where the messages waiting for delivery to the aggregator are persisted.
+    /// If not set, the messages are kept in memory only.
+    #[clap(long, env = "NOTIFICATION_QUEUE_FILE")]
+    notification_queue_file: Option<PathBuf>,
+
+    /// Maximum number of messages waiting for delivery to the aggregator
+    #[clap(long, env = "NOTIFICATION_QUEUE_MAX_SIZE", default_value_t = 10_000)]
+    notification_queue_max_size: usize,
+
+    /// Number of epochs after which a message waiting for delivery to the aggregator is discarded
+    #[clap(long, env = "NOTIFICATION_QUEUE_MAX_EPOCH_AGE", default_value_t = 1)]
+    notification_queue_max_epoch_age: u64,
+
+    #[clap(flatten)]
+    peer_args: PeerArgs,
+
+    #[clap(flatten)]
+    metrics_server_args: MetricsServerArgs,
 }
 
 impl AggregatorCommand {
@@ -26,9 +47,24 @@ impl AggregatorCommand {
     pub async fn execute(&self, _config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
         let dial_to = self.dial_to.to_owned();
         let addr: Multiaddr = format!("/ip4/0.0.0.0/tcp/{}", self.listen_port).parse()?;
+        let peer_config = self.peer_args.build_peer_config()?;
+        let metrics_service = self.metrics_server_args.start_metrics_service()?;
         let aggregator_endpoint = self.aggregator_endpoint.to_owned();
+        let notification_queue_config = NotificationQueueConfig {
+            max_size: self.notification_queue_max_size,
+            max_epoch_age: self.notification_queue_max_epoch_age,
+            storage_file: self.notification_queue_file.to_owned(),
+            ..NotificationQueueConfig::default()
+        };
 
-        let mut relay = AggregatorRelay::start(&addr, &aggregator_endpoint).await?;
+        let mut relay = AggregatorRelay::start(
+            &addr,
+            &aggregator_endpoint,
+            peer_config,
+            notification_queue_config,
+            metrics_service,
+        )
+        .await?;
         if let Some(dial_to_address) = dial_to {
             relay.dial_peer(dial_to_address.clone())?;
         }
@@ -10,10 +10,16 @@ pub use signer::SignerCommand;
 
 use clap::Parser;
 use config::{builder::DefaultState, ConfigBuilder, Map, Source, Value};
+use libp2p::Multiaddr;
 use mithril_common::StdResult;
 use slog::Level;
-use slog_scope::debug;
-use std::path::PathBuf;
+use slog_scope::{debug, error};
+use std::{path::PathBuf, sync::Arc};
+
+use crate::{
+    p2p::{load_or_generate_keypair, PeerConfig},
+    MetricsServer, MetricsService,
+};
 
 /// Relay for Mithril Node
 #[derive(Parser, Debug, Clone)]
@@ -75,3 +81,74 @@ impl Source for Args {
         Ok(Map::new())
     }
 }
+
+/// P2P peer arguments shared by all the relays
+#[derive(Parser, Debug, Clone)]
+pub struct PeerArgs {
+    /// Path of the file holding the peer identity keypair, generated if it does not exist.
+    /// If not set, a new identity is generated at each start.
+    #[clap(long, env = "IDENTITY_FILE")]
+    identity_file: Option<PathBuf>,
+
+    /// Comma separated multi-addresses of the peers used to bootstrap the discovery of the P2P network
+    /// (e.g. /ip4/1.2.3.4/tcp/1234/p2p/12D3KooW...)
+    #[clap(long, env = "BOOTSTRAP_PEERS", value_delimiter = ',')]
+    bootstrap_peers: Vec<Multiaddr>,
+
+    /// Discover the peers of the local network with mDNS
+    #[clap(long, env = "ENABLE_MDNS", default_value_t = false)]
+    enable_mdns: bool,
+}
+
+impl PeerArgs {
+    /// Build the configuration of the peer of the relay
+    pub fn build_peer_config(&self) -> StdResult<PeerConfig> {
+        Ok(PeerConfig {
+            keypair: self
+                .identity_file
+                .as_deref()
+                .map(load_or_generate_keypair)
+                .transpose()?,
+            bootstrap_peers: self.bootstrap_peers.clone(),
+            enable_mdns: self.enable_mdns,
+            ..PeerConfig::default()
+        })
+    }
+}
+
+/// Metrics server arguments shared by all the relays
+#[derive(Parser, Debug, Clone)]
+pub struct MetricsServerArgs {
+    /// Enable metrics HTTP server (Prometheus endpoint on /metrics and relay status on /status).
+    #[clap(long, env = "ENABLE_METRICS_SERVER", default_value_t = false)]
+    enable_metrics_server: bool,
+
+    /// Metrics HTTP server IP.
+    #[clap(long, env = "METRICS_SERVER_IP", default_value = "0.0.0.0")]
+    metrics_server_ip: String,
+
+    /// Metrics HTTP server listening port.
+    #[clap(long, env = "METRICS_SERVER_PORT", default_value_t = 9092)]
+    metrics_server_port: u16,
+}
+
+impl MetricsServerArgs {
+    /// Build the metrics service of the relay and serve it if the metrics server is enabled
+    pub fn start_metrics_service(&self) -> StdResult<Arc<MetricsService>> {
+        let metrics_service = Arc::new(MetricsService::new()?);
+        if self.enable_metrics_server {
+            let metrics_server = MetricsServer::new(
+                &self.metrics_server_ip,
+                self.metrics_server_port,
+                metrics_service.clone(),
+            );
+            tokio::spawn(async move {
+                if let Err(err) = metrics_server.start().await {
+                    error!("MetricsServer: server error"; "error" => format!("{err:#?}"));
+                }
+            });
+        }
+
+        Ok(metrics_service)
+    }
+}
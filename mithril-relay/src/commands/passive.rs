@@ -4,6 +4,7 @@ use libp2p::Multiaddr;
 use mithril_common::StdResult;
 use slog_scope::error;
 
+use super::{MetricsServerArgs, PeerArgs};
 use crate::PassiveRelay;
 
 #[derive(Parser, Debug, Clone)]
@@ -15,6 +16,12 @@ pub struct PassiveCommand {
     /// Dial to peer multi-address (e.g. /ip4/0.0.0.0/tcp/1234)
     #[clap(long, env = "DIAL_TO")]
     dial_to: Option<Multiaddr>,
+
+    #[clap(flatten)]
+    peer_args: PeerArgs,
+
+    #[clap(flatten)]
+    metrics_server_args: MetricsServerArgs,
 }
 
 impl PassiveCommand {
@@ -22,8 +29,10 @@ impl PassiveCommand {
     pub async fn execute(&self, _config_builder: ConfigBuilder<DefaultState>) -> StdResult<()> {
         let dial_to = self.dial_to.to_owned();
         let addr: Multiaddr = format!("/ip4/0.0.0.0/tcp/{}", self.listen_port).parse()?;
+        let peer_config = self.peer_args.build_peer_config()?;
+        let metrics_service = self.metrics_server_args.start_metrics_service()?;
 
-        let mut relay = PassiveRelay::start(&addr).await?;
+        let mut relay = PassiveRelay::start(&addr, peer_config, metrics_service).await?;
         if let Some(dial_to_address) = dial_to {
             relay.dial_peer(dial_to_address.clone())?;
         }
@@ -6,6 +6,7 @@ use libp2p::Multiaddr;
 use mithril_common::StdResult;
 use slog_scope::error;
 
+use super::{MetricsServerArgs, PeerArgs};
 use crate::SignerRelay;
 
 #[derive(Parser, Debug, Clone)]
@@ -29,6 +30,12 @@ pub struct SignerCommand {
     /// Interval at which a signer registration should be repeated in milliseconds (defaults to 1 hour)
     #[clap(long, env = "SIGNER_REPEATER_DELAY", default_value_t = 3_600 * 1_000)]
     signer_repeater_delay: u64,
+
+    #[clap(flatten)]
+    peer_args: PeerArgs,
+
+    #[clap(flatten)]
+    metrics_server_args: MetricsServerArgs,
 }
 
 impl SignerCommand {
@@ -37,6 +44,8 @@ impl SignerCommand {
         let server_port = self.server_port.to_owned();
         let dial_to = self.dial_to.to_owned();
         let addr: Multiaddr = format!("/ip4/0.0.0.0/tcp/{}", self.listen_port).parse()?;
+        let peer_config = self.peer_args.build_peer_config()?;
+        let metrics_service = self.metrics_server_args.start_metrics_service()?;
         let aggregator_endpoint = self.aggregator_endpoint.to_owned();
         let signer_repeater_delay = Duration::from_millis(self.signer_repeater_delay);
 
@@ -45,6 +54,8 @@ impl SignerCommand {
             &server_port,
             &aggregator_endpoint,
             &signer_repeater_delay,
+            peer_config,
+            metrics_service,
         )
         .await?;
         if let Some(dial_to_address) = dial_to {
@@ -2,6 +2,7 @@
 #![doc = include_str!("../README.md")]
 
 mod commands;
+mod metrics;
 /// Peer to peer module
 pub mod p2p;
 mod relay;
@@ -9,7 +10,9 @@ mod repeater;
 
 pub use commands::Args;
 pub use commands::RelayCommands;
+pub use metrics::{MetricsServer, MetricsService, RelayStatus};
 pub use relay::AggregatorRelay;
+pub use relay::NotificationQueueConfig;
 pub use relay::PassiveRelay;
 pub use relay::SignerRelay;
 
@@ -0,0 +1,45 @@
+//! metrics module.
+//! This module contains the relay metrics service and metrics server.
+
+mod server;
+mod service;
+
+pub use server::MetricsServer;
+pub use service::{MetricsService, RelayStatus};
+
+/// 'connected_peers' metric name
+pub const CONNECTED_PEERS_METRIC_NAME: &str = "mithril_relay_connected_peers";
+/// 'connected_peers' metric help
+pub const CONNECTED_PEERS_METRIC_HELP: &str =
+    "Number of peers connected to a Mithril relay in the P2P network";
+
+/// 'messages_received_since_startup' metric name
+pub const MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_relay_messages_received_since_startup";
+/// 'messages_received_since_startup' metric help
+pub const MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of messages received from the P2P network per topic since startup on a Mithril relay";
+
+/// 'messages_published_since_startup' metric name
+pub const MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_relay_messages_published_since_startup";
+/// 'messages_published_since_startup' metric help
+pub const MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of messages published to the P2P network per topic since startup on a Mithril relay";
+
+/// 'aggregator_notification_failures_since_startup' metric name
+pub const AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_relay_aggregator_notification_failures_since_startup";
+/// 'aggregator_notification_failures_since_startup' metric help
+pub const AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of failed notifications to the aggregator per topic since startup on a Mithril relay";
+
+/// 'repeated_messages_since_startup' metric name
+pub const REPEATED_MESSAGES_SINCE_STARTUP_METRIC_NAME: &str =
+    "mithril_relay_repeated_messages_since_startup";
+/// 'repeated_messages_since_startup' metric help
+pub const REPEATED_MESSAGES_SINCE_STARTUP_METRIC_HELP: &str =
+    "Number of signer registrations repeated by the message repeater since startup on a Mithril relay";
+
+/// Label of the metrics that are recorded per P2P topic
+pub const TOPIC_METRIC_LABEL: &str = "topic";
@@ -0,0 +1,123 @@
+use mithril_common::StdResult;
+use slog_scope::{error, info};
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+use warp::{http::StatusCode, Filter};
+
+use crate::MetricsService;
+
+/// The MetricsServer is responsible for exposing the metrics and the status of the relay.
+pub struct MetricsServer {
+    server_port: u16,
+    server_ip: String,
+    metrics_service: Arc<MetricsService>,
+}
+
+impl MetricsServer {
+    /// Create a new MetricsServer instance.
+    pub fn new(server_ip: &str, server_port: u16, metrics_service: Arc<MetricsService>) -> Self {
+        Self {
+            server_port,
+            server_ip: server_ip.to_string(),
+            metrics_service,
+        }
+    }
+
+    /// Metrics server endpoint.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}:{}", self.server_ip, self.server_port)
+    }
+
+    /// Serve the metrics and the status on a HTTP server.
+    pub async fn start(&self) -> StdResult<()> {
+        info!(
+            "MetricsServer: starting HTTP server for metrics on port {}",
+            self.server_port
+        );
+        let address: SocketAddr = format!("{}:{}", self.server_ip, self.server_port).parse()?;
+        let (_, server) =
+            warp::serve(routes(self.metrics_service.clone())).try_bind_ephemeral(address)?;
+        server.await;
+
+        Ok(())
+    }
+}
+
+fn routes(
+    metrics_service: Arc<MetricsService>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let with_metrics_service = warp::any().map(move || metrics_service.clone());
+
+    warp::path("metrics")
+        .and(warp::get())
+        .and(with_metrics_service.clone())
+        .and_then(handlers::metrics)
+        .or(warp::path("status")
+            .and(warp::get())
+            .and(with_metrics_service)
+            .and_then(handlers::status))
+}
+
+mod handlers {
+    use super::*;
+
+    pub async fn metrics(
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<Box<dyn warp::Reply>, Infallible> {
+        match metrics_service.export_metrics() {
+            Ok(metrics) => Ok(Box::new(metrics)),
+            Err(err) => {
+                error!("MetricsServer: can not export metrics"; "error" => ?err);
+
+                Ok(Box::new(warp::reply::with_status(
+                    format!("Error: {err:?}"),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                )))
+            }
+        }
+    }
+
+    pub async fn status(
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        Ok(warp::reply::json(&metrics_service.status()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{metrics::RelayStatus, mithril_p2p_topic};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metrics_route() {
+        let metrics_service = Arc::new(MetricsService::new().unwrap());
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/metrics")
+            .reply(&routes(metrics_service))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_ne!("", response.body());
+    }
+
+    #[tokio::test]
+    async fn test_status_route() {
+        let metrics_service = Arc::new(MetricsService::new().unwrap());
+        metrics_service.connected_peers_gauge_set(2);
+        metrics_service
+            .messages_received_since_startup_counter_increment(mithril_p2p_topic::SIGNERS);
+
+        let response = warp::test::request()
+            .method("GET")
+            .path("/status")
+            .reply(&routes(metrics_service.clone()))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        let status: RelayStatus = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(metrics_service.status(), status);
+    }
+}
@@ -0,0 +1,307 @@
+use libp2p::PeerId;
+use mithril_common::StdResult;
+use prometheus::{Counter, CounterVec, Encoder, Gauge, Opts, Registry, TextEncoder};
+use serde::{Deserialize, Serialize};
+use slog_scope::debug;
+use std::{collections::BTreeMap, sync::RwLock};
+
+use super::{
+    AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_HELP,
+    AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_NAME, CONNECTED_PEERS_METRIC_HELP,
+    CONNECTED_PEERS_METRIC_NAME, MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_HELP,
+    MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_NAME, MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_HELP,
+    MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_NAME, REPEATED_MESSAGES_SINCE_STARTUP_METRIC_HELP,
+    REPEATED_MESSAGES_SINCE_STARTUP_METRIC_NAME, TOPIC_METRIC_LABEL,
+};
+use crate::mithril_p2p_topic;
+
+/// Type alias for a metric name.
+pub type MetricName = str;
+
+/// Type alias for a counter value.
+type CounterValue = u64;
+
+/// Status of a relay, served as JSON on the `/status` route of the metrics server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayStatus {
+    /// Peer id of the relay in the P2P network
+    pub peer_id: Option<String>,
+
+    /// Number of peers connected to the relay
+    pub connected_peers: CounterValue,
+
+    /// Number of messages received from the P2P network per topic
+    pub messages_received: BTreeMap<String, CounterValue>,
+
+    /// Number of messages published to the P2P network per topic
+    pub messages_published: BTreeMap<String, CounterValue>,
+
+    /// Number of failed notifications to the aggregator per topic
+    pub aggregator_notification_failures: BTreeMap<String, CounterValue>,
+
+    /// Number of signer registrations repeated by the message repeater
+    pub repeated_messages: CounterValue,
+}
+
+/// Metrics service which is responsible for recording and exposing metrics.
+pub struct MetricsService {
+    registry: Registry,
+    peer_id: RwLock<Option<PeerId>>,
+    connected_peers_gauge: Box<Gauge>,
+    messages_received_since_startup_counter: Box<CounterVec>,
+    messages_published_since_startup_counter: Box<CounterVec>,
+    aggregator_notification_failures_since_startup_counter: Box<CounterVec>,
+    repeated_messages_since_startup_counter: Box<Counter>,
+}
+
+impl MetricsService {
+    /// Create a new `MetricsService` instance.
+    pub fn new() -> StdResult<Self> {
+        let registry = Registry::new();
+
+        // P2P network metrics
+        let connected_peers_gauge = Box::new(Self::create_metric_gauge(
+            CONNECTED_PEERS_METRIC_NAME,
+            CONNECTED_PEERS_METRIC_HELP,
+        )?);
+        registry.register(connected_peers_gauge.clone())?;
+
+        let messages_received_since_startup_counter = Box::new(Self::create_metric_counter_vec(
+            MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_NAME,
+            MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_HELP,
+        )?);
+        registry.register(messages_received_since_startup_counter.clone())?;
+
+        let messages_published_since_startup_counter = Box::new(Self::create_metric_counter_vec(
+            MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_NAME,
+            MESSAGES_PUBLISHED_SINCE_STARTUP_METRIC_HELP,
+        )?);
+        registry.register(messages_published_since_startup_counter.clone())?;
+
+        // Aggregator metrics
+        let aggregator_notification_failures_since_startup_counter =
+            Box::new(Self::create_metric_counter_vec(
+                AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_NAME,
+                AGGREGATOR_NOTIFICATION_FAILURES_SINCE_STARTUP_METRIC_HELP,
+            )?);
+        registry.register(aggregator_notification_failures_since_startup_counter.clone())?;
+
+        // Repeater metrics
+        let repeated_messages_since_startup_counter = Box::new(Self::create_metric_counter(
+            REPEATED_MESSAGES_SINCE_STARTUP_METRIC_NAME,
+            REPEATED_MESSAGES_SINCE_STARTUP_METRIC_HELP,
+        )?);
+        registry.register(repeated_messages_since_startup_counter.clone())?;
+
+        Ok(Self {
+            registry,
+            peer_id: RwLock::new(None),
+            connected_peers_gauge,
+            messages_received_since_startup_counter,
+            messages_published_since_startup_counter,
+            aggregator_notification_failures_since_startup_counter,
+            repeated_messages_since_startup_counter,
+        })
+    }
+
+    fn create_metric_counter(name: &MetricName, help: &str) -> StdResult<Counter> {
+        let counter_opts = Opts::new(name, help);
+        let counter = Counter::with_opts(counter_opts)?;
+
+        Ok(counter)
+    }
+
+    fn create_metric_counter_vec(name: &MetricName, help: &str) -> StdResult<CounterVec> {
+        let counter_opts = Opts::new(name, help);
+        let counter = CounterVec::new(counter_opts, &[TOPIC_METRIC_LABEL])?;
+
+        Ok(counter)
+    }
+
+    fn create_metric_gauge(name: &MetricName, help: &str) -> StdResult<Gauge> {
+        let gauge_opts = Opts::new(name, help);
+        let gauge = Gauge::with_opts(gauge_opts)?;
+
+        Ok(gauge)
+    }
+
+    /// Export the metrics as a string with the Open Metrics standard format.
+    /// These metrics can be exposed on a HTTP server.
+    pub fn export_metrics(&self) -> StdResult<String> {
+        let mut buffer = vec![];
+        let encoder = TextEncoder::new();
+        let metric_families = self.registry.gather();
+        encoder.encode(&metric_families, &mut buffer).unwrap();
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Compute the status of the relay from the recorded metrics.
+    pub fn status(&self) -> RelayStatus {
+        let per_topic = |counter: &CounterVec| {
+            [mithril_p2p_topic::SIGNERS, mithril_p2p_topic::SIGNATURES]
+                .into_iter()
+                .map(|topic| {
+                    (
+                        topic.to_string(),
+                        counter.with_label_values(&[topic]).get().round() as CounterValue,
+                    )
+                })
+                .collect()
+        };
+
+        RelayStatus {
+            peer_id: self
+                .peer_id
+                .read()
+                .unwrap()
+                .map(|peer_id| peer_id.to_string()),
+            connected_peers: self.connected_peers_gauge_get(),
+            messages_received: per_topic(&self.messages_received_since_startup_counter),
+            messages_published: per_topic(&self.messages_published_since_startup_counter),
+            aggregator_notification_failures: per_topic(
+                &self.aggregator_notification_failures_since_startup_counter,
+            ),
+            repeated_messages: self.repeated_messages_since_startup_counter_get(),
+        }
+    }
+
+    /// Set the peer id of the relay.
+    pub fn set_peer_id(&self, peer_id: Option<PeerId>) {
+        *self.peer_id.write().unwrap() = peer_id;
+    }
+
+    /// Set the `connected_peers` gauge value.
+    pub fn connected_peers_gauge_set(&self, value: usize) {
+        debug!("MetricsService: set 'connected_peers' gauge value to {value}");
+        self.connected_peers_gauge.set(value as f64);
+    }
+
+    /// Get the `connected_peers` gauge value.
+    pub fn connected_peers_gauge_get(&self) -> CounterValue {
+        self.connected_peers_gauge.get().round() as CounterValue
+    }
+
+    /// Increment the `messages_received_since_startup` counter of a topic.
+    pub fn messages_received_since_startup_counter_increment(&self, topic: &str) {
+        debug!("MetricsService: incrementing 'messages_received_since_startup' counter"; "topic" => topic);
+        self.messages_received_since_startup_counter
+            .with_label_values(&[topic])
+            .inc();
+    }
+
+    /// Get the `messages_received_since_startup` counter of a topic.
+    pub fn messages_received_since_startup_counter_get(&self, topic: &str) -> CounterValue {
+        self.messages_received_since_startup_counter
+            .with_label_values(&[topic])
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Increment the `messages_published_since_startup` counter of a topic.
+    pub fn messages_published_since_startup_counter_increment(&self, topic: &str) {
+        debug!("MetricsService: incrementing 'messages_published_since_startup' counter"; "topic" => topic);
+        self.messages_published_since_startup_counter
+            .with_label_values(&[topic])
+            .inc();
+    }
+
+    /// Get the `messages_published_since_startup` counter of a topic.
+    pub fn messages_published_since_startup_counter_get(&self, topic: &str) -> CounterValue {
+        self.messages_published_since_startup_counter
+            .with_label_values(&[topic])
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Increment the `aggregator_notification_failures_since_startup` counter of a topic.
+    pub fn aggregator_notification_failures_since_startup_counter_increment(&self, topic: &str) {
+        debug!("MetricsService: incrementing 'aggregator_notification_failures_since_startup' counter"; "topic" => topic);
+        self.aggregator_notification_failures_since_startup_counter
+            .with_label_values(&[topic])
+            .inc();
+    }
+
+    /// Get the `aggregator_notification_failures_since_startup` counter of a topic.
+    pub fn aggregator_notification_failures_since_startup_counter_get(
+        &self,
+        topic: &str,
+    ) -> CounterValue {
+        self.aggregator_notification_failures_since_startup_counter
+            .with_label_values(&[topic])
+            .get()
+            .round() as CounterValue
+    }
+
+    /// Increment the `repeated_messages_since_startup` counter.
+    pub fn repeated_messages_since_startup_counter_increment(&self) {
+        debug!("MetricsService: incrementing 'repeated_messages_since_startup' counter");
+        self.repeated_messages_since_startup_counter.inc();
+    }
+
+    /// Get the `repeated_messages_since_startup` counter.
+    pub fn repeated_messages_since_startup_counter_get(&self) -> CounterValue {
+        self.repeated_messages_since_startup_counter.get().round() as CounterValue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_metrics() {
+        let metrics_service = MetricsService::new().unwrap();
+        metrics_service
+            .messages_received_since_startup_counter_increment(mithril_p2p_topic::SIGNERS);
+
+        let exported_metrics = metrics_service.export_metrics().unwrap();
+
+        assert!(exported_metrics.contains(CONNECTED_PEERS_METRIC_NAME));
+        assert!(exported_metrics.contains(&format!(
+            "{MESSAGES_RECEIVED_SINCE_STARTUP_METRIC_NAME}{{topic=\"{}\"}} 1",
+            mithril_p2p_topic::SIGNERS
+        )));
+    }
+
+    #[test]
+    fn test_status_reflects_recorded_metrics() {
+        let metrics_service = MetricsService::new().unwrap();
+        let peer_id = PeerId::random();
+        metrics_service.set_peer_id(Some(peer_id));
+        metrics_service.connected_peers_gauge_set(3);
+        metrics_service
+            .messages_received_since_startup_counter_increment(mithril_p2p_topic::SIGNERS);
+        metrics_service
+            .messages_published_since_startup_counter_increment(mithril_p2p_topic::SIGNATURES);
+        metrics_service
+            .messages_published_since_startup_counter_increment(mithril_p2p_topic::SIGNATURES);
+        metrics_service.aggregator_notification_failures_since_startup_counter_increment(
+            mithril_p2p_topic::SIGNERS,
+        );
+        metrics_service.repeated_messages_since_startup_counter_increment();
+
+        let status = metrics_service.status();
+
+        assert_eq!(
+            RelayStatus {
+                peer_id: Some(peer_id.to_string()),
+                connected_peers: 3,
+                messages_received: BTreeMap::from([
+                    (mithril_p2p_topic::SIGNATURES.to_string(), 0),
+                    (mithril_p2p_topic::SIGNERS.to_string(), 1),
+                ]),
+                messages_published: BTreeMap::from([
+                    (mithril_p2p_topic::SIGNATURES.to_string(), 2),
+                    (mithril_p2p_topic::SIGNERS.to_string(), 0),
+                ]),
+                aggregator_notification_failures: BTreeMap::from([
+                    (mithril_p2p_topic::SIGNATURES.to_string(), 0),
+                    (mithril_p2p_topic::SIGNERS.to_string(), 1),
+                ]),
+                repeated_messages: 1,
+            },
+            status
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::{anyhow, Context};
+use libp2p::identity::Keypair;
+use mithril_common::StdResult;
+use slog_scope::info;
+use std::{fs, path::Path};
+
+/// Load the identity keypair of a peer from the given file, or generate a new one and save it
+/// to the file if it does not exist yet.
+///
+/// Reusing the same keypair across restarts keeps the `PeerId` of the peer stable.
+pub fn load_or_generate_keypair(identity_file: &Path) -> StdResult<Keypair> {
+    if identity_file.exists() {
+        let bytes = fs::read(identity_file).with_context(|| {
+            format!(
+                "Can not read peer identity file '{}'",
+                identity_file.display()
+            )
+        })?;
+        let keypair = Keypair::from_protobuf_encoding(&bytes)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| {
+                format!(
+                    "Can not decode peer identity file '{}'",
+                    identity_file.display()
+                )
+            })?;
+        info!("Peer: loaded identity"; "identity_file" => format!("{}", identity_file.display()), "peer_id" => keypair.public().to_peer_id().to_string());
+
+        return Ok(keypair);
+    }
+
+    let keypair = Keypair::generate_ed25519();
+    let bytes = keypair
+        .to_protobuf_encoding()
+        .map_err(|e| anyhow!(e))
+        .with_context(|| "Can not encode generated peer identity")?;
+    if let Some(parent) = identity_file.parent() {
+        fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Can not create directory of peer identity file '{}'",
+                identity_file.display()
+            )
+        })?;
+    }
+    write_secret_file(identity_file, &bytes).with_context(|| {
+        format!(
+            "Can not write peer identity file '{}'",
+            identity_file.display()
+        )
+    })?;
+    info!("Peer: generated new identity"; "identity_file" => format!("{}", identity_file.display()), "peer_id" => keypair.public().to_peer_id().to_string());
+
+    Ok(keypair)
+}
+
+#[cfg(unix)]
+fn write_secret_file(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(bytes)
+}
+
+#[cfg(not(unix))]
+fn write_secret_file(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    fs::write(path, bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn generate_and_save_keypair_when_identity_file_does_not_exist() {
+        let identity_file = TempDir::create(
+            "relay_identity",
+            "generate_and_save_keypair_when_identity_file_does_not_exist",
+        )
+        .join("sub_dir")
+        .join("peer.key");
+
+        load_or_generate_keypair(&identity_file).unwrap();
+
+        assert!(identity_file.exists());
+    }
+
+    #[test]
+    fn reload_same_peer_id_from_existing_identity_file() {
+        let identity_file = TempDir::create(
+            "relay_identity",
+            "reload_same_peer_id_from_existing_identity_file",
+        )
+        .join("peer.key");
+
+        let generated_keypair = load_or_generate_keypair(&identity_file).unwrap();
+        let loaded_keypair = load_or_generate_keypair(&identity_file).unwrap();
+
+        assert_eq!(
+            generated_keypair.public().to_peer_id(),
+            loaded_keypair.public().to_peer_id()
+        );
+    }
+
+    #[test]
+    fn fail_to_load_invalid_identity_file() {
+        let identity_file = TempDir::create("relay_identity", "fail_to_load_invalid_identity_file")
+            .join("peer.key");
+        fs::write(&identity_file, b"not a keypair").unwrap();
+
+        load_or_generate_keypair(&identity_file)
+            .expect_err("loading an invalid identity file should fail");
+    }
+}
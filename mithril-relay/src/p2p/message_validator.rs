@@ -0,0 +1,353 @@
+use libp2p::PeerId;
+use mithril_common::{
+    crypto_helper::{ProtocolSignerVerificationKey, ProtocolSingleSignature},
+    messages::{RegisterSignatureMessage, RegisterSignerMessage},
+};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    time::{Duration, Instant},
+};
+
+use crate::{mithril_p2p_topic, p2p::BroadcastMessage};
+
+/// [MessageValidator] configuration
+#[derive(Debug, Clone)]
+pub struct MessageValidatorConfig {
+    /// Maximum size in bytes of a broadcast message
+    pub max_message_size: usize,
+
+    /// Duration during which a message already received is considered as a duplicate
+    pub duplicate_window: Duration,
+
+    /// Duration of the window used to rate limit the messages propagated by a peer
+    pub rate_limit_window: Duration,
+
+    /// Maximum number of messages that a peer can propagate during a rate limit window
+    pub max_messages_per_peer: usize,
+}
+
+impl Default for MessageValidatorConfig {
+    fn default() -> Self {
+        Self {
+            max_message_size: 65_536,
+            duplicate_window: Duration::from_secs(600),
+            rate_limit_window: Duration::from_secs(60),
+            max_messages_per_peer: 5_000,
+        }
+    }
+}
+
+/// Result of the validation of a broadcast message received from the P2P pubsub
+#[derive(Debug)]
+pub enum MessageValidation {
+    /// The message is valid, it can be processed and forwarded to the other peers
+    Accept(BroadcastMessage),
+
+    /// The message must not be processed nor forwarded, but the peer that propagated it must
+    /// not be penalized (i.e. a duplicate or a message of a rate limited peer)
+    Ignore(String),
+
+    /// The message is invalid, the peer that propagated it must be penalized
+    Reject(String),
+}
+
+/// Application level validation of the broadcast messages received from the P2P pubsub.
+///
+/// Messages are checked for their size, format and content, duplicates are ignored and
+/// peers that propagate too many messages are rate limited.
+pub struct MessageValidator {
+    config: MessageValidatorConfig,
+    seen_messages: HashMap<u64, Instant>,
+    peers_rate_limit: HashMap<PeerId, (Instant, usize)>,
+}
+
+impl MessageValidator {
+    /// `MessageValidator` factory
+    pub fn new(config: MessageValidatorConfig) -> Self {
+        Self {
+            config,
+            seen_messages: HashMap::new(),
+            peers_rate_limit: HashMap::new(),
+        }
+    }
+
+    /// Validate a message received on a topic from a propagation source peer
+    pub fn validate(
+        &mut self,
+        propagation_source: &PeerId,
+        topic_name: &str,
+        data: &[u8],
+    ) -> MessageValidation {
+        self.validate_at(propagation_source, topic_name, data, Instant::now())
+    }
+
+    fn validate_at(
+        &mut self,
+        propagation_source: &PeerId,
+        topic_name: &str,
+        data: &[u8],
+        now: Instant,
+    ) -> MessageValidation {
+        // Honest peers may exceed the limit when relaying a burst of messages, so they are
+        // not penalized
+        if self.is_rate_limited(propagation_source, now) {
+            return MessageValidation::Ignore(format!(
+                "peer exceeded the limit of {} messages per {:?}",
+                self.config.max_messages_per_peer, self.config.rate_limit_window
+            ));
+        }
+
+        if data.len() > self.config.max_message_size {
+            return MessageValidation::Reject(format!(
+                "message size {} exceeds the maximum size of {} bytes",
+                data.len(),
+                self.config.max_message_size
+            ));
+        }
+
+        let message: BroadcastMessage = match serde_json::from_slice(data) {
+            Ok(message) => message,
+            Err(error) => {
+                return MessageValidation::Reject(format!("invalid message format: {error}"))
+            }
+        };
+
+        if let Err(reason) = validate_message_content(topic_name, &message) {
+            return MessageValidation::Reject(reason);
+        }
+
+        if self.is_duplicate(data, now) {
+            return MessageValidation::Ignore("duplicate message".to_string());
+        }
+
+        MessageValidation::Accept(message)
+    }
+
+    fn is_rate_limited(&mut self, peer_id: &PeerId, now: Instant) -> bool {
+        let rate_limit_window = self.config.rate_limit_window;
+        self.peers_rate_limit
+            .retain(|_, (window_start, _)| now.duration_since(*window_start) < rate_limit_window);
+        let (_, total_messages) = self.peers_rate_limit.entry(*peer_id).or_insert((now, 0));
+        *total_messages += 1;
+
+        *total_messages > self.config.max_messages_per_peer
+    }
+
+    fn is_duplicate(&mut self, data: &[u8], now: Instant) -> bool {
+        let duplicate_window = self.config.duplicate_window;
+        self.seen_messages
+            .retain(|_, seen_at| now.duration_since(*seen_at) < duplicate_window);
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        self.seen_messages.insert(hasher.finish(), now).is_some()
+    }
+}
+
+fn validate_message_content(topic_name: &str, message: &BroadcastMessage) -> Result<(), String> {
+    match (topic_name, message) {
+        (mithril_p2p_topic::SIGNERS, BroadcastMessage::RegisterSigner(message)) => {
+            validate_signer_message(message)
+        }
+        (mithril_p2p_topic::SIGNATURES, BroadcastMessage::RegisterSignature(message)) => {
+            validate_signature_message(message)
+        }
+        _ => Err(format!("unexpected message on topic '{topic_name}'")),
+    }
+}
+
+fn validate_signer_message(message: &RegisterSignerMessage) -> Result<(), String> {
+    if message.party_id.is_empty() {
+        return Err("signer registration with empty party id".to_string());
+    }
+    ProtocolSignerVerificationKey::from_json_hex(&message.verification_key)
+        .map_err(|e| format!("signer registration with invalid verification key: {e}"))?;
+
+    Ok(())
+}
+
+fn validate_signature_message(message: &RegisterSignatureMessage) -> Result<(), String> {
+    if message.party_id.is_empty() {
+        return Err("signature with empty party id".to_string());
+    }
+    if message.won_indexes.is_empty() {
+        return Err("signature without won lottery indexes".to_string());
+    }
+    ProtocolSingleSignature::from_json_hex(&message.signature)
+        .map_err(|e| format!("signature with invalid single signature: {e}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(message: BroadcastMessage) -> Vec<u8> {
+        serde_json::to_vec(&message).unwrap()
+    }
+
+    fn signer_message_data() -> Vec<u8> {
+        encode(BroadcastMessage::RegisterSigner(
+            RegisterSignerMessage::dummy(),
+        ))
+    }
+
+    fn signature_message_data() -> Vec<u8> {
+        encode(BroadcastMessage::RegisterSignature(
+            RegisterSignatureMessage::dummy(),
+        ))
+    }
+
+    #[test]
+    fn accept_valid_messages() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig::default());
+        let peer_id = PeerId::random();
+
+        assert!(matches!(
+            validator.validate(&peer_id, mithril_p2p_topic::SIGNERS, &signer_message_data()),
+            MessageValidation::Accept(BroadcastMessage::RegisterSigner(_))
+        ));
+        assert!(matches!(
+            validator.validate(
+                &peer_id,
+                mithril_p2p_topic::SIGNATURES,
+                &signature_message_data()
+            ),
+            MessageValidation::Accept(BroadcastMessage::RegisterSignature(_))
+        ));
+    }
+
+    #[test]
+    fn reject_message_with_invalid_format() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig::default());
+
+        assert!(matches!(
+            validator.validate(&PeerId::random(), mithril_p2p_topic::SIGNERS, b"invalid"),
+            MessageValidation::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn reject_message_exceeding_max_size() {
+        let data = signer_message_data();
+        let mut validator = MessageValidator::new(MessageValidatorConfig {
+            max_message_size: data.len() - 1,
+            ..MessageValidatorConfig::default()
+        });
+
+        assert!(matches!(
+            validator.validate(&PeerId::random(), mithril_p2p_topic::SIGNERS, &data),
+            MessageValidation::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn reject_message_published_on_unexpected_topic() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig::default());
+
+        assert!(matches!(
+            validator.validate(
+                &PeerId::random(),
+                mithril_p2p_topic::SIGNATURES,
+                &signer_message_data()
+            ),
+            MessageValidation::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn reject_message_with_invalid_content() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig::default());
+        let data = encode(BroadcastMessage::RegisterSignature(
+            RegisterSignatureMessage {
+                signature: "invalid".to_string(),
+                ..RegisterSignatureMessage::dummy()
+            },
+        ));
+
+        assert!(matches!(
+            validator.validate(&PeerId::random(), mithril_p2p_topic::SIGNATURES, &data),
+            MessageValidation::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn ignore_duplicate_message_until_duplicate_window_is_elapsed() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig {
+            duplicate_window: Duration::from_secs(10),
+            ..MessageValidatorConfig::default()
+        });
+        let now = Instant::now();
+        let data = signer_message_data();
+
+        assert!(matches!(
+            validator.validate_at(&PeerId::random(), mithril_p2p_topic::SIGNERS, &data, now),
+            MessageValidation::Accept(_)
+        ));
+        assert!(matches!(
+            validator.validate_at(&PeerId::random(), mithril_p2p_topic::SIGNERS, &data, now),
+            MessageValidation::Ignore(_)
+        ));
+        assert!(matches!(
+            validator.validate_at(
+                &PeerId::random(),
+                mithril_p2p_topic::SIGNERS,
+                &data,
+                now + Duration::from_secs(10)
+            ),
+            MessageValidation::Accept(_)
+        ));
+    }
+
+    #[test]
+    fn ignore_messages_of_peer_exceeding_rate_limit_until_window_is_elapsed() {
+        let mut validator = MessageValidator::new(MessageValidatorConfig {
+            rate_limit_window: Duration::from_secs(10),
+            max_messages_per_peer: 1,
+            ..MessageValidatorConfig::default()
+        });
+        let now = Instant::now();
+        let peer_id = PeerId::random();
+        let signer_data = signer_message_data();
+        let signature_data = signature_message_data();
+
+        assert!(matches!(
+            validator.validate_at(&peer_id, mithril_p2p_topic::SIGNERS, &signer_data, now),
+            MessageValidation::Accept(_)
+        ));
+        assert!(matches!(
+            validator.validate_at(
+                &peer_id,
+                mithril_p2p_topic::SIGNATURES,
+                &signature_data,
+                now
+            ),
+            MessageValidation::Ignore(_)
+        ));
+        assert!(matches!(
+            validator.validate_at(
+                &PeerId::random(),
+                mithril_p2p_topic::SIGNATURES,
+                &signature_data,
+                now
+            ),
+            MessageValidation::Accept(_)
+        ));
+        assert!(matches!(
+            validator.validate_at(
+                &peer_id,
+                mithril_p2p_topic::SIGNATURES,
+                &encode(BroadcastMessage::RegisterSignature(
+                    RegisterSignatureMessage {
+                        party_id: "another-party-id".to_string(),
+                        ..RegisterSignatureMessage::dummy()
+                    }
+                )),
+                now + Duration::from_secs(10)
+            ),
+            MessageValidation::Accept(_)
+        ));
+    }
+}
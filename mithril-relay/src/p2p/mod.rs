@@ -1,5 +1,9 @@
 mod error;
+mod identity;
+mod message_validator;
 mod peer;
 
 pub use error::*;
+pub use identity::*;
+pub use message_validator::*;
 pub use peer::*;
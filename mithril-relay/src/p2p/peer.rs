@@ -1,31 +1,75 @@
 #![allow(missing_docs)]
 use anyhow::{anyhow, Context};
 use libp2p::{
+    core::ConnectedPoint,
     core::{muxing::StreamMuxerBox, transport::dummy::DummyTransport},
     futures::StreamExt,
     gossipsub::{self, ValidationMode},
+    identify,
+    identity::Keypair,
+    kad::{self, store::MemoryStore},
+    mdns,
+    multiaddr::Protocol,
     noise, ping,
-    swarm::{self, DialError, NetworkBehaviour},
-    tls, yamux, Multiaddr, PeerId, Swarm, SwarmBuilder,
+    swarm::{self, behaviour::toggle::Toggle, DialError, NetworkBehaviour},
+    tls, yamux, Multiaddr, PeerId, StreamProtocol, Swarm, SwarmBuilder,
 };
 use mithril_common::{
     messages::{RegisterSignatureMessage, RegisterSignerMessage},
     StdResult,
 };
 use serde::{Deserialize, Serialize};
-use slog_scope::{debug, info};
+use slog_scope::{debug, info, warn};
 use std::{collections::HashMap, time::Duration};
+use tokio::time::Instant;
 
-use crate::{mithril_p2p_topic, p2p::PeerError};
+use crate::{
+    mithril_p2p_topic,
+    p2p::{MessageValidation, MessageValidator, MessageValidatorConfig, PeerError},
+};
 
 /// The idle connection timeout for a P2P connection
 const P2P_IDLE_CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// The interval at which the peer runs the discovery of the P2P network and redials lost peers
+const P2P_DISCOVERY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The delay before redialing a lost bootstrap or dialed peer
+const P2P_REDIAL_DELAY: Duration = Duration::from_secs(2);
+
+/// The maximum number of connected peers above which discovered peers are not dialed
+const P2P_MAX_CONNECTED_PEERS_FOR_DISCOVERY: usize = 25;
+
+/// The protocol name of the Kademlia DHT of the Mithril P2P network
+const P2P_KADEMLIA_PROTOCOL_NAME: StreamProtocol = StreamProtocol::new("/mithril/kad/1.0.0");
+
+/// The protocol version advertised by the identify protocol
+const P2P_IDENTIFY_PROTOCOL_VERSION: &str = "/mithril/relay/1.0.0";
+
 /// [Peer] custom network behaviour
 #[derive(NetworkBehaviour)]
 pub struct PeerBehaviour {
     gossipsub: gossipsub::Behaviour,
     ping: ping::Behaviour,
+    identify: identify::Behaviour,
+    kademlia: kad::Behaviour<MemoryStore>,
+    mdns: Toggle<mdns::tokio::Behaviour>,
+}
+
+/// [Peer] configuration
+#[derive(Debug, Clone, Default)]
+pub struct PeerConfig {
+    /// Identity keypair of the peer, a new one is generated at start if not set
+    pub keypair: Option<Keypair>,
+
+    /// Addresses of the peers used to bootstrap the discovery of the P2P network
+    pub bootstrap_peers: Vec<Multiaddr>,
+
+    /// Discover the peers of the local network with mDNS
+    pub enable_mdns: bool,
+
+    /// Configuration of the validation of the messages received from the P2P pubsub
+    pub message_validator_config: MessageValidatorConfig,
 }
 
 /// [Peer] event that is polled from the swarm
@@ -59,7 +103,7 @@ pub enum PeerEvent {
 pub type TopicName = String;
 
 /// The broadcast message received from a Gossip sub event
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BroadcastMessage {
     /// A signer registration message received from the Gossip sub
     RegisterSigner(RegisterSignerMessage),
@@ -73,6 +117,11 @@ pub struct Peer {
     topics: HashMap<TopicName, gossipsub::IdentTopic>,
     swarm: Option<Swarm<PeerBehaviour>>,
     addr: Multiaddr,
+    config: PeerConfig,
+    /// Addresses of the peers that are redialed when lost, with their peer id once connected
+    persistent_peers: HashMap<Multiaddr, Option<PeerId>>,
+    next_discovery_at: Instant,
+    message_validator: MessageValidator,
     /// Multi address on which the peer is listening
     pub addr_peer: Option<Multiaddr>,
 }
@@ -84,10 +133,21 @@ impl Peer {
             topics: Self::build_topics(),
             swarm: None,
             addr: addr.to_owned(),
+            config: PeerConfig::default(),
+            persistent_peers: HashMap::new(),
+            next_discovery_at: Instant::now() + P2P_DISCOVERY_INTERVAL,
+            message_validator: MessageValidator::new(MessageValidatorConfig::default()),
             addr_peer: None,
         }
     }
 
+    /// Set the configuration of the peer
+    pub fn with_config(mut self, config: PeerConfig) -> Self {
+        self.message_validator = MessageValidator::new(config.message_validator_config.clone());
+        self.config = config;
+        self
+    }
+
     fn build_topics() -> HashMap<TopicName, gossipsub::IdentTopic> {
         HashMap::from([
             (
@@ -101,10 +161,40 @@ impl Peer {
         ])
     }
 
+    /// Peer scoring penalizes the peers propagating messages rejected by the message validator,
+    /// until they are graylisted. Scoring on message delivery rates is disabled as the traffic of
+    /// the Mithril topics is low and bursty.
+    fn build_peer_score_params(
+        topics: &HashMap<TopicName, gossipsub::IdentTopic>,
+    ) -> gossipsub::PeerScoreParams {
+        let topic_score_params = gossipsub::TopicScoreParams {
+            topic_weight: 1.0,
+            mesh_message_deliveries_weight: 0.0,
+            mesh_failure_penalty_weight: 0.0,
+            invalid_message_deliveries_weight: -10.0,
+            invalid_message_deliveries_decay: 0.9,
+            ..gossipsub::TopicScoreParams::default()
+        };
+
+        gossipsub::PeerScoreParams {
+            topics: topics
+                .values()
+                .map(|topic| (topic.hash(), topic_score_params.clone()))
+                .collect(),
+            ..gossipsub::PeerScoreParams::default()
+        }
+    }
+
     /// Start the peer
     pub async fn start(mut self) -> StdResult<Self> {
         debug!("Peer: starting...");
-        let mut swarm = SwarmBuilder::with_new_identity()
+        let keypair = self
+            .config
+            .keypair
+            .clone()
+            .unwrap_or_else(Keypair::generate_ed25519);
+        let enable_mdns = self.config.enable_mdns;
+        let mut swarm = SwarmBuilder::with_existing_identity(keypair)
             .with_tokio()
             .with_tcp(
                 Default::default(),
@@ -127,7 +217,27 @@ impl Peer {
                     .history_length(10)
                     .history_gossip(10)
                     .validation_mode(ValidationMode::Strict)
+                    // Messages are forwarded only once accepted by the message validator
+                    .validate_messages()
                     .build()?;
+                let local_peer_id = key.public().to_peer_id();
+                let mut kademlia_config = kad::Config::default();
+                kademlia_config.set_protocol_names(vec![P2P_KADEMLIA_PROTOCOL_NAME]);
+                let mut kademlia = kad::Behaviour::with_config(
+                    local_peer_id,
+                    MemoryStore::new(local_peer_id),
+                    kademlia_config,
+                );
+                // Relays are reachable by design, they must answer the queries of other peers
+                kademlia.set_mode(Some(kad::Mode::Server));
+                let mdns = if enable_mdns {
+                    Some(mdns::tokio::Behaviour::new(
+                        mdns::Config::default(),
+                        local_peer_id,
+                    )?)
+                } else {
+                    None
+                };
                 Ok(PeerBehaviour {
                     gossipsub: gossipsub::Behaviour::new(
                         gossipsub::MessageAuthenticity::Signed(key.clone()),
@@ -135,11 +245,26 @@ impl Peer {
                     )
                     .expect("Valid configuration"),
                     ping: ping::Behaviour::new(ping::Config::new()),
+                    identify: identify::Behaviour::new(identify::Config::new(
+                        P2P_IDENTIFY_PROTOCOL_VERSION.to_string(),
+                        key.public(),
+                    )),
+                    kademlia,
+                    mdns: mdns.into(),
                 })
             })?
             .with_swarm_config(|c| c.with_idle_connection_timeout(P2P_IDLE_CONNECTION_TIMEOUT))
             .build();
 
+        swarm
+            .behaviour_mut()
+            .gossipsub
+            .with_peer_score(
+                Self::build_peer_score_params(&self.topics),
+                gossipsub::PeerScoreThresholds::default(),
+            )
+            .map_err(|e| anyhow!(e))
+            .with_context(|| "Can not enable peer scoring")?;
         for topic in self.topics.values() {
             debug!("Peer: subscribing to"; "topic" => format!("{topic:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
             swarm.behaviour_mut().gossipsub.subscribe(topic)?;
@@ -156,6 +281,10 @@ impl Peer {
             }
         }
 
+        for bootstrap_peer in self.config.bootstrap_peers.clone() {
+            self.dial(bootstrap_peer)?;
+        }
+
         Ok(self)
     }
 
@@ -175,14 +304,19 @@ impl Peer {
     /// Tick the peer swarm to receive the next event
     pub async fn tick_swarm(&mut self) -> StdResult<Option<PeerEvent>> {
         debug!("Peer: reading next event"; "local_peer_id" => format!("{:?}", self.local_peer_id()));
-        match self
+        let swarm = self
             .swarm
             .as_mut()
             .ok_or(PeerError::UnavailableSwarm())
-            .with_context(|| "Can not publish signature without swarm")?
-            .next()
-            .await
-        {
+            .with_context(|| "Can not publish signature without swarm")?;
+        let event = tokio::select! {
+            event = swarm.next() => event,
+            _ = tokio::time::sleep_until(self.next_discovery_at) => {
+                self.run_discovery()?;
+                return Ok(None);
+            }
+        };
+        match event {
             Some(swarm::SwarmEvent::NewListenAddr { address, .. }) => {
                 debug!("Peer: received listening address event"; "address" => format!("{address:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
                 Ok(Some(PeerEvent::ListeningOnAddr { address }))
@@ -191,12 +325,48 @@ impl Peer {
                 debug!("Peer: received outgoing connection error event"; "error" => format!("{error:#?}"), "remote_peer_id" => format!("{peer_id:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
                 Ok(Some(PeerEvent::OutgoingConnectionError { peer_id, error }))
             }
-            Some(swarm::SwarmEvent::ConnectionEstablished { peer_id, .. }) => {
+            Some(swarm::SwarmEvent::ConnectionEstablished {
+                peer_id, endpoint, ..
+            }) => {
                 debug!("Peer: received connection established event"; "remote_peer_id" => format!("{peer_id:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
+                if let ConnectedPoint::Dialer { address, .. } = endpoint {
+                    if let Some(persistent_peer_id) = self.persistent_peers.get_mut(&address) {
+                        *persistent_peer_id = Some(peer_id);
+                    }
+                }
                 Ok(Some(PeerEvent::ConnectionEstablished { peer_id }))
             }
+            Some(swarm::SwarmEvent::ConnectionClosed {
+                peer_id,
+                num_established,
+                ..
+            }) => {
+                debug!("Peer: received connection closed event"; "remote_peer_id" => format!("{peer_id:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
+                let is_persistent_peer = self
+                    .persistent_peers
+                    .values()
+                    .any(|persistent_peer_id| persistent_peer_id == &Some(peer_id));
+                if num_established == 0 && is_persistent_peer {
+                    info!("Peer: lost connection with peer, redialing soon"; "remote_peer_id" => format!("{peer_id:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
+                    self.next_discovery_at = self
+                        .next_discovery_at
+                        .min(Instant::now() + P2P_REDIAL_DELAY);
+                }
+                Ok(None)
+            }
             Some(swarm::SwarmEvent::Behaviour(event)) => {
                 debug!("Peer: received behaviour event"; "event" => format!("{event:#?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
+                self.handle_discovery_event(&event)?;
+                if let PeerBehaviourEvent::Gossipsub(gossipsub::Event::Message {
+                    propagation_source,
+                    message_id,
+                    message,
+                }) = &event
+                {
+                    if !self.validate_gossip_message(propagation_source, message_id, message)? {
+                        return Ok(None);
+                    }
+                }
                 Ok(Some(PeerEvent::Behaviour { event }))
             }
             Some(event) => {
@@ -265,15 +435,144 @@ impl Peer {
         )
     }
 
-    /// Connect to a remote peer
+    /// Connect to a remote peer, the peer is redialed if the connection is lost
     pub fn dial(&mut self, addr: Multiaddr) -> StdResult<()> {
         debug!("Peer: dialing to"; "address" => format!("{addr:?}"), "local_peer_id" => format!("{:?}", self.local_peer_id()));
+        let swarm = self
+            .swarm
+            .as_mut()
+            .ok_or(PeerError::UnavailableSwarm())
+            .with_context(|| "Can not dial without swarm")?;
+        let peer_id = extract_peer_id(&addr);
+        if let Some(peer_id) = peer_id {
+            swarm
+                .behaviour_mut()
+                .kademlia
+                .add_address(&peer_id, addr.clone());
+        }
+        self.persistent_peers.insert(addr.clone(), peer_id);
+
+        swarm.dial(addr).map_err(|e| anyhow!(e))
+    }
+
+    /// Get the number of peers currently connected to the peer
+    pub fn connected_peers_count(&self) -> usize {
+        self.swarm
+            .as_ref()
+            .map(|swarm| swarm.connected_peers().count())
+            .unwrap_or_default()
+    }
+
+    /// Validate a message received from the P2P pubsub and report the result to the pubsub so
+    /// that only accepted messages are forwarded to the other peers
+    fn validate_gossip_message(
+        &mut self,
+        propagation_source: &PeerId,
+        message_id: &gossipsub::MessageId,
+        message: &gossipsub::Message,
+    ) -> StdResult<bool> {
+        let (acceptance, is_accepted) = match self.message_validator.validate(
+            propagation_source,
+            message.topic.as_str(),
+            &message.data,
+        ) {
+            MessageValidation::Accept(_) => (gossipsub::MessageAcceptance::Accept, true),
+            MessageValidation::Ignore(reason) => {
+                debug!("Peer: ignored message"; "reason" => reason, "remote_peer_id" => format!("{propagation_source:?}"));
+                (gossipsub::MessageAcceptance::Ignore, false)
+            }
+            MessageValidation::Reject(reason) => {
+                warn!("Peer: rejected message"; "reason" => reason, "remote_peer_id" => format!("{propagation_source:?}"));
+                (gossipsub::MessageAcceptance::Reject, false)
+            }
+        };
         self.swarm
             .as_mut()
             .ok_or(PeerError::UnavailableSwarm())
-            .with_context(|| "Can not dial without swarm")?
-            .dial(addr)
-            .map_err(|e| anyhow!(e))
+            .with_context(|| "Can not report message validation without swarm")?
+            .behaviour_mut()
+            .gossipsub
+            .report_message_validation_result(message_id, propagation_source, acceptance)
+            .with_context(|| "Can not report message validation result")?;
+
+        Ok(is_accepted)
+    }
+
+    /// Redial the lost persistent peers and look for new peers in the P2P network
+    fn run_discovery(&mut self) -> StdResult<()> {
+        self.next_discovery_at = Instant::now() + P2P_DISCOVERY_INTERVAL;
+        let swarm = self
+            .swarm
+            .as_mut()
+            .ok_or(PeerError::UnavailableSwarm())
+            .with_context(|| "Can not run discovery without swarm")?;
+
+        for (address, peer_id) in &self.persistent_peers {
+            if peer_id.is_some_and(|peer_id| swarm.is_connected(&peer_id)) {
+                continue;
+            }
+            debug!("Peer: redialing peer"; "address" => format!("{address:?}"));
+            if let Err(error) = swarm.dial(address.clone()) {
+                debug!("Peer: redialing peer failed"; "address" => format!("{address:?}"), "error" => format!("{error:?}"));
+            }
+        }
+        if let Err(error) = swarm.behaviour_mut().kademlia.bootstrap() {
+            debug!("Peer: can not bootstrap the P2P network discovery"; "error" => format!("{error:?}"));
+        }
+        info!("Peer: discovery done"; "connected_peers" => swarm.connected_peers().count(), "local_peer_id" => format!("{:?}", swarm.local_peer_id()));
+
+        Ok(())
+    }
+
+    /// Feed the routing table with the discovered peers and connect to them
+    fn handle_discovery_event(&mut self, event: &PeerBehaviourEvent) -> StdResult<()> {
+        let swarm = self
+            .swarm
+            .as_mut()
+            .ok_or(PeerError::UnavailableSwarm())
+            .with_context(|| "Can not handle discovery event without swarm")?;
+        let mut peers_to_dial = vec![];
+        match event {
+            PeerBehaviourEvent::Identify(identify::Event::Received { peer_id, info }) => {
+                for address in &info.listen_addrs {
+                    swarm
+                        .behaviour_mut()
+                        .kademlia
+                        .add_address(peer_id, address.clone());
+                }
+            }
+            PeerBehaviourEvent::Mdns(mdns::Event::Discovered(peers)) => {
+                for (peer_id, address) in peers {
+                    swarm
+                        .behaviour_mut()
+                        .kademlia
+                        .add_address(peer_id, address.clone());
+                    peers_to_dial.push(*peer_id);
+                }
+            }
+            PeerBehaviourEvent::Kademlia(kad::Event::RoutingUpdated {
+                peer,
+                is_new_peer: true,
+                ..
+            }) => {
+                peers_to_dial.push(*peer);
+            }
+            _ => {}
+        }
+
+        for peer_id in peers_to_dial {
+            if swarm.is_connected(&peer_id)
+                || swarm.connected_peers().count() >= P2P_MAX_CONNECTED_PEERS_FOR_DISCOVERY
+            {
+                continue;
+            }
+            debug!("Peer: dialing discovered peer"; "remote_peer_id" => format!("{peer_id:?}"));
+            if let Err(error) = swarm.dial(peer_id) {
+                debug!("Peer: dialing discovered peer failed"; "remote_peer_id" => format!("{peer_id:?}"), "error" => format!("{error:?}"));
+            }
+        }
+
+        Ok(())
     }
 
     /// Get the local peer id (if any)
@@ -281,3 +580,61 @@ impl Peer {
         self.swarm.as_ref().map(|s| s.local_peer_id().to_owned())
     }
 }
+
+/// Extract the peer id of a multi address ending with a `/p2p/<peer_id>` component
+fn extract_peer_id(address: &Multiaddr) -> Option<PeerId> {
+    match address.iter().last() {
+        Some(Protocol::P2p(peer_id)) => Some(peer_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_peer_id_from_address_with_p2p_component() {
+        let peer_id = PeerId::random();
+        let address: Multiaddr = format!("/ip4/127.0.0.1/tcp/1234/p2p/{peer_id}")
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(peer_id), extract_peer_id(&address));
+    }
+
+    #[test]
+    fn extract_no_peer_id_from_address_without_p2p_component() {
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/1234".parse().unwrap();
+
+        assert_eq!(None, extract_peer_id(&address));
+    }
+
+    #[tokio::test]
+    async fn connect_to_bootstrap_peers_at_start() {
+        let addr: Multiaddr = "/ip4/127.0.0.1/tcp/0".parse().unwrap();
+        let mut bootstrap_peer = Peer::new(&addr).start().await.unwrap();
+        let mut peer = Peer::new(&addr)
+            .with_config(PeerConfig {
+                bootstrap_peers: vec![bootstrap_peer.addr_peer.clone().unwrap()],
+                ..PeerConfig::default()
+            })
+            .start()
+            .await
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while peer.connected_peers_count() == 0 || bootstrap_peer.connected_peers_count() == 0 {
+                tokio::select! {
+                    res = peer.tick_swarm() => { res.unwrap(); },
+                    res = bootstrap_peer.tick_swarm() => { res.unwrap(); },
+                }
+            }
+        })
+        .await
+        .expect("peer should connect to its bootstrap peer");
+
+        assert_eq!(1, peer.connected_peers_count());
+        assert_eq!(1, bootstrap_peer.connected_peers_count());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::p2p::{BroadcastMessage, Peer, PeerEvent};
+use crate::{
+    mithril_p2p_topic,
+    p2p::{BroadcastMessage, Peer, PeerConfig, PeerEvent},
+    MetricsService,
+};
 use anyhow::anyhow;
 use libp2p::Multiaddr;
 use mithril_common::{
@@ -6,29 +10,93 @@ use mithril_common::{
     StdResult,
 };
 use reqwest::StatusCode;
-use slog_scope::{error, info};
+use slog_scope::{error, info, warn};
+use std::{sync::Arc, time::Duration};
+use tokio::time::{sleep_until, Instant};
+
+use super::{record_peer_event_metrics, NotificationQueue, NotificationQueueConfig};
+
+/// Default maximum duration of a notification of a message to the aggregator
+const DEFAULT_AGGREGATOR_NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Error of a failed notification of a message to the aggregator
+enum NotificationError {
+    /// The aggregator rejected the message, it must not be sent again
+    Rejected(anyhow::Error),
+
+    /// The aggregator is unavailable, the message must be sent again later
+    Unavailable(anyhow::Error),
+
+    /// The aggregator failed without telling why, the message may be the cause of the failure
+    /// so it is only sent again a limited number of times
+    Ambiguous(anyhow::Error),
+}
+
+impl NotificationError {
+    /// The aggregator returns a payload describing the error when it fails to process a
+    /// message, so the server errors without payload are returned by a proxy: the gateway and
+    /// service unavailable errors are caused by an unavailable aggregator, the other ones are
+    /// ambiguous.
+    fn from_response(status: StatusCode, body: &str, error: anyhow::Error) -> Self {
+        match status {
+            StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::GATEWAY_TIMEOUT => Self::Unavailable(error),
+            StatusCode::INTERNAL_SERVER_ERROR if !body.is_empty() => Self::Rejected(error),
+            status if status.is_server_error() => Self::Ambiguous(error),
+            _ => Self::Rejected(error),
+        }
+    }
+}
 
 /// A relay for a Mithril aggregator
 pub struct AggregatorRelay {
     aggregator_endpoint: String,
+    http_client: reqwest::Client,
+    notification_timeout: Duration,
     peer: Peer,
+    notification_queue: NotificationQueue,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl AggregatorRelay {
     /// Start a relay for a Mithril aggregator
-    pub async fn start(addr: &Multiaddr, aggregator_endpoint: &str) -> StdResult<Self> {
+    pub async fn start(
+        addr: &Multiaddr,
+        aggregator_endpoint: &str,
+        peer_config: PeerConfig,
+        notification_queue_config: NotificationQueueConfig,
+        metrics_service: Arc<MetricsService>,
+    ) -> StdResult<Self> {
+        let notification_queue = NotificationQueue::load(notification_queue_config).await?;
+        let peer = Peer::new(addr).with_config(peer_config).start().await?;
+        metrics_service.set_peer_id(peer.local_peer_id());
+
         Ok(Self {
             aggregator_endpoint: aggregator_endpoint.to_owned(),
-            peer: Peer::new(addr).start().await?,
+            http_client: reqwest::Client::new(),
+            notification_timeout: DEFAULT_AGGREGATOR_NOTIFICATION_TIMEOUT,
+            peer,
+            notification_queue,
+            metrics_service,
         })
     }
 
+    /// Set the maximum duration of a notification of a message to the aggregator, the peer is
+    /// not polled while a notification is in progress.
+    pub fn with_notification_timeout(mut self, notification_timeout: Duration) -> Self {
+        self.notification_timeout = notification_timeout;
+        self
+    }
+
     async fn notify_signature_to_aggregator(
         &self,
         signature_message: &RegisterSignatureMessage,
-    ) -> StdResult<()> {
-        let response = reqwest::Client::new()
+    ) -> Result<(), NotificationError> {
+        let response = self
+            .http_client
             .post(format!("{}/register-signatures", self.aggregator_endpoint))
+            .timeout(self.notification_timeout)
             .json(signature_message)
             //.header(MITHRIL_API_VERSION_HEADER, "0.1.13") // TODO: retrieve current version
             .send()
@@ -40,23 +108,28 @@ impl AggregatorRelay {
                     Ok(())
                 }
                 status => {
-                    error!("Relay aggregator: Post `/register-signatures` should have returned a 201 status code, got: {status}");
-                    Err(anyhow!("Post `/register-signatures` should have returned a 201 status code, got: {status}"))
+                    let body = response.text().await.unwrap_or_default();
+                    Err(NotificationError::from_response(
+                        status,
+                        &body,
+                        anyhow!("Post `/register-signatures` should have returned a 201 status code, got: {status}, {body}"),
+                    ))
                 }
             },
-            Err(err) => {
-                error!("Relay aggregator: Post `/register-signatures` failed: {err:?}");
-                Err(anyhow!("Post `/register-signatures` failed: {err:?}"))
-            }
+            Err(err) => Err(NotificationError::Unavailable(anyhow!(
+                "Post `/register-signatures` failed: {err:?}"
+            ))),
         }
     }
 
     async fn notify_signer_to_aggregator(
         &self,
         signer_message: &RegisterSignerMessage,
-    ) -> StdResult<()> {
-        let response = reqwest::Client::new()
+    ) -> Result<(), NotificationError> {
+        let response = self
+            .http_client
             .post(format!("{}/register-signer", self.aggregator_endpoint))
+            .timeout(self.notification_timeout)
             .json(signer_message)
             //.header(MITHRIL_API_VERSION_HEADER, "0.1.13") // TODO: retrieve current version
             .send()
@@ -68,61 +141,101 @@ impl AggregatorRelay {
                     Ok(())
                 }
                 status => {
-                    error!("Relay aggregator: Post `/register-signer` should have returned a 201 status code, got: {status}");
-                    Err(anyhow!("Post `/register-signer` should have returned a 201 status code, got: {status}"))
+                    let body = response.text().await.unwrap_or_default();
+                    Err(NotificationError::from_response(
+                        status,
+                        &body,
+                        anyhow!("Post `/register-signer` should have returned a 201 status code, got: {status}, {body}"),
+                    ))
                 }
             },
-            Err(err) => {
-                error!("Relay aggregator: Post `/register-signer` failed: {err:?}");
-                Err(anyhow!("Post `/register-signer` failed: {err:?}"))
-            }
+            Err(err) => Err(NotificationError::Unavailable(anyhow!(
+                "Post `/register-signer` failed: {err:?}"
+            ))),
         }
     }
 
-    /// Tick the aggregator relay
-    pub async fn tick(&mut self) -> StdResult<()> {
-        if let Some(peer_event) = self.peer.tick_swarm().await? {
-            match self.peer.convert_peer_event_to_message(peer_event) {
-                Ok(Some(BroadcastMessage::RegisterSigner(signer_message_received))) => {
-                    let retry_max = 3;
-                    let mut retry_count = 0;
-                    while let Err(e) = self
-                        .notify_signer_to_aggregator(&signer_message_received)
-                        .await
-                    {
-                        retry_count += 1;
-                        if retry_count >= retry_max {
-                            error!("Relay aggregator: failed to send signer registration message to aggregator after {retry_count} attempts"; "signer_message" => format!("{:#?}", signer_message_received), "error" => format!("{e:?}"));
-                            return Err(e);
-                        }
-                    }
+    async fn notify_message_to_aggregator(
+        &self,
+        message: &BroadcastMessage,
+    ) -> Result<(), NotificationError> {
+        let (topic, result) = match message {
+            BroadcastMessage::RegisterSigner(signer_message) => (
+                mithril_p2p_topic::SIGNERS,
+                self.notify_signer_to_aggregator(signer_message).await,
+            ),
+            BroadcastMessage::RegisterSignature(signature_message) => (
+                mithril_p2p_topic::SIGNATURES,
+                self.notify_signature_to_aggregator(signature_message).await,
+            ),
+        };
+        if result.is_err() {
+            self.metrics_service
+                .aggregator_notification_failures_since_startup_counter_increment(topic);
+        }
+
+        result
+    }
+
+    /// Deliver the queued messages to the aggregator, until a delivery fails
+    async fn flush_notification_queue(&mut self) -> StdResult<()> {
+        while let Some(message) = self.notification_queue.next_message(Instant::now()) {
+            let message = message.to_owned();
+            match self.notify_message_to_aggregator(&message).await {
+                Ok(()) => self.notification_queue.acknowledge().await?,
+                Err(NotificationError::Rejected(error)) => {
+                    error!("Relay aggregator: message rejected by aggregator, dropping it"; "message" => format!("{message:#?}"), "error" => format!("{error:?}"));
+                    self.notification_queue.discard().await?;
+                }
+                Err(NotificationError::Unavailable(error)) => {
+                    let backoff = self.notification_queue.postpone(Instant::now());
+                    warn!("Relay aggregator: aggregator is unavailable, will retry later"; "retry_in" => ?backoff, "queued_messages" => self.notification_queue.len(), "error" => format!("{error:?}"));
+                    return Ok(());
                 }
-                Ok(Some(BroadcastMessage::RegisterSignature(signature_message_received))) => {
-                    let retry_max = 3;
-                    let mut retry_count = 0;
-                    while let Err(e) = self
-                        .notify_signature_to_aggregator(&signature_message_received)
-                        .await
+                Err(NotificationError::Ambiguous(error)) => {
+                    match self
+                        .notification_queue
+                        .postpone_failed_attempt(Instant::now())
                     {
-                        retry_count += 1;
-                        if retry_count >= retry_max {
-                            error!("Relay aggregator: failed to send signature message to aggregator after {retry_count} attempts"; "signature_message" => format!("{:#?}", signature_message_received), "error" => format!("{e:?}"));
-                            return Err(e);
+                        Some(backoff) => {
+                            warn!("Relay aggregator: failed to send message to aggregator, will retry later"; "retry_in" => ?backoff, "queued_messages" => self.notification_queue.len(), "error" => format!("{error:?}"));
+                            return Ok(());
+                        }
+                        None => {
+                            error!("Relay aggregator: failed to send message to aggregator, maximum delivery attempts reached, dropping it"; "message" => format!("{message:#?}"), "error" => format!("{error:?}"));
+                            self.notification_queue.discard().await?;
                         }
                     }
                 }
-                Ok(None) => {}
-                Err(e) => return Err(e),
             }
         }
 
         Ok(())
     }
 
+    /// Tick the aggregator relay
+    pub async fn tick(&mut self) -> StdResult<()> {
+        let next_attempt_at = self.notification_queue.next_attempt_at();
+        tokio::select! {
+            peer_event = self.tick_peer() => {
+                if let Some(peer_event) = peer_event? {
+                    if let Some(message) = self.peer.convert_peer_event_to_message(peer_event)? {
+                        self.notification_queue.push(message).await?;
+                    }
+                }
+            }
+            _ = sleep_until(next_attempt_at.unwrap_or_else(Instant::now)), if next_attempt_at.is_some() => {}
+        }
+
+        self.flush_notification_queue().await
+    }
+
     /// Tick the peer of the aggregator relay
-    #[allow(dead_code)]
     pub(crate) async fn tick_peer(&mut self) -> StdResult<Option<PeerEvent>> {
-        self.peer.tick_swarm().await
+        let peer_event = self.peer.tick_swarm().await?;
+        record_peer_event_metrics(&self.metrics_service, &self.peer, peer_event.as_ref());
+
+        Ok(peer_event)
     }
 
     /// Connect to a remote peer
@@ -134,4 +247,167 @@ impl AggregatorRelay {
     pub fn peer_address(&self) -> Option<Multiaddr> {
         self.peer.addr_peer.to_owned()
     }
+
+    /// Retrieve the number of peers connected to the relay
+    pub fn connected_peers_count(&self) -> usize {
+        self.peer.connected_peers_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::test_http_server::{test_http_server, TestHttpServer};
+    use std::{sync::Mutex, time::Duration};
+    use warp::Filter;
+
+    use super::*;
+
+    async fn start_relay_with_aggregator_reply(
+        aggregator_reply: Arc<Mutex<(u16, &'static str)>>,
+        max_delivery_attempts: u32,
+    ) -> (AggregatorRelay, TestHttpServer) {
+        let server = test_http_server(warp::path("register-signatures").and(warp::post()).map(
+            move || {
+                let (status, body) = *aggregator_reply.lock().unwrap();
+                warp::reply::with_status(body, warp::http::StatusCode::from_u16(status).unwrap())
+            },
+        ));
+        let relay = start_relay(&server, max_delivery_attempts).await;
+
+        (relay, server)
+    }
+
+    async fn start_relay(server: &TestHttpServer, max_delivery_attempts: u32) -> AggregatorRelay {
+        AggregatorRelay::start(
+            &"/ip4/127.0.0.1/tcp/0".parse().unwrap(),
+            &server.url(),
+            PeerConfig::default(),
+            NotificationQueueConfig {
+                initial_backoff: Duration::ZERO,
+                max_backoff: Duration::ZERO,
+                max_delivery_attempts,
+                ..NotificationQueueConfig::default()
+            },
+            Arc::new(MetricsService::new().unwrap()),
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn push_signature_message(relay: &mut AggregatorRelay) {
+        relay
+            .notification_queue
+            .push(BroadcastMessage::RegisterSignature(
+                RegisterSignatureMessage::dummy(),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn deliver_queued_message_once_aggregator_is_available() {
+        let aggregator_reply = Arc::new(Mutex::new((503, "")));
+        let (mut relay, _server) =
+            start_relay_with_aggregator_reply(aggregator_reply.clone(), 10).await;
+        push_signature_message(&mut relay).await;
+
+        relay.flush_notification_queue().await.unwrap();
+        assert_eq!(1, relay.notification_queue.len());
+        assert_eq!(
+            1,
+            relay
+                .metrics_service
+                .aggregator_notification_failures_since_startup_counter_get(
+                    mithril_p2p_topic::SIGNATURES
+                )
+        );
+
+        for reply in [(502, ""), (504, "")] {
+            *aggregator_reply.lock().unwrap() = reply;
+            relay.flush_notification_queue().await.unwrap();
+            assert_eq!(1, relay.notification_queue.len());
+        }
+
+        *aggregator_reply.lock().unwrap() = (201, "");
+        relay.flush_notification_queue().await.unwrap();
+        assert!(relay.notification_queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn drop_queued_messages_rejected_by_aggregator() {
+        for aggregator_reply in [
+            (400, r#"{"label":"error","message":"error"}"#),
+            (404, ""),
+            (409, ""),
+            (410, ""),
+            (412, ""),
+            (500, r#"{"label":"error","message":"error"}"#),
+        ] {
+            let (mut relay, _server) =
+                start_relay_with_aggregator_reply(Arc::new(Mutex::new(aggregator_reply)), 10).await;
+            push_signature_message(&mut relay).await;
+            push_signature_message(&mut relay).await;
+
+            relay.flush_notification_queue().await.unwrap();
+
+            assert!(
+                relay.notification_queue.is_empty(),
+                "queued messages should have been dropped for aggregator reply {aggregator_reply:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn keep_queued_messages_while_aggregator_is_unavailable() {
+        let (mut relay, _server) =
+            start_relay_with_aggregator_reply(Arc::new(Mutex::new((503, ""))), 2).await;
+        push_signature_message(&mut relay).await;
+        push_signature_message(&mut relay).await;
+
+        for _ in 0..5 {
+            relay.flush_notification_queue().await.unwrap();
+        }
+
+        assert_eq!(2, relay.notification_queue.len());
+    }
+
+    #[tokio::test]
+    async fn postpone_queued_message_if_aggregator_does_not_answer_in_time() {
+        let server = test_http_server(warp::path("register-signatures").and(warp::post()).then(
+            || async {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                warp::reply::with_status("", warp::http::StatusCode::CREATED)
+            },
+        ));
+        let mut relay = start_relay(&server, 10)
+            .await
+            .with_notification_timeout(Duration::from_millis(50));
+        push_signature_message(&mut relay).await;
+
+        tokio::time::timeout(Duration::from_millis(500), relay.flush_notification_queue())
+            .await
+            .expect("flush should not wait for the aggregator answer")
+            .unwrap();
+
+        assert_eq!(1, relay.notification_queue.len());
+    }
+
+    #[tokio::test]
+    async fn drop_queued_message_once_max_delivery_attempts_is_reached() {
+        let aggregator_reply = Arc::new(Mutex::new((500, "")));
+        let (mut relay, _server) =
+            start_relay_with_aggregator_reply(aggregator_reply.clone(), 2).await;
+        push_signature_message(&mut relay).await;
+        push_signature_message(&mut relay).await;
+
+        relay.flush_notification_queue().await.unwrap();
+        assert_eq!(2, relay.notification_queue.len());
+
+        relay.flush_notification_queue().await.unwrap();
+        assert_eq!(1, relay.notification_queue.len());
+
+        *aggregator_reply.lock().unwrap() = (201, "");
+        relay.flush_notification_queue().await.unwrap();
+        assert!(relay.notification_queue.is_empty());
+    }
 }
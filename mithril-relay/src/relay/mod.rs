@@ -1,7 +1,31 @@
 mod aggregator;
+mod notification_queue;
 mod passive;
 mod signer;
 
 pub use aggregator::AggregatorRelay;
+pub use notification_queue::{NotificationQueue, NotificationQueueConfig};
 pub use passive::PassiveRelay;
 pub use signer::SignerRelay;
+
+use libp2p::gossipsub;
+
+use crate::{
+    p2p::{Peer, PeerBehaviourEvent, PeerEvent},
+    MetricsService,
+};
+
+/// Record the metrics related to an event polled by the peer of a relay
+fn record_peer_event_metrics(
+    metrics_service: &MetricsService,
+    peer: &Peer,
+    peer_event: Option<&PeerEvent>,
+) {
+    metrics_service.connected_peers_gauge_set(peer.connected_peers_count());
+    if let Some(PeerEvent::Behaviour {
+        event: PeerBehaviourEvent::Gossipsub(gossipsub::Event::Message { message, .. }),
+    }) = peer_event
+    {
+        metrics_service.messages_received_since_startup_counter_increment(message.topic.as_str());
+    }
+}
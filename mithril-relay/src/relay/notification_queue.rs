@@ -0,0 +1,654 @@
+use anyhow::Context;
+use mithril_common::{entities::Epoch, StdResult};
+use serde::{Deserialize, Serialize};
+use slog_scope::{debug, warn};
+use std::{
+    collections::{HashSet, VecDeque},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::{fs, io::AsyncWriteExt, time::Instant};
+
+use crate::p2p::BroadcastMessage;
+
+/// Minimum number of entries of the storage file before it is compacted
+const STORAGE_COMPACTION_MIN_ENTRIES: usize = 1_000;
+
+/// [NotificationQueue] configuration
+#[derive(Debug, Clone)]
+pub struct NotificationQueueConfig {
+    /// Maximum number of messages kept in the queue, the oldest messages are dropped when full
+    pub max_size: usize,
+
+    /// Number of epochs after which a message is considered too old to be delivered
+    pub max_epoch_age: u64,
+
+    /// Delay before the first retry after a failed delivery
+    pub initial_backoff: Duration,
+
+    /// Maximum delay between two retries
+    pub max_backoff: Duration,
+
+    /// Maximum number of delivery attempts of a message that fail for an unknown reason before
+    /// it is discarded.
+    ///
+    /// The attempts made while the aggregator is unavailable are not counted: the message is
+    /// kept until it is delivered or its epoch is too old.
+    pub max_delivery_attempts: u32,
+
+    /// File where the queue is persisted, kept in memory only if not set
+    pub storage_file: Option<PathBuf>,
+}
+
+impl Default for NotificationQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 10_000,
+            max_epoch_age: 1,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_delivery_attempts: 10,
+            storage_file: None,
+        }
+    }
+}
+
+/// Entry of the storage file of the queue.
+///
+/// The storage file is an append-only log of the messages added to and removed from the queue,
+/// one JSON entry per line, which is rewritten only when it is compacted.
+#[derive(Debug, Serialize, Deserialize)]
+enum StorageEntry {
+    Push { id: u64, message: BroadcastMessage },
+    Remove { id: u64 },
+}
+
+struct QueuedMessage {
+    id: u64,
+    message: BroadcastMessage,
+}
+
+/// A bounded store-and-forward queue of the messages to deliver to the aggregator.
+///
+/// Messages are delivered in order: when a delivery fails, the next attempt is postponed with
+/// an exponential backoff. While the aggregator is unavailable the message is retried until its
+/// epoch is too old, otherwise it is retried until it reaches its maximum number of delivery
+/// attempts so that it does not block the delivery of the following messages.
+///
+/// Messages whose epoch is too old compared to the epoch of the last message accepted by the
+/// aggregator are discarded: the epochs of the messages received from the P2P network are not
+/// trusted until the aggregator accepts them.
+pub struct NotificationQueue {
+    config: NotificationQueueConfig,
+    messages: VecDeque<QueuedMessage>,
+    next_message_id: u64,
+    pending_storage_entries: Vec<StorageEntry>,
+    storage_entries: usize,
+    storage_compaction_required: bool,
+    trusted_epoch: Option<Epoch>,
+    current_backoff: Option<Duration>,
+    failed_attempts: u32,
+    next_attempt_at: Option<Instant>,
+}
+
+impl NotificationQueue {
+    /// Create a new `NotificationQueue`, loading the messages persisted in its storage file if any
+    pub async fn load(config: NotificationQueueConfig) -> StdResult<Self> {
+        let mut queue = Self {
+            config,
+            messages: VecDeque::new(),
+            next_message_id: 0,
+            pending_storage_entries: vec![],
+            storage_entries: 0,
+            storage_compaction_required: true,
+            trusted_epoch: None,
+            current_backoff: None,
+            failed_attempts: 0,
+            next_attempt_at: None,
+        };
+        if let Some(storage_file) = queue.config.storage_file.clone() {
+            if fs::try_exists(&storage_file).await? {
+                let content = fs::read_to_string(&storage_file).await.with_context(|| {
+                    format!(
+                        "Can not read notification queue file '{}'",
+                        storage_file.display()
+                    )
+                })?;
+                queue.replay_storage_entries(&content).with_context(|| {
+                    format!(
+                        "Can not decode notification queue file '{}'",
+                        storage_file.display()
+                    )
+                })?;
+            }
+            queue.persist().await?;
+        }
+
+        Ok(queue)
+    }
+
+    fn replay_storage_entries(&mut self, content: &str) -> StdResult<()> {
+        let lines: Vec<&str> = content.lines().filter(|line| !line.is_empty()).collect();
+        let mut pushed_messages = vec![];
+        let mut removed_ids = HashSet::new();
+        for (index, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(StorageEntry::Push { id, message }) => {
+                    pushed_messages.push(QueuedMessage { id, message })
+                }
+                Ok(StorageEntry::Remove { id }) => {
+                    removed_ids.insert(id);
+                }
+                // The last entry may have been partially written if the relay was stopped
+                Err(error) if index == lines.len() - 1 => {
+                    warn!("NotificationQueue: ignoring truncated last entry of storage file"; "error" => ?error);
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+        self.next_message_id = pushed_messages
+            .iter()
+            .map(|queued_message| queued_message.id + 1)
+            .max()
+            .unwrap_or_default();
+        self.messages = pushed_messages
+            .into_iter()
+            .filter(|queued_message| !removed_ids.contains(&queued_message.id))
+            .collect();
+
+        Ok(())
+    }
+
+    /// Number of messages waiting for delivery
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if no message is waiting for delivery
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Instant of the next delivery attempt, if any message is waiting for delivery
+    pub fn next_attempt_at(&self) -> Option<Instant> {
+        (!self.is_empty()).then(|| self.next_attempt_at.unwrap_or_else(Instant::now))
+    }
+
+    /// Add a message at the end of the queue
+    pub async fn push(&mut self, message: BroadcastMessage) -> StdResult<()> {
+        let id = self.next_message_id;
+        self.next_message_id += 1;
+        self.pending_storage_entries.push(StorageEntry::Push {
+            id,
+            message: message.clone(),
+        });
+        self.messages.push_back(QueuedMessage { id, message });
+        while self.messages.len() > self.config.max_size {
+            if let Some(dropped_message) = self.pop_front() {
+                warn!("NotificationQueue: queue is full, dropping oldest message"; "message" => format!("{dropped_message:?}"));
+                self.failed_attempts = 0;
+            }
+        }
+        self.discard_outdated_messages();
+
+        self.persist().await
+    }
+
+    /// Get the message to deliver if the next delivery attempt is due
+    pub fn next_message(&self, now: Instant) -> Option<&BroadcastMessage> {
+        if self
+            .next_attempt_at
+            .is_some_and(|next_attempt_at| now < next_attempt_at)
+        {
+            return None;
+        }
+
+        self.messages
+            .front()
+            .map(|queued_message| &queued_message.message)
+    }
+
+    /// Remove the first message of the queue once it is accepted by the aggregator
+    pub async fn acknowledge(&mut self) -> StdResult<()> {
+        if let Some(epoch) = self.pop_front().as_ref().and_then(message_epoch) {
+            self.trusted_epoch = self.trusted_epoch.max(Some(epoch));
+        }
+        self.reset_backoff();
+        self.discard_outdated_messages();
+
+        self.persist().await
+    }
+
+    /// Remove the first message of the queue when it can not be delivered at all
+    pub async fn discard(&mut self) -> StdResult<()> {
+        self.pop_front();
+        self.reset_backoff();
+
+        self.persist().await
+    }
+
+    /// Postpone the delivery of the first message of the queue while the aggregator is
+    /// unavailable, the attempt is not counted in its maximum number of delivery attempts.
+    pub fn postpone(&mut self, now: Instant) -> Duration {
+        let backoff = self
+            .current_backoff
+            .map(|backoff| (backoff * 2).min(self.config.max_backoff))
+            .unwrap_or(self.config.initial_backoff);
+        self.current_backoff = Some(backoff);
+        self.next_attempt_at = Some(now + backoff);
+
+        backoff
+    }
+
+    /// Postpone the delivery of the first message of the queue after an attempt that failed for
+    /// an unknown reason.
+    ///
+    /// Returns `None` if the message has reached its maximum number of delivery attempts, in
+    /// which case it must be discarded.
+    pub fn postpone_failed_attempt(&mut self, now: Instant) -> Option<Duration> {
+        self.failed_attempts += 1;
+        if self.failed_attempts >= self.config.max_delivery_attempts {
+            return None;
+        }
+
+        Some(self.postpone(now))
+    }
+
+    fn reset_backoff(&mut self) {
+        self.current_backoff = None;
+        self.failed_attempts = 0;
+        self.next_attempt_at = None;
+    }
+
+    fn pop_front(&mut self) -> Option<BroadcastMessage> {
+        let queued_message = self.messages.pop_front()?;
+        self.pending_storage_entries.push(StorageEntry::Remove {
+            id: queued_message.id,
+        });
+
+        Some(queued_message.message)
+    }
+
+    fn discard_outdated_messages(&mut self) {
+        let Some(trusted_epoch) = self.trusted_epoch else {
+            return;
+        };
+        let max_epoch_age = self.config.max_epoch_age;
+        let pending_storage_entries = &mut self.pending_storage_entries;
+        self.messages
+            .retain(|queued_message| match message_epoch(&queued_message.message) {
+                Some(epoch) if epoch.0.saturating_add(max_epoch_age) < trusted_epoch.0 => {
+                    debug!("NotificationQueue: discarding outdated message"; "message" => format!("{:?}", queued_message.message), "trusted_epoch" => ?trusted_epoch);
+                    pending_storage_entries.push(StorageEntry::Remove {
+                        id: queued_message.id,
+                    });
+                    false
+                }
+                _ => true,
+            });
+    }
+
+    /// Append the pending entries to the storage file, or rewrite it with the queued messages
+    /// only once it holds too many entries of removed messages
+    async fn persist(&mut self) -> StdResult<()> {
+        let pending_storage_entries = std::mem::take(&mut self.pending_storage_entries);
+        let Some(storage_file) = self.config.storage_file.clone() else {
+            return Ok(());
+        };
+        let total_storage_entries = self.storage_entries + pending_storage_entries.len();
+        if self.storage_compaction_required
+            || total_storage_entries > STORAGE_COMPACTION_MIN_ENTRIES.max(2 * self.messages.len())
+        {
+            // Until the compaction succeeds, the storage file may be out of sync with the queue
+            self.storage_compaction_required = true;
+            let entries = self
+                .messages
+                .iter()
+                .map(|queued_message| StorageEntry::Push {
+                    id: queued_message.id,
+                    message: queued_message.message.clone(),
+                });
+            let temporary_file = storage_file.with_extension("tmp");
+            write_storage_entries(&temporary_file, entries, false).await?;
+            fs::rename(&temporary_file, &storage_file)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Can not write notification queue file '{}'",
+                        storage_file.display()
+                    )
+                })?;
+            self.storage_entries = self.messages.len();
+            self.storage_compaction_required = false;
+        } else if !pending_storage_entries.is_empty() {
+            self.storage_compaction_required = true;
+            write_storage_entries(&storage_file, pending_storage_entries.into_iter(), true).await?;
+            self.storage_entries = total_storage_entries;
+            self.storage_compaction_required = false;
+        }
+
+        Ok(())
+    }
+}
+
+async fn write_storage_entries(
+    file: &Path,
+    entries: impl Iterator<Item = StorageEntry>,
+    append: bool,
+) -> StdResult<()> {
+    let mut bytes = vec![];
+    for entry in entries {
+        serde_json::to_writer(&mut bytes, &entry)?;
+        bytes.push(b'\n');
+    }
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let mut storage_file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(file)
+        .await
+        .with_context(|| format!("Can not open notification queue file '{}'", file.display()))?;
+    storage_file.write_all(&bytes).await?;
+    storage_file.sync_data().await?;
+
+    Ok(())
+}
+
+fn message_epoch(message: &BroadcastMessage) -> Option<Epoch> {
+    match message {
+        BroadcastMessage::RegisterSigner(message) => message.epoch,
+        BroadcastMessage::RegisterSignature(message) => {
+            Some(message.signed_entity_type.get_epoch())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{
+        entities::SignedEntityType,
+        messages::{RegisterSignatureMessage, RegisterSignerMessage},
+        test_utils::TempDir,
+    };
+
+    use super::*;
+
+    fn signature_message(epoch: u64) -> BroadcastMessage {
+        BroadcastMessage::RegisterSignature(RegisterSignatureMessage {
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+            ..RegisterSignatureMessage::dummy()
+        })
+    }
+
+    fn signer_message(epoch: Option<u64>) -> BroadcastMessage {
+        BroadcastMessage::RegisterSigner(RegisterSignerMessage {
+            epoch: epoch.map(Epoch),
+            ..RegisterSignerMessage::dummy()
+        })
+    }
+
+    fn queued_epochs(queue: &NotificationQueue) -> Vec<Option<Epoch>> {
+        queue
+            .messages
+            .iter()
+            .map(|queued_message| message_epoch(&queued_message.message))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn deliver_messages_in_order() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig::default())
+            .await
+            .unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signer_message(Some(2))).await.unwrap();
+
+        let now = Instant::now();
+        assert!(matches!(
+            queue.next_message(now),
+            Some(BroadcastMessage::RegisterSignature(_))
+        ));
+        queue.acknowledge().await.unwrap();
+        assert!(matches!(
+            queue.next_message(now),
+            Some(BroadcastMessage::RegisterSigner(_))
+        ));
+        queue.acknowledge().await.unwrap();
+        assert!(queue.next_message(now).is_none());
+        assert_eq!(None, queue.next_attempt_at());
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_messages_when_queue_is_full() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_size: 2,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+
+        queue.push(signer_message(Some(1))).await.unwrap();
+        queue.push(signer_message(Some(2))).await.unwrap();
+        queue.push(signer_message(None)).await.unwrap();
+
+        assert_eq!(vec![Some(Epoch(2)), None], queued_epochs(&queue));
+    }
+
+    #[tokio::test]
+    async fn discard_messages_with_too_old_epoch() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_epoch_age: 1,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+
+        queue.push(signature_message(5)).await.unwrap();
+        queue.push(signature_message(3)).await.unwrap();
+        queue.push(signer_message(None)).await.unwrap();
+        queue.push(signature_message(4)).await.unwrap();
+        queue.acknowledge().await.unwrap();
+
+        assert_eq!(vec![None, Some(Epoch(4))], queued_epochs(&queue));
+
+        queue.push(signature_message(2)).await.unwrap();
+
+        assert_eq!(vec![None, Some(Epoch(4))], queued_epochs(&queue));
+    }
+
+    #[tokio::test]
+    async fn do_not_trust_epoch_of_messages_not_accepted_by_aggregator() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_epoch_age: 1,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+
+        queue.push(signature_message(u64::MAX)).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signature_message(2)).await.unwrap();
+
+        assert_eq!(
+            vec![Some(Epoch(u64::MAX)), Some(Epoch(1)), Some(Epoch(2))],
+            queued_epochs(&queue)
+        );
+
+        queue.discard().await.unwrap();
+        queue.acknowledge().await.unwrap();
+
+        assert_eq!(vec![Some(Epoch(2))], queued_epochs(&queue));
+    }
+
+    #[tokio::test]
+    async fn do_not_overflow_when_checking_age_of_far_future_epoch() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_epoch_age: u64::MAX,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+
+        queue.push(signature_message(u64::MAX)).await.unwrap();
+        queue.push(signature_message(u64::MAX)).await.unwrap();
+        queue.acknowledge().await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+
+        assert_eq!(
+            vec![Some(Epoch(u64::MAX)), Some(Epoch(1))],
+            queued_epochs(&queue)
+        );
+    }
+
+    #[tokio::test]
+    async fn postpone_delivery_with_exponential_backoff_until_acknowledged() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(3),
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        let now = Instant::now();
+
+        assert_eq!(Duration::from_secs(1), queue.postpone(now));
+        assert!(queue.next_message(now).is_none());
+        assert!(queue.next_message(now + Duration::from_secs(1)).is_some());
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            queue.postpone_failed_attempt(now)
+        );
+        assert_eq!(Duration::from_secs(3), queue.postpone(now));
+
+        queue.acknowledge().await.unwrap();
+        assert_eq!(Duration::from_secs(1), queue.postpone(now));
+    }
+
+    #[tokio::test]
+    async fn stop_postponing_delivery_when_max_delivery_attempts_is_reached() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_delivery_attempts: 3,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        let now = Instant::now();
+
+        assert!(queue.postpone_failed_attempt(now).is_some());
+        assert!(queue.postpone_failed_attempt(now).is_some());
+        assert_eq!(None, queue.postpone_failed_attempt(now));
+
+        queue.discard().await.unwrap();
+        assert!(queue.next_message(now).is_some());
+        assert!(queue.postpone_failed_attempt(now).is_some());
+    }
+
+    #[tokio::test]
+    async fn do_not_count_attempts_postponed_while_aggregator_is_unavailable() {
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            max_delivery_attempts: 2,
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        let now = Instant::now();
+
+        for _ in 0..10 {
+            queue.postpone(now);
+        }
+
+        assert!(queue.postpone_failed_attempt(now).is_some());
+        assert_eq!(None, queue.postpone_failed_attempt(now));
+    }
+
+    #[tokio::test]
+    async fn reload_persisted_messages_from_storage_file() {
+        let storage_file = TempDir::create(
+            "relay_notification_queue",
+            "reload_persisted_messages_from_storage_file",
+        )
+        .join("queue.json");
+        let config = NotificationQueueConfig {
+            storage_file: Some(storage_file),
+            ..NotificationQueueConfig::default()
+        };
+        let mut queue = NotificationQueue::load(config.clone()).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signer_message(Some(2))).await.unwrap();
+        queue.push(signature_message(2)).await.unwrap();
+        queue.acknowledge().await.unwrap();
+
+        let reloaded_queue = NotificationQueue::load(config).await.unwrap();
+
+        assert_eq!(
+            vec![Some(Epoch(2)), Some(Epoch(2))],
+            queued_epochs(&reloaded_queue)
+        );
+    }
+
+    #[tokio::test]
+    async fn append_changes_to_storage_file_and_compact_it_when_too_large() {
+        let storage_file = TempDir::create(
+            "relay_notification_queue",
+            "append_changes_to_storage_file_and_compact_it_when_too_large",
+        )
+        .join("queue.json");
+        let storage_file_lines = || {
+            std::fs::read_to_string(&storage_file)
+                .unwrap()
+                .lines()
+                .count()
+        };
+        let mut queue = NotificationQueue::load(NotificationQueueConfig {
+            storage_file: Some(storage_file.clone()),
+            ..NotificationQueueConfig::default()
+        })
+        .await
+        .unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.acknowledge().await.unwrap();
+
+        assert_eq!(3, storage_file_lines());
+
+        for _ in 0..STORAGE_COMPACTION_MIN_ENTRIES {
+            queue.push(signature_message(1)).await.unwrap();
+            queue.acknowledge().await.unwrap();
+        }
+
+        assert!(storage_file_lines() <= STORAGE_COMPACTION_MIN_ENTRIES);
+        assert_eq!(1, queue.len());
+    }
+
+    #[tokio::test]
+    async fn ignore_truncated_last_entry_of_storage_file() {
+        let storage_file = TempDir::create(
+            "relay_notification_queue",
+            "ignore_truncated_last_entry_of_storage_file",
+        )
+        .join("queue.json");
+        let config = NotificationQueueConfig {
+            storage_file: Some(storage_file.clone()),
+            ..NotificationQueueConfig::default()
+        };
+        let mut queue = NotificationQueue::load(config.clone()).await.unwrap();
+        queue.push(signature_message(1)).await.unwrap();
+        queue.push(signature_message(2)).await.unwrap();
+        let mut content = std::fs::read_to_string(&storage_file).unwrap();
+        content.truncate(content.len() - 10);
+        std::fs::write(&storage_file, content).unwrap();
+
+        let reloaded_queue = NotificationQueue::load(config).await.unwrap();
+
+        assert_eq!(vec![Some(Epoch(1))], queued_epochs(&reloaded_queue));
+    }
+}
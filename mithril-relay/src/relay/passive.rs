@@ -1,21 +1,36 @@
-use crate::p2p::{BroadcastMessage, Peer, PeerEvent};
+use crate::{
+    p2p::{BroadcastMessage, Peer, PeerConfig, PeerEvent},
+    MetricsService,
+};
 use libp2p::Multiaddr;
 use mithril_common::StdResult;
 use slog_scope::{debug, info};
+use std::sync::Arc;
+
+use super::record_peer_event_metrics;
 
 /// A passive relay
 pub struct PassiveRelay {
     /// Relay peer
     // TODO: should be private
     pub peer: Peer,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl PassiveRelay {
     /// Start a passive relay
-    pub async fn start(addr: &Multiaddr) -> StdResult<Self> {
+    pub async fn start(
+        addr: &Multiaddr,
+        peer_config: PeerConfig,
+        metrics_service: Arc<MetricsService>,
+    ) -> StdResult<Self> {
         debug!("PassiveRelay: starting...");
+        let peer = Peer::new(addr).with_config(peer_config).start().await?;
+        metrics_service.set_peer_id(peer.local_peer_id());
+
         Ok(Self {
-            peer: Peer::new(addr).start().await?,
+            peer,
+            metrics_service,
         })
     }
 
@@ -30,7 +45,7 @@ impl PassiveRelay {
 
     /// Tick the passive relay
     pub async fn tick(&mut self) -> StdResult<()> {
-        if let Some(peer_event) = self.peer.tick_swarm().await? {
+        if let Some(peer_event) = self.tick_peer().await? {
             match self.peer.convert_peer_event_to_message(peer_event) {
                 Ok(Some(BroadcastMessage::RegisterSigner(signer_message_received))) => {
                     info!("Relay passive: received signer registration message from P2P network"; "signer_message" => format!("{:#?}", signer_message_received));
@@ -48,7 +63,10 @@ impl PassiveRelay {
 
     /// Tick the peer of the passive relay
     pub async fn tick_peer(&mut self) -> StdResult<Option<PeerEvent>> {
-        self.peer.tick_swarm().await
+        let peer_event = self.peer.tick_swarm().await?;
+        record_peer_event_metrics(&self.metrics_service, &self.peer, peer_event.as_ref());
+
+        Ok(peer_event)
     }
 
     /// Connect to a remote peer
@@ -60,4 +78,9 @@ impl PassiveRelay {
     pub fn peer_address(&self) -> Option<Multiaddr> {
         self.peer.addr_peer.to_owned()
     }
+
+    /// Retrieve the number of peers connected to the relay
+    pub fn connected_peers_count(&self) -> usize {
+        self.peer.connected_peers_count()
+    }
 }
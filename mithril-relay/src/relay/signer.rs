@@ -1,6 +1,8 @@
 use crate::{
-    p2p::{Peer, PeerEvent},
+    mithril_p2p_topic,
+    p2p::{Peer, PeerConfig, PeerEvent},
     repeater::MessageRepeater,
+    MetricsService,
 };
 use libp2p::Multiaddr;
 use mithril_common::{
@@ -13,6 +15,8 @@ use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 use warp::Filter;
 
+use super::record_peer_event_metrics;
+
 /// A relay for a Mithril signer
 pub struct SignerRelay {
     server: TestHttpServer,
@@ -20,6 +24,7 @@ pub struct SignerRelay {
     signature_rx: UnboundedReceiver<RegisterSignatureMessage>,
     signer_rx: UnboundedReceiver<RegisterSignerMessage>,
     signer_repeater: Arc<MessageRepeater<RegisterSignerMessage>>,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl SignerRelay {
@@ -29,6 +34,8 @@ impl SignerRelay {
         server_port: &u16,
         aggregator_endpoint: &str,
         signer_repeater_delay: &Duration,
+        peer_config: PeerConfig,
+        metrics_service: Arc<MetricsService>,
     ) -> StdResult<Self> {
         debug!("SignerRelay: starting...");
         let (signature_tx, signature_rx) = unbounded_channel::<RegisterSignatureMessage>();
@@ -36,8 +43,10 @@ impl SignerRelay {
         let signer_repeater = Arc::new(MessageRepeater::new(
             signer_tx.clone(),
             signer_repeater_delay.to_owned(),
+            metrics_service.clone(),
         ));
-        let peer = Peer::new(address).start().await?;
+        let peer = Peer::new(address).with_config(peer_config).start().await?;
+        metrics_service.set_peer_id(peer.local_peer_id());
         let server = Self::start_http_server(
             server_port,
             aggregator_endpoint,
@@ -54,6 +63,7 @@ impl SignerRelay {
             signature_rx,
             signer_rx,
             signer_repeater,
+            metrics_service,
         })
     }
 
@@ -100,6 +110,7 @@ impl SignerRelay {
                     Some(signature_message) => {
                         info!("SignerRelay: publish signature to p2p network"; "message" => format!("{signature_message:#?}"));
                         self.peer.publish_signature(&signature_message)?;
+                        self.metrics_service.messages_published_since_startup_counter_increment(mithril_p2p_topic::SIGNATURES);
                         Ok(())
                     }
                     None => {
@@ -113,6 +124,7 @@ impl SignerRelay {
                     Some(signer_message) => {
                         info!("SignerRelay: publish signer-registration to p2p network"; "message" => format!("{signer_message:#?}"));
                         self.peer.publish_signer_registration(&signer_message)?;
+                        self.metrics_service.messages_published_since_startup_counter_increment(mithril_p2p_topic::SIGNERS);
                         Ok(())
                     }
                     None => {
@@ -122,7 +134,10 @@ impl SignerRelay {
                 }
             },
             _ = self.signer_repeater.repeat_message() => {Ok(())},
-            _event =  self.peer.tick_swarm() => {Ok(())}
+            event =  self.peer.tick_swarm() => {
+                record_peer_event_metrics(&self.metrics_service, &self.peer, event?.as_ref());
+                Ok(())
+            }
         }
     }
 
@@ -134,7 +149,10 @@ impl SignerRelay {
 
     /// Tick the peer of the signer relay
     pub async fn tick_peer(&mut self) -> StdResult<Option<PeerEvent>> {
-        self.peer.tick_swarm().await
+        let peer_event = self.peer.tick_swarm().await?;
+        record_peer_event_metrics(&self.metrics_service, &self.peer, peer_event.as_ref());
+
+        Ok(peer_event)
     }
 
     /// Connect to a remote peer
@@ -151,6 +169,11 @@ impl SignerRelay {
     pub fn peer_address(&self) -> Option<Multiaddr> {
         self.peer.addr_peer.to_owned()
     }
+
+    /// Retrieve the number of peers connected to the relay
+    pub fn connected_peers_count(&self) -> usize {
+        self.peer.connected_peers_count()
+    }
 }
 
 mod middlewares {
@@ -7,22 +7,30 @@ use tokio::{
     time::Instant,
 };
 
+use crate::MetricsService;
+
 /// A message repeater will send a copy of the message to a channel at a given frequency
 pub struct MessageRepeater<M: Clone + Debug + Sync + Send + 'static> {
     message: Arc<Mutex<Option<M>>>,
     tx_message: UnboundedSender<M>,
     delay: Duration,
     next_repeat_at: Arc<Mutex<Option<Instant>>>,
+    metrics_service: Arc<MetricsService>,
 }
 
 impl<M: Clone + Debug + Sync + Send + 'static> MessageRepeater<M> {
     /// Factory for MessageRepeater
-    pub fn new(tx_message: UnboundedSender<M>, delay: Duration) -> Self {
+    pub fn new(
+        tx_message: UnboundedSender<M>,
+        delay: Duration,
+        metrics_service: Arc<MetricsService>,
+    ) -> Self {
         Self {
             message: Arc::new(Mutex::new(None)),
             tx_message,
             delay,
             next_repeat_at: Arc::new(Mutex::new(None)),
+            metrics_service,
         }
     }
 
@@ -52,7 +60,9 @@ impl<M: Clone + Debug + Sync + Send + 'static> MessageRepeater<M> {
                 debug!("MessageRepeater: repeat message"; "message" => format!("{:#?}", message));
                 self.tx_message
                     .send(message.clone())
-                    .map_err(|e| anyhow!(e))?
+                    .map_err(|e| anyhow!(e))?;
+                self.metrics_service
+                    .repeated_messages_since_startup_counter_increment();
             }
             None => {
                 debug!("MessageRepeater: no message to repeat");
@@ -74,7 +84,8 @@ mod tests {
     async fn should_repeat_message_when_exists() {
         let (tx, mut rx) = mpsc::unbounded_channel();
         let delay = Duration::from_millis(100);
-        let repeater = MessageRepeater::new(tx, delay);
+        let metrics_service = Arc::new(MetricsService::new().unwrap());
+        let repeater = MessageRepeater::new(tx, delay, metrics_service.clone());
 
         let message = "Hello, world!";
         repeater.set_message(message.to_string()).await;
@@ -82,13 +93,17 @@ mod tests {
 
         let received = rx.recv().await.unwrap();
         assert_eq!(message, received);
+        assert_eq!(
+            1,
+            metrics_service.repeated_messages_since_startup_counter_get()
+        );
     }
 
     #[tokio::test]
     async fn should_repeat_message_when_exists_with_expected_delay() {
         let (tx, _rx) = mpsc::unbounded_channel();
         let delay = Duration::from_secs(1);
-        let repeater = MessageRepeater::new(tx, delay);
+        let repeater = MessageRepeater::new(tx, delay, Arc::new(MetricsService::new().unwrap()));
 
         let message = "Hello, world!";
         repeater.set_message(message.to_string()).await;
@@ -105,7 +120,7 @@ mod tests {
     async fn should_do_nothing_when_message_not_exists() {
         let (tx, rx) = mpsc::unbounded_channel::<String>();
         let delay = Duration::from_millis(100);
-        let repeater = MessageRepeater::new(tx, delay);
+        let repeater = MessageRepeater::new(tx, delay, Arc::new(MetricsService::new().unwrap()));
 
         repeater.repeat_message().await.unwrap();
 
@@ -116,7 +131,7 @@ mod tests {
     async fn should_do_nothing_when_message_not_exists_with_expected_delay() {
         let (tx, _rx) = mpsc::unbounded_channel::<String>();
         let delay = Duration::from_secs(1);
-        let repeater = MessageRepeater::new(tx, delay);
+        let repeater = MessageRepeater::new(tx, delay, Arc::new(MetricsService::new().unwrap()));
 
         let result = tokio::select! {
             _ = time::sleep(delay - Duration::from_millis(100)) => {Err(anyhow!("Timeout"))}
@@ -3,8 +3,8 @@ use std::{sync::Arc, time::Duration};
 use libp2p::{gossipsub, Multiaddr};
 use mithril_common::messages::{RegisterSignatureMessage, RegisterSignerMessage};
 use mithril_relay::{
-    p2p::{BroadcastMessage, PeerBehaviourEvent, PeerEvent},
-    PassiveRelay, SignerRelay,
+    p2p::{BroadcastMessage, PeerBehaviourEvent, PeerConfig, PeerEvent},
+    MetricsService, PassiveRelay, SignerRelay,
 };
 use reqwest::StatusCode;
 use slog::{Drain, Level, Logger};
@@ -41,6 +41,8 @@ async fn should_receive_registrations_from_signers_when_subscribed_to_pubsub() {
         &server_port,
         &aggregator_endpoint,
         &signer_repeater_delay,
+        PeerConfig::default(),
+        Arc::new(MetricsService::new().unwrap()),
     )
     .await
     .expect("Relay start failed");
@@ -48,17 +50,25 @@ async fn should_receive_registrations_from_signers_when_subscribed_to_pubsub() {
     let relay_peer_address = signer_relay.peer_address().unwrap();
     info!("Test: relay_address is '{relay_address:?}'");
 
-    let mut p2p_client1 = PassiveRelay::start(&addr)
-        .await
-        .expect("P2P client start failed");
+    let mut p2p_client1 = PassiveRelay::start(
+        &addr,
+        PeerConfig::default(),
+        Arc::new(MetricsService::new().unwrap()),
+    )
+    .await
+    .expect("P2P client start failed");
     p2p_client1
         .peer
         .dial(relay_peer_address.clone())
         .expect("P2P client dial to the relay should not fail");
 
-    let mut p2p_client2 = PassiveRelay::start(&addr)
-        .await
-        .expect("P2P client start failed");
+    let mut p2p_client2 = PassiveRelay::start(
+        &addr,
+        PeerConfig::default(),
+        Arc::new(MetricsService::new().unwrap()),
+    )
+    .await
+    .expect("P2P client start failed");
     p2p_client2
         .peer
         .dial(relay_peer_address.clone())
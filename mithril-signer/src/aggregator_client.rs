@@ -12,7 +12,8 @@ use mithril_common::{
     },
     messages::{
         AggregatorFeaturesMessage, CertificatePendingMessage, EpochSettingsMessage,
-        FromMessageAdapter, TryFromMessageAdapter, TryToMessageAdapter,
+        FromMessageAdapter, SigningOpportunityListMessage, TryFromMessageAdapter,
+        TryToMessageAdapter,
     },
     StdError, MITHRIL_API_VERSION_HEADER, MITHRIL_SIGNER_VERSION_HEADER,
 };
@@ -86,6 +87,13 @@ pub trait AggregatorClient: Sync + Send {
         &self,
     ) -> Result<Option<CertificatePending>, AggregatorClientError>;
 
+    /// Retrieves the messages open for signatures from the aggregator
+    ///
+    /// Returns `None` if the aggregator does not support listing them.
+    async fn retrieve_signing_opportunities(
+        &self,
+    ) -> Result<Option<SigningOpportunityListMessage>, AggregatorClientError>;
+
     /// Registers signer with the aggregator.
     async fn register_signer(
         &self,
@@ -240,6 +248,33 @@ impl AggregatorClient for AggregatorHTTPClient {
         }
     }
 
+    async fn retrieve_signing_opportunities(
+        &self,
+    ) -> Result<Option<SigningOpportunityListMessage>, AggregatorClientError> {
+        debug!("Retrieve signing opportunities");
+        let url = format!("{}/signing-opportunities", self.aggregator_endpoint);
+        let response = self
+            .prepare_request_builder(self.prepare_http_client()?.get(url.clone()))
+            .send()
+            .await;
+
+        match response {
+            Ok(response) => match response.status() {
+                StatusCode::OK => match response.json::<SigningOpportunityListMessage>().await {
+                    Ok(message) => Ok(Some(message)),
+                    Err(err) => Err(AggregatorClientError::JsonParseFailed(anyhow!(err))),
+                },
+                StatusCode::PRECONDITION_FAILED => Err(self.handle_api_error(&response)),
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                    "{}",
+                    response.text().await.unwrap_or_default()
+                ))),
+            },
+            Err(err) => Err(AggregatorClientError::RemoteServerUnreachable(anyhow!(err))),
+        }
+    }
+
     async fn register_signer(
         &self,
         epoch: Epoch,
@@ -348,6 +383,7 @@ pub(crate) mod dumb {
     pub struct DumbAggregatorClient {
         epoch_settings: RwLock<Option<EpochSettings>>,
         certificate_pending: RwLock<Option<CertificatePending>>,
+        signing_opportunities: RwLock<Option<SigningOpportunityListMessage>>,
         last_registered_signer: RwLock<Option<Signer>>,
     }
 
@@ -357,6 +393,7 @@ pub(crate) mod dumb {
             Self {
                 epoch_settings: RwLock::new(None),
                 certificate_pending: RwLock::new(None),
+                signing_opportunities: RwLock::new(None),
                 last_registered_signer: RwLock::new(None),
             }
         }
@@ -379,6 +416,15 @@ pub(crate) mod dumb {
             *signer = None;
         }
 
+        /// this method pilots the signing opportunities handler
+        pub async fn set_signing_opportunities(
+            &self,
+            signing_opportunities: Option<SigningOpportunityListMessage>,
+        ) {
+            let mut opportunities = self.signing_opportunities.write().await;
+            *opportunities = signing_opportunities;
+        }
+
         /// Return the last signer that called with the `register` method.
         pub async fn get_last_registered_signer(&self) -> Option<Signer> {
             self.last_registered_signer.read().await.clone()
@@ -390,6 +436,7 @@ pub(crate) mod dumb {
             Self {
                 epoch_settings: RwLock::new(Some(fake_data::epoch_settings())),
                 certificate_pending: RwLock::new(Some(fake_data::certificate_pending())),
+                signing_opportunities: RwLock::new(None),
                 last_registered_signer: RwLock::new(None),
             }
         }
@@ -413,6 +460,14 @@ pub(crate) mod dumb {
             Ok(cert)
         }
 
+        async fn retrieve_signing_opportunities(
+            &self,
+        ) -> Result<Option<SigningOpportunityListMessage>, AggregatorClientError> {
+            let signing_opportunities = self.signing_opportunities.read().await.clone();
+
+            Ok(signing_opportunities)
+        }
+
         /// Registers signer with the aggregator
         async fn register_signer(
             &self,
@@ -450,7 +505,7 @@ mod tests {
 
     use mithril_common::entities::{ClientError, Epoch};
     use mithril_common::era::{EraChecker, SupportedEra};
-    use mithril_common::messages::TryFromMessageAdapter;
+    use mithril_common::messages::{SigningOpportunityListItemMessage, TryFromMessageAdapter};
     use mithril_common::test_utils::fake_data;
 
     use crate::configuration::Configuration;
@@ -775,6 +830,63 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_signing_opportunities_ok_200() {
+        let (server, client) = setup_server_and_client();
+        let message_expected = vec![SigningOpportunityListItemMessage::dummy()];
+        let _server_mock = server.mock(|when, then| {
+            when.path("/signing-opportunities");
+            then.status(200).body(json!(message_expected).to_string());
+        });
+
+        let message = client.retrieve_signing_opportunities().await.unwrap();
+
+        assert_eq!(Some(message_expected), message);
+    }
+
+    #[tokio::test]
+    async fn test_signing_opportunities_ok_404_when_not_supported_by_aggregator() {
+        let (server, client) = setup_server_and_client();
+        let _server_mock = server.mock(|when, then| {
+            when.path("/signing-opportunities");
+            then.status(404);
+        });
+
+        let message = client.retrieve_signing_opportunities().await.unwrap();
+
+        assert_eq!(None, message);
+    }
+
+    #[tokio::test]
+    async fn test_signing_opportunities_ko_412() {
+        let (server, client) = setup_server_and_client();
+        set_returning_412(&server);
+
+        let error = client.retrieve_signing_opportunities().await.unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::ApiVersionMismatch(_));
+    }
+
+    #[tokio::test]
+    async fn test_signing_opportunities_ko_500() {
+        let (server, client) = setup_server_and_client();
+        set_returning_500(&server);
+
+        let error = client.retrieve_signing_opportunities().await.unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::RemoteServerTechnical(_));
+    }
+
+    #[tokio::test]
+    async fn test_signing_opportunities_ko_json_serialization() {
+        let (server, client) = setup_server_and_client();
+        set_unparsable_json(&server);
+
+        let error = client.retrieve_signing_opportunities().await.unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::JsonParseFailed(_));
+    }
+
     #[tokio::test]
     async fn test_register_signer_ok_201() {
         let epoch = Epoch(1);
@@ -0,0 +1,617 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use slog_scope::debug;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use mithril_common::api_version::APIVersionProvider;
+use mithril_common::chain_observer::ChainObserver;
+use mithril_common::crypto_helper::{OpCert, SerDeShelleyFileFormat, Sum6KesBytes};
+use mithril_common::entities::{Epoch, PartyId};
+use mithril_common::era::{EraChecker, EraReader, SupportedEra};
+use mithril_common::StdResult;
+
+use crate::{
+    AggregatorClient, AggregatorHTTPClient, Configuration, KesExpiry, KesExpiryStatus,
+    ProductionServiceBuilder, HTTP_REQUEST_TIMEOUT_DURATION,
+};
+
+/// Check the signer setup (keys, Cardano node, era reader and aggregator) and print a report.
+#[derive(Parser, Debug, Clone)]
+pub struct DoctorCommand {}
+
+impl DoctorCommand {
+    /// Execute the command
+    pub async fn execute(&self, config: &Configuration) -> StdResult<()> {
+        debug!("DOCTOR command"; "config" => format!("{config:?}"));
+        let chain_observer = ProductionServiceBuilder::new(config).build_chain_observer()?;
+        let era_reader = EraReader::new(config.build_era_reader_adapter(chain_observer.clone())?);
+        // The API version sent to the aggregator only depends on the era, fallback to the first
+        // supported one so the aggregator can still be checked if the era reader is misconfigured.
+        let current_era = match chain_observer.get_current_epoch().await {
+            Ok(Some(epoch)) => era_reader
+                .read_era_epoch_token(epoch)
+                .await
+                .ok()
+                .and_then(|token| token.get_current_supported_era().ok()),
+            _ => None,
+        }
+        .unwrap_or(SupportedEra::eras()[0]);
+        let aggregator_client = Arc::new(AggregatorHTTPClient::new(
+            config.aggregator_endpoint.clone(),
+            config.relay_endpoint.clone(),
+            Arc::new(APIVersionProvider::new(Arc::new(EraChecker::new(
+                current_era,
+                Epoch(0),
+            )))),
+            Some(Duration::from_millis(HTTP_REQUEST_TIMEOUT_DURATION)),
+        ));
+
+        let report = SignerDoctor::new(config, chain_observer, era_reader, aggregator_client)
+            .run()
+            .await;
+        println!("{report}");
+
+        match report.count_failures() {
+            0 => Ok(()),
+            failures => Err(anyhow!("{failures} doctor check(s) failed")),
+        }
+    }
+}
+
+/// Status of a [DoctorCheck]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorCheckStatus {
+    /// The check succeeded
+    Pass,
+    /// The check failed
+    Fail,
+    /// The check was not run since it does not apply to this configuration
+    Skip,
+}
+
+/// Result of a single check run by the [SignerDoctor]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    /// Name of the check
+    pub name: String,
+    /// Status of the check
+    pub status: DoctorCheckStatus,
+    /// Details about the check outcome
+    pub details: String,
+}
+
+impl DoctorCheck {
+    fn new<T: Into<String>>(name: &str, status: DoctorCheckStatus, details: T) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            details: details.into(),
+        }
+    }
+
+    fn from_result(name: &str, result: StdResult<String>) -> Self {
+        match result {
+            Ok(details) => Self::new(name, DoctorCheckStatus::Pass, details),
+            Err(error) => Self::new(name, DoctorCheckStatus::Fail, format!("{error:#}")),
+        }
+    }
+}
+
+/// Report of all the checks run by the [SignerDoctor]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorReport {
+    /// Checks run, in order
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Number of failed checks
+    pub fn count_failures(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status == DoctorCheckStatus::Fail)
+            .count()
+    }
+}
+
+impl Display for DoctorReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for check in &self.checks {
+            let status = match check.status {
+                DoctorCheckStatus::Pass => "PASS",
+                DoctorCheckStatus::Fail => "FAIL",
+                DoctorCheckStatus::Skip => "SKIP",
+            };
+            writeln!(f, "[{status}] {}: {}", check.name, check.details)?;
+        }
+
+        write!(
+            f,
+            "{} check(s) run, {} failure(s)",
+            self.checks.len(),
+            self.count_failures()
+        )
+    }
+}
+
+/// Run the checks of the signer setup
+pub struct SignerDoctor<'a> {
+    config: &'a Configuration,
+    chain_observer: Arc<dyn ChainObserver>,
+    era_reader: EraReader,
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl<'a> SignerDoctor<'a> {
+    /// Create a new instance
+    pub fn new(
+        config: &'a Configuration,
+        chain_observer: Arc<dyn ChainObserver>,
+        era_reader: EraReader,
+        aggregator_client: Arc<dyn AggregatorClient>,
+    ) -> Self {
+        Self {
+            config,
+            chain_observer,
+            era_reader,
+            aggregator_client,
+        }
+    }
+
+    /// Run all the checks and return their report
+    pub async fn run(&self) -> DoctorReport {
+        let mut checks = vec![];
+
+        let opcert = match &self.config.operational_certificate_path {
+            Some(path) => {
+                let opcert = OpCert::from_file(path)
+                    .map_err(|e| anyhow!(e))
+                    .with_context(|| format!("Could not decode file '{}'", path.display()));
+                let check_result = opcert
+                    .as_ref()
+                    .map_err(|e| anyhow!("{e:#}"))
+                    .and_then(|opcert| self.check_operational_certificate(opcert));
+                checks.push(DoctorCheck::from_result(
+                    "Operational certificate",
+                    check_result,
+                ));
+                opcert.ok()
+            }
+            None => {
+                checks.push(DoctorCheck::new(
+                    "Operational certificate",
+                    DoctorCheckStatus::Skip,
+                    "no operational certificate configured",
+                ));
+                None
+            }
+        };
+
+        let kes_secret_key_period = match &self.config.kes_secret_key_path {
+            Some(path) => {
+                let check_result = self.check_kes_secret_key(path, opcert.as_ref());
+                let kes_period = check_result.as_ref().ok().map(|(period, _)| *period);
+                checks.push(DoctorCheck::from_result(
+                    "KES secret key",
+                    check_result.map(|(_, details)| details),
+                ));
+                kes_period
+            }
+            None => {
+                checks.push(DoctorCheck::new(
+                    "KES secret key",
+                    DoctorCheckStatus::Skip,
+                    "no KES secret key configured",
+                ));
+                None
+            }
+        };
+
+        let epoch = self.chain_observer.get_current_epoch().await;
+        checks.push(DoctorCheck::from_result(
+            "Cardano node epoch",
+            match &epoch {
+                Ok(Some(epoch)) => Ok(format!("current epoch is {epoch}")),
+                Ok(None) => Err(anyhow!("the Cardano node did not return any epoch")),
+                Err(error) => Err(anyhow!("could not query the Cardano node: {error}")),
+            },
+        ));
+
+        match &opcert {
+            Some(opcert) => checks.push(DoctorCheck::from_result(
+                "Cardano node KES period",
+                self.check_kes_period(opcert, kes_secret_key_period).await,
+            )),
+            None => checks.push(DoctorCheck::new(
+                "Cardano node KES period",
+                DoctorCheckStatus::Skip,
+                "no operational certificate configured",
+            )),
+        }
+
+        match self.compute_party_id(opcert.as_ref()) {
+            Some(party_id) => checks.push(DoctorCheck::from_result(
+                "Stake distribution",
+                self.check_stake_distribution(&party_id).await,
+            )),
+            None => checks.push(DoctorCheck::new(
+                "Stake distribution",
+                DoctorCheckStatus::Fail,
+                "no party id could be computed from the configuration",
+            )),
+        }
+
+        match epoch {
+            Ok(Some(epoch)) => checks.push(DoctorCheck::from_result(
+                "Era reader",
+                self.check_era_reader(epoch).await,
+            )),
+            _ => checks.push(DoctorCheck::new(
+                "Era reader",
+                DoctorCheckStatus::Skip,
+                "the current epoch is unknown",
+            )),
+        }
+
+        checks.push(DoctorCheck::from_result(
+            "Aggregator",
+            self.check_aggregator().await,
+        ));
+
+        DoctorReport { checks }
+    }
+
+    fn compute_party_id(&self, opcert: Option<&OpCert>) -> Option<PartyId> {
+        match opcert {
+            Some(opcert) => opcert.compute_protocol_party_id().ok(),
+            None => self.config.party_id.clone(),
+        }
+    }
+
+    fn check_operational_certificate(&self, opcert: &OpCert) -> StdResult<String> {
+        opcert
+            .validate()
+            .with_context(|| "Invalid operational certificate signature")?;
+        let party_id = opcert
+            .compute_protocol_party_id()
+            .with_context(|| "Could not compute party id from operational certificate")?;
+
+        match &self.config.party_id {
+            Some(configured_party_id) if configured_party_id != &party_id => Err(anyhow!(
+                "party id '{party_id}' of the operational certificate does not match the configured party id '{configured_party_id}'"
+            )),
+            _ => Ok(format!("valid, party id is '{party_id}'")),
+        }
+    }
+
+    fn check_kes_secret_key(
+        &self,
+        kes_secret_key_path: &std::path::Path,
+        opcert: Option<&OpCert>,
+    ) -> StdResult<(u32, String)> {
+        let kes_secret_key = Sum6KesBytes::from_file(kes_secret_key_path)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| {
+                format!("Could not decode file '{}'", kes_secret_key_path.display())
+            })?;
+        let opcert = opcert.ok_or_else(|| {
+            anyhow!("a valid operational certificate is required to check the KES secret key")
+        })?;
+        let kes_period = opcert.check_kes_secret_key(kes_secret_key)?;
+
+        Ok((
+            kes_period,
+            format!("certified by the operational certificate, evolved to KES period {kes_period}"),
+        ))
+    }
+
+    async fn check_kes_period(
+        &self,
+        opcert: &OpCert,
+        kes_secret_key_period: Option<u32>,
+    ) -> StdResult<String> {
+        let current_kes_period = self
+            .chain_observer
+            .get_current_kes_period(opcert)
+            .await
+            .map_err(|e| anyhow!("could not query the Cardano node: {e}"))?
+            .ok_or_else(|| anyhow!("the Cardano node did not return any KES period"))?;
+        let start_kes_period = opcert.start_kes_period;
+
+        if (current_kes_period as u64) < start_kes_period {
+            return Err(anyhow!(
+                "current KES period {current_kes_period} is before the start KES period {start_kes_period} of the operational certificate"
+            ));
+        }
+        // The signer evolves the KES secret key up to the current period but can not go back
+        let relative_kes_period = current_kes_period - start_kes_period as u32;
+        if let Some(kes_secret_key_period) = kes_secret_key_period {
+            if kes_secret_key_period > relative_kes_period {
+                return Err(anyhow!(
+                    "KES secret key is evolved to period {kes_secret_key_period} which is after the current period {relative_kes_period}"
+                ));
+            }
+        }
+
+        let kes_expiry = KesExpiry::compute(
+            start_kes_period,
+            current_kes_period,
+            self.config.kes_max_evolutions,
+            &self.config.kes_expiry_thresholds(),
+        );
+        if kes_expiry.status == KesExpiryStatus::Expired {
+            return Err(anyhow!(
+                "operational certificate has expired at KES period {}",
+                start_kes_period + self.config.kes_max_evolutions
+            ));
+        }
+
+        Ok(format!(
+            "current KES period is {current_kes_period}, {relative_kes_period} period(s) after the start of the operational certificate, {} period(s) remaining before expiry",
+            kes_expiry.remaining_kes_periods
+        ))
+    }
+
+    async fn check_stake_distribution(&self, party_id: &PartyId) -> StdResult<String> {
+        let stake_distribution = self
+            .chain_observer
+            .get_current_stake_distribution()
+            .await
+            .map_err(|e| anyhow!("could not query the Cardano node: {e}"))?
+            .ok_or_else(|| anyhow!("the Cardano node did not return any stake distribution"))?;
+
+        match stake_distribution.get(party_id) {
+            Some(stake) => Ok(format!("party id '{party_id}' has a stake of {stake}")),
+            None => Err(anyhow!(
+                "party id '{party_id}' is not in the stake distribution"
+            )),
+        }
+    }
+
+    async fn check_era_reader(&self, epoch: Epoch) -> StdResult<String> {
+        let token = self
+            .era_reader
+            .read_era_epoch_token(epoch)
+            .await
+            .with_context(|| "Could not read era markers")?;
+        let current_era = token
+            .get_current_supported_era()
+            .with_context(|| "Current era is not supported by this version of the signer")?;
+
+        match token.get_next_supported_era() {
+            Ok(_) => Ok(format!("current era is '{current_era}'")),
+            Err(_) => Err(anyhow!(
+                "current era is '{current_era}' but the upcoming era is not supported by this version of the signer, please update"
+            )),
+        }
+    }
+
+    async fn check_aggregator(&self) -> StdResult<String> {
+        let features = self
+            .aggregator_client
+            .retrieve_aggregator_features()
+            .await
+            .with_context(|| "Could not retrieve aggregator features")?;
+        let signed_entity_types = features
+            .capabilities
+            .signed_entity_types
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(format!(
+            "reachable, API version {}, signed entity types: {signed_entity_types}",
+            features.open_api_version
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::chain_observer::FakeObserver;
+    use mithril_common::entities::TimePoint;
+    use mithril_common::era::adapters::EraReaderDummyAdapter;
+    use mithril_common::era::EraMarker;
+    use mithril_common::messages::AggregatorFeaturesMessage;
+    use mithril_common::test_utils::{MithrilFixtureBuilder, TempDir};
+
+    use crate::{AggregatorClientError, MockAggregatorClient};
+
+    use super::*;
+
+    fn era_reader_with_current_era() -> EraReader {
+        EraReader::new(Arc::new(EraReaderDummyAdapter::from_markers(vec![
+            EraMarker::new(&SupportedEra::dummy().to_string(), Some(Epoch(0))),
+        ])))
+    }
+
+    fn aggregator_client_returning_features() -> Arc<dyn AggregatorClient> {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_aggregator_features()
+            .returning(|| Ok(AggregatorFeaturesMessage::dummy()));
+        Arc::new(aggregator_client)
+    }
+
+    fn get_status(report: &DoctorReport, name: &str) -> DoctorCheckStatus {
+        report
+            .checks
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("Check '{name}' should be in the report"))
+            .status
+    }
+
+    #[tokio::test]
+    async fn all_checks_pass_with_a_valid_setup() {
+        let temp_dir = TempDir::create("signer_doctor", "all_checks_pass_with_a_valid_setup");
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let opcert_path = temp_dir.join("opcert.cert");
+        signer
+            .signer_with_stake
+            .operational_certificate
+            .clone()
+            .unwrap()
+            .to_file(&opcert_path)
+            .unwrap();
+        let config = Configuration {
+            operational_certificate_path: Some(opcert_path),
+            kes_secret_key_path: signer.kes_secret_key_path.clone(),
+            ..Configuration::new_sample(signer.signer_with_stake.party_id.clone())
+        };
+        let chain_observer = Arc::new(FakeObserver::new(Some(TimePoint::dummy())));
+        chain_observer
+            .set_signers(fixture.signers_with_stake())
+            .await;
+
+        let report = SignerDoctor::new(
+            &config,
+            chain_observer,
+            era_reader_with_current_era(),
+            aggregator_client_returning_features(),
+        )
+        .run()
+        .await;
+
+        assert_eq!(0, report.count_failures(), "{report}");
+        assert!(report
+            .checks
+            .iter()
+            .all(|c| c.status == DoctorCheckStatus::Pass));
+    }
+
+    #[tokio::test]
+    async fn checks_fail_when_party_id_is_not_in_stake_distribution_and_aggregator_is_unreachable()
+    {
+        let config = Configuration {
+            operational_certificate_path: None,
+            kes_secret_key_path: None,
+            ..Configuration::new_sample("unknown-party-id")
+        };
+        let chain_observer = Arc::new(FakeObserver::new(Some(TimePoint::dummy())));
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_aggregator_features()
+            .returning(|| {
+                Err(AggregatorClientError::RemoteServerUnreachable(anyhow!(
+                    "unreachable"
+                )))
+            });
+
+        let report = SignerDoctor::new(
+            &config,
+            chain_observer,
+            era_reader_with_current_era(),
+            Arc::new(aggregator_client),
+        )
+        .run()
+        .await;
+
+        assert_eq!(2, report.count_failures(), "{report}");
+        assert_eq!(
+            DoctorCheckStatus::Fail,
+            get_status(&report, "Stake distribution")
+        );
+        assert_eq!(DoctorCheckStatus::Fail, get_status(&report, "Aggregator"));
+        assert_eq!(
+            DoctorCheckStatus::Skip,
+            get_status(&report, "Operational certificate")
+        );
+        assert_eq!(DoctorCheckStatus::Pass, get_status(&report, "Era reader"));
+    }
+
+    #[tokio::test]
+    async fn operational_certificate_check_fails_when_party_id_does_not_match_configuration() {
+        let temp_dir = TempDir::create(
+            "signer_doctor",
+            "operational_certificate_check_fails_when_party_id_does_not_match_configuration",
+        );
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let opcert_path = temp_dir.join("opcert.cert");
+        signer
+            .signer_with_stake
+            .operational_certificate
+            .clone()
+            .unwrap()
+            .to_file(&opcert_path)
+            .unwrap();
+        let config = Configuration {
+            operational_certificate_path: Some(opcert_path),
+            kes_secret_key_path: None,
+            ..Configuration::new_sample("another-party-id")
+        };
+
+        let report = SignerDoctor::new(
+            &config,
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            era_reader_with_current_era(),
+            aggregator_client_returning_features(),
+        )
+        .run()
+        .await;
+
+        assert_eq!(
+            DoctorCheckStatus::Fail,
+            get_status(&report, "Operational certificate")
+        );
+    }
+
+    #[tokio::test]
+    async fn kes_period_check_fails_when_operational_certificate_has_expired() {
+        let temp_dir = TempDir::create(
+            "signer_doctor",
+            "kes_period_check_fails_when_operational_certificate_has_expired",
+        );
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let opcert_path = temp_dir.join("opcert.cert");
+        signer
+            .signer_with_stake
+            .operational_certificate
+            .clone()
+            .unwrap()
+            .to_file(&opcert_path)
+            .unwrap();
+        let config = Configuration {
+            operational_certificate_path: Some(opcert_path),
+            kes_secret_key_path: None,
+            kes_max_evolutions: 0,
+            ..Configuration::new_sample(signer.signer_with_stake.party_id.clone())
+        };
+
+        let report = SignerDoctor::new(
+            &config,
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            era_reader_with_current_era(),
+            aggregator_client_returning_features(),
+        )
+        .run()
+        .await;
+
+        assert_eq!(
+            DoctorCheckStatus::Fail,
+            get_status(&report, "Cardano node KES period")
+        );
+    }
+
+    #[test]
+    fn display_report() {
+        let report = DoctorReport {
+            checks: vec![
+                DoctorCheck::new("Check A", DoctorCheckStatus::Pass, "ok"),
+                DoctorCheck::new("Check B", DoctorCheckStatus::Fail, "ko"),
+                DoctorCheck::new("Check C", DoctorCheckStatus::Skip, "not configured"),
+            ],
+        };
+
+        assert_eq!(
+            "[PASS] Check A: ok\n[FAIL] Check B: ko\n[SKIP] Check C: not configured\n3 check(s) run, 1 failure(s)",
+            report.to_string()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Commands module
+//!
+//! This module holds the subcommands that can be run by the signer binary in addition to its
+//! main state machine loop.
+
+mod doctor_command;
+mod signature_history_command;
+
+pub use doctor_command::*;
+pub use signature_history_command::*;
@@ -0,0 +1,92 @@
+use anyhow::Context;
+use clap::Parser;
+use slog_scope::debug;
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+
+use crate::database::migration::get_migrations;
+use crate::database::record::SingleSignatureHistoryRecord;
+use crate::database::repository::SingleSignatureHistoryRepository;
+use crate::{Configuration, ProductionServiceBuilder, SQLITE_FILE};
+
+/// List the single signatures sent by the signer to the aggregator, most recent first.
+#[derive(Parser, Debug, Clone)]
+pub struct SignatureHistoryCommand {
+    /// Maximum number of single signatures to list
+    #[clap(long, default_value_t = 20)]
+    limit: usize,
+
+    /// Output the single signatures as JSON
+    #[clap(long)]
+    json: bool,
+}
+
+impl SignatureHistoryCommand {
+    /// Execute the command
+    pub async fn execute(&self, config: &Configuration) -> StdResult<()> {
+        debug!("SIGNATURE HISTORY command"; "config" => format!("{config:?}"));
+        let connection = ProductionServiceBuilder::new(config)
+            .build_sqlite_connection(SQLITE_FILE, get_migrations())
+            .await
+            .with_context(|| "signature-history: could not open the signer database")?;
+        let repository = SingleSignatureHistoryRepository::new(Arc::new(connection));
+        let history = repository
+            .get_latest_single_signatures(self.limit)
+            .with_context(|| "signature-history: could not read the single signatures history")?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&history)?);
+        } else {
+            for record in &history {
+                println!("{}", Self::format_record(record)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn format_record(record: &SingleSignatureHistoryRecord) -> StdResult<String> {
+        let status = match &record.aggregator_error {
+            None => "accepted".to_string(),
+            Some(error) => format!("refused: {error}"),
+        };
+
+        Ok(format!(
+            "{} epoch={} {} beacon={} won_indexes={:?} {}",
+            record.sent_at.to_rfc3339(),
+            record.epoch,
+            record.signed_entity_type,
+            record.signed_entity_type.get_json_beacon()?,
+            record.won_indexes,
+            status
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use mithril_common::entities::{Epoch, SignedEntityType};
+
+    use super::*;
+
+    #[test]
+    fn format_record_of_refused_single_signatures() {
+        let record = SingleSignatureHistoryRecord {
+            epoch: Epoch(7),
+            signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(7)),
+            won_indexes: vec![1, 4],
+            sent_at: DateTime::parse_from_rfc3339("2024-02-12T13:11:47Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            aggregator_error: Some("an error".to_string()),
+        };
+
+        assert_eq!(
+            "2024-02-12T13:11:47+00:00 epoch=7 MithrilStakeDistribution beacon=7 won_indexes=[1, 4] refused: an error",
+            SignatureHistoryCommand::format_record(&record).unwrap()
+        );
+    }
+}
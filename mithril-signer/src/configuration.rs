@@ -15,6 +15,8 @@ use mithril_common::{
     CardanoNetwork, StdResult,
 };
 
+use crate::KesExpiryThresholds;
+
 /// Client configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Documenter)]
 pub struct Configuration {
@@ -76,6 +78,18 @@ pub struct Configuration {
     /// File path to the operational certificate of the pool
     pub operational_certificate_path: Option<PathBuf>,
 
+    /// Maximum number of KES evolutions of an operational certificate, as defined by the
+    /// `maxKESEvolutions` Shelley genesis parameter `[default: 62]`.
+    pub kes_max_evolutions: u64,
+
+    /// Number of remaining KES periods below which a warning is logged to rotate the KES key
+    /// and operational certificate `[default: 14]`.
+    pub kes_expiry_warning_threshold: u64,
+
+    /// Number of remaining KES periods below which a critical warning is logged to rotate the
+    /// KES key and operational certificate `[default: 3]`.
+    pub kes_expiry_critical_threshold: u64,
+
     /// Disable immutables digests cache.
     pub disable_digests_cache: bool,
 
@@ -142,6 +156,9 @@ impl Configuration {
             operational_certificate_path: signer_temp_dir
                 .as_ref()
                 .map(|dir| dir.join("opcert.cert")),
+            kes_max_evolutions: 62,
+            kes_expiry_warning_threshold: 14,
+            kes_expiry_critical_threshold: 3,
             disable_digests_cache: false,
             reset_digests_cache: false,
             era_reader_adapter_type: EraReaderAdapterType::Bootstrap,
@@ -200,6 +217,15 @@ impl Configuration {
             )
         })
     }
+
+    /// Return the thresholds used to warn about the expiry of the KES key and operational
+    /// certificate.
+    pub fn kes_expiry_thresholds(&self) -> KesExpiryThresholds {
+        KesExpiryThresholds {
+            warning: self.kes_expiry_warning_threshold,
+            critical: self.kes_expiry_critical_threshold,
+        }
+    }
 }
 
 /// Default configuration with all the default values for configurations.
@@ -228,6 +254,15 @@ pub struct DefaultConfiguration {
 
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
+
+    /// Maximum number of KES evolutions of an operational certificate
+    pub kes_max_evolutions: u64,
+
+    /// Number of remaining KES periods below which a warning is logged
+    pub kes_expiry_warning_threshold: u64,
+
+    /// Number of remaining KES periods below which a critical warning is logged
+    pub kes_expiry_critical_threshold: u64,
 }
 
 impl DefaultConfiguration {
@@ -247,6 +282,9 @@ impl Default for DefaultConfiguration {
             enable_transaction_pruning: true,
             transactions_import_block_chunk_size: 1500,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
+            kes_max_evolutions: 62, // 62 is the mainnet value
+            kes_expiry_warning_threshold: 14,
+            kes_expiry_critical_threshold: 3,
         }
     }
 }
@@ -283,6 +321,9 @@ impl Source for DefaultConfiguration {
             result,
             myself.cardano_transactions_block_streamer_max_roll_forwards_per_poll
         );
+        insert_default_configuration!(result, myself.kes_max_evolutions);
+        insert_default_configuration!(result, myself.kes_expiry_warning_threshold);
+        insert_default_configuration!(result, myself.kes_expiry_critical_threshold);
 
         Ok(result)
     }
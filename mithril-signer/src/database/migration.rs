@@ -23,5 +23,39 @@ drop table db_version;
 alter table new_db_version rename to db_version;
             ",
         ),
+        // Migration 2
+        // Add the `signed_beacon` table to keep track of the signed entity types already
+        // signed by the signer.
+        SqlMigration::new(
+            2,
+            r"
+create table signed_beacon (
+    epoch integer not null,
+    beacon text not null,
+    signed_entity_type_id integer not null,
+    signed_at text not null,
+    primary key (beacon, signed_entity_type_id)
+);
+create index signed_beacon_epoch on signed_beacon(epoch);
+            ",
+        ),
+        // Migration 3
+        // Add the `single_signature_history` table to keep track of the single signatures
+        // emitted by the signer and of the aggregator response.
+        SqlMigration::new(
+            3,
+            r"
+create table single_signature_history (
+    single_signature_history_id integer primary key autoincrement,
+    epoch integer not null,
+    beacon text not null,
+    signed_entity_type_id integer not null,
+    won_indexes text not null,
+    sent_at text not null,
+    aggregator_error text
+);
+create index single_signature_history_epoch on single_signature_history(epoch);
+            ",
+        ),
     ]
 }
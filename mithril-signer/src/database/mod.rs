@@ -2,6 +2,8 @@
 //! This module contains the entities definition tied with database
 //! representation with their associated providers.
 pub mod migration;
+pub mod query;
+pub mod record;
 pub mod repository;
 #[cfg(test)]
 pub(crate) mod test_helper;
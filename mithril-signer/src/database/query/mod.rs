@@ -0,0 +1,6 @@
+//! Signer related database queries
+mod signed_beacon;
+mod single_signature_history;
+
+pub use signed_beacon::*;
+pub use single_signature_history::*;
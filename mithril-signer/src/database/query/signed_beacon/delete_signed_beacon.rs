@@ -0,0 +1,132 @@
+use sqlite::Value;
+
+use mithril_common::entities::{Epoch, SignedEntityType};
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SignedBeaconRecord;
+
+/// Query to delete old [SignedBeaconRecord] from the sqlite database
+pub struct DeleteSignedBeaconRecordQuery {
+    condition: WhereCondition,
+}
+
+impl DeleteSignedBeaconRecordQuery {
+    /// Create the SQL query to delete the signed beacon of the given signed entity type.
+    pub fn by_signed_entity_type(signed_entity_type: &SignedEntityType) -> StdResult<Self> {
+        Ok(Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and beacon = ?*",
+                vec![
+                    Value::Integer(signed_entity_type.index() as i64),
+                    Value::String(signed_entity_type.get_json_beacon()?),
+                ],
+            ),
+        })
+    }
+
+    /// Create the SQL query to prune data older than the given Epoch.
+    pub fn below_epoch_threshold(epoch_threshold: Epoch) -> StdResult<Self> {
+        Ok(Self {
+            condition: WhereCondition::new(
+                "epoch < ?*",
+                vec![Value::Integer(epoch_threshold.try_into()?)],
+            ),
+        })
+    }
+}
+
+impl Query for DeleteSignedBeaconRecordQuery {
+    type Entity = SignedBeaconRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection()
+            .expand(SourceAlias::new(&[("{:signed_beacon:}", "signed_beacon")]));
+
+        format!("delete from signed_beacon where {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::{GetSignedBeaconQuery, InsertSignedBeaconQuery};
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn test_prune_below_epoch_threshold() {
+        let connection = main_db_connection().unwrap();
+        for epoch in [1, 2, 3] {
+            connection
+                .fetch_first(
+                    InsertSignedBeaconQuery::one(SignedBeaconRecord::new(
+                        SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+                    ))
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+
+        let cursor = connection
+            .fetch(DeleteSignedBeaconRecordQuery::below_epoch_threshold(Epoch(3)).unwrap())
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let remaining_records: Vec<SignedBeaconRecord> = connection
+            .fetch_collect(GetSignedBeaconQuery::all())
+            .unwrap();
+        assert_eq!(
+            vec![Epoch(3)],
+            remaining_records
+                .into_iter()
+                .map(|r| r.epoch)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_delete_by_signed_entity_type() {
+        let connection = main_db_connection().unwrap();
+        for signed_entity_type in [
+            SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+            SignedEntityType::CardanoStakeDistribution(Epoch(1)),
+        ] {
+            connection
+                .fetch_first(
+                    InsertSignedBeaconQuery::one(SignedBeaconRecord::new(signed_entity_type))
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+
+        let cursor = connection
+            .fetch(
+                DeleteSignedBeaconRecordQuery::by_signed_entity_type(
+                    &SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let remaining_records: Vec<SignedBeaconRecord> = connection
+            .fetch_collect(GetSignedBeaconQuery::all())
+            .unwrap();
+        assert_eq!(
+            vec![SignedEntityType::CardanoStakeDistribution(Epoch(1))],
+            remaining_records
+                .into_iter()
+                .map(|r| r.signed_entity_type)
+                .collect::<Vec<_>>()
+        );
+    }
+}
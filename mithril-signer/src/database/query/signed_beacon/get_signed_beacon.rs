@@ -0,0 +1,96 @@
+use sqlite::Value;
+
+use mithril_common::entities::SignedEntityType;
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SignedBeaconRecord;
+
+/// Simple queries to retrieve [SignedBeaconRecord] from the sqlite database.
+pub struct GetSignedBeaconQuery {
+    condition: WhereCondition,
+}
+
+impl GetSignedBeaconQuery {
+    /// Query the signed beacon of the given signed entity type
+    pub fn by_signed_entity_type(signed_entity_type: &SignedEntityType) -> StdResult<Self> {
+        Ok(Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and beacon = ?*",
+                vec![
+                    Value::Integer(signed_entity_type.index() as i64),
+                    Value::String(signed_entity_type.get_json_beacon()?),
+                ],
+            ),
+        })
+    }
+
+    /// Query all the signed beacons
+    #[cfg(test)]
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+}
+
+impl Query for GetSignedBeaconQuery {
+    type Entity = SignedBeaconRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:signed_beacon:}", "sb")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from signed_beacon as sb where {condition} order by rowid desc"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, SignedEntityType};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::InsertSignedBeaconQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn get_signed_beacon_by_signed_entity_type() {
+        let connection = main_db_connection().unwrap();
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(5));
+        let record = SignedBeaconRecord::new(signed_entity_type.clone());
+        connection
+            .fetch_first(InsertSignedBeaconQuery::one(record.clone()).unwrap())
+            .unwrap();
+        connection
+            .fetch_first(
+                InsertSignedBeaconQuery::one(SignedBeaconRecord::new(
+                    SignedEntityType::CardanoStakeDistribution(Epoch(5)),
+                ))
+                .unwrap(),
+            )
+            .unwrap();
+
+        let signed_beacon: Option<SignedBeaconRecord> = connection
+            .fetch_first(GetSignedBeaconQuery::by_signed_entity_type(&signed_entity_type).unwrap())
+            .unwrap();
+        assert_eq!(Some(record), signed_beacon);
+
+        let signed_beacon: Option<SignedBeaconRecord> = connection
+            .fetch_first(
+                GetSignedBeaconQuery::by_signed_entity_type(
+                    &SignedEntityType::MithrilStakeDistribution(Epoch(6)),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(None, signed_beacon);
+    }
+}
@@ -0,0 +1,100 @@
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SignedBeaconRecord;
+
+/// Query to insert [SignedBeaconRecord] in the sqlite database
+pub struct InsertSignedBeaconQuery {
+    condition: WhereCondition,
+}
+
+impl InsertSignedBeaconQuery {
+    /// Insert one signed beacon, replacing it if it was already signed
+    pub fn one(record: SignedBeaconRecord) -> StdResult<Self> {
+        let expression =
+            "(epoch, beacon, signed_entity_type_id, signed_at) values (?*, ?*, ?*, ?*)";
+        let parameters = vec![
+            Value::Integer(record.epoch.try_into()?),
+            Value::String(record.signed_entity_type.get_json_beacon()?),
+            Value::Integer(record.signed_entity_type.index() as i64),
+            Value::String(record.signed_at.to_rfc3339()),
+        ];
+
+        Ok(Self {
+            condition: WhereCondition::new(expression, parameters),
+        })
+    }
+}
+
+impl Query for InsertSignedBeaconQuery {
+    type Entity = SignedBeaconRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection()
+            .expand(SourceAlias::new(&[("{:signed_beacon:}", "signed_beacon")]));
+
+        format!("insert or replace into signed_beacon {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, SignedEntityType};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::GetSignedBeaconQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn insert_signed_beacons() {
+        let connection = main_db_connection().unwrap();
+
+        for signed_entity_type in [
+            SignedEntityType::MithrilStakeDistribution(Epoch(5)),
+            SignedEntityType::CardanoImmutableFilesFull(
+                mithril_common::test_utils::fake_data::beacon(),
+            ),
+        ] {
+            let record = SignedBeaconRecord::new(signed_entity_type);
+            let inserted_record = connection
+                .fetch_first(InsertSignedBeaconQuery::one(record.clone()).unwrap())
+                .unwrap();
+
+            assert_eq!(Some(record), inserted_record);
+        }
+    }
+
+    #[test]
+    fn inserting_the_same_signed_beacon_twice_replaces_it() {
+        let connection = main_db_connection().unwrap();
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(5));
+
+        connection
+            .fetch_first(
+                InsertSignedBeaconQuery::one(SignedBeaconRecord::new(signed_entity_type.clone()))
+                    .unwrap(),
+            )
+            .unwrap();
+        let record = SignedBeaconRecord::new(signed_entity_type);
+        let inserted_record = connection
+            .fetch_first(InsertSignedBeaconQuery::one(record.clone()).unwrap())
+            .unwrap();
+
+        assert_eq!(Some(record), inserted_record);
+        let count = connection
+            .fetch(GetSignedBeaconQuery::all())
+            .unwrap()
+            .count();
+        assert_eq!(1, count);
+    }
+}
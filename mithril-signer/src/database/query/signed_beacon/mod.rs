@@ -0,0 +1,7 @@
+mod delete_signed_beacon;
+mod get_signed_beacon;
+mod insert_signed_beacon;
+
+pub use delete_signed_beacon::*;
+pub use get_signed_beacon::*;
+pub use insert_signed_beacon::*;
@@ -0,0 +1,90 @@
+use sqlite::Value;
+
+use mithril_common::entities::Epoch;
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SingleSignatureHistoryRecord;
+
+/// Query to delete old [SingleSignatureHistoryRecord] from the sqlite database
+pub struct DeleteSingleSignatureHistoryQuery {
+    condition: WhereCondition,
+}
+
+impl DeleteSingleSignatureHistoryQuery {
+    /// Create the SQL query to prune data older than the given Epoch.
+    pub fn below_epoch_threshold(epoch_threshold: Epoch) -> StdResult<Self> {
+        Ok(Self {
+            condition: WhereCondition::new(
+                "epoch < ?*",
+                vec![Value::Integer(epoch_threshold.try_into()?)],
+            ),
+        })
+    }
+}
+
+impl Query for DeleteSingleSignatureHistoryQuery {
+    type Entity = SingleSignatureHistoryRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:single_signature_history:}",
+            "single_signature_history",
+        )]));
+
+        format!("delete from single_signature_history where {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SignedEntityType;
+    use mithril_common::test_utils::fake_data;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::{
+        GetSingleSignatureHistoryQuery, InsertSingleSignatureHistoryQuery,
+    };
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn test_prune_below_epoch_threshold() {
+        let connection = main_db_connection().unwrap();
+        for epoch in [1, 2, 3] {
+            connection
+                .fetch_first(
+                    InsertSingleSignatureHistoryQuery::one(SingleSignatureHistoryRecord::new(
+                        SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+                        &fake_data::single_signatures(vec![1]),
+                        None,
+                    ))
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+
+        let cursor = connection
+            .fetch(DeleteSingleSignatureHistoryQuery::below_epoch_threshold(Epoch(3)).unwrap())
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let remaining_records: Vec<SingleSignatureHistoryRecord> = connection
+            .fetch_collect(GetSingleSignatureHistoryQuery::latest(10))
+            .unwrap();
+        assert_eq!(
+            vec![Epoch(3)],
+            remaining_records
+                .into_iter()
+                .map(|r| r.epoch)
+                .collect::<Vec<_>>()
+        );
+    }
+}
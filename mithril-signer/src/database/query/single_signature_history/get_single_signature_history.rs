@@ -0,0 +1,74 @@
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SingleSignatureHistoryRecord;
+
+/// Query to retrieve [SingleSignatureHistoryRecord] from the sqlite database, most recent first.
+pub struct GetSingleSignatureHistoryQuery {
+    condition: WhereCondition,
+    limit: usize,
+}
+
+impl GetSingleSignatureHistoryQuery {
+    /// Query the last `limit` single signatures sent
+    pub fn latest(limit: usize) -> Self {
+        Self {
+            condition: WhereCondition::default(),
+            limit,
+        }
+    }
+}
+
+impl Query for GetSingleSignatureHistoryQuery {
+    type Entity = SingleSignatureHistoryRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:single_signature_history:}", "ssh")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from single_signature_history as ssh where {condition} order by single_signature_history_id desc limit {}",
+            self.limit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, SignedEntityType};
+    use mithril_common::test_utils::fake_data;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::InsertSingleSignatureHistoryQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn get_latest_single_signatures_most_recent_first() {
+        let connection = main_db_connection().unwrap();
+        let records: Vec<SingleSignatureHistoryRecord> = (1..=3)
+            .map(|epoch| {
+                SingleSignatureHistoryRecord::new(
+                    SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+                    &fake_data::single_signatures(vec![1, 3]),
+                    None,
+                )
+            })
+            .collect();
+        for record in &records {
+            connection
+                .fetch_first(InsertSingleSignatureHistoryQuery::one(record.clone()).unwrap())
+                .unwrap();
+        }
+
+        let history: Vec<SingleSignatureHistoryRecord> = connection
+            .fetch_collect(GetSingleSignatureHistoryQuery::latest(2))
+            .unwrap();
+
+        assert_eq!(vec![records[2].clone(), records[1].clone()], history);
+    }
+}
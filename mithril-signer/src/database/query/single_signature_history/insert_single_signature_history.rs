@@ -0,0 +1,89 @@
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::SingleSignatureHistoryRecord;
+
+/// Query to insert [SingleSignatureHistoryRecord] in the sqlite database
+pub struct InsertSingleSignatureHistoryQuery {
+    condition: WhereCondition,
+}
+
+impl InsertSingleSignatureHistoryQuery {
+    /// Insert one single signature history record
+    pub fn one(record: SingleSignatureHistoryRecord) -> StdResult<Self> {
+        let expression =
+            "(epoch, beacon, signed_entity_type_id, won_indexes, sent_at, aggregator_error) \
+values (?*, ?*, ?*, ?*, ?*, ?*)";
+        let parameters = vec![
+            Value::Integer(record.epoch.try_into()?),
+            Value::String(record.signed_entity_type.get_json_beacon()?),
+            Value::Integer(record.signed_entity_type.index() as i64),
+            Value::String(serde_json::to_string(&record.won_indexes)?),
+            Value::String(record.sent_at.to_rfc3339()),
+            record
+                .aggregator_error
+                .map(Value::String)
+                .unwrap_or(Value::Null),
+        ];
+
+        Ok(Self {
+            condition: WhereCondition::new(expression, parameters),
+        })
+    }
+}
+
+impl Query for InsertSingleSignatureHistoryQuery {
+    type Entity = SingleSignatureHistoryRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:single_signature_history:}",
+            "single_signature_history",
+        )]));
+
+        format!("insert into single_signature_history {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, SignedEntityType};
+    use mithril_common::test_utils::fake_data;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn insert_single_signature_history_records() {
+        let connection = main_db_connection().unwrap();
+
+        for (signed_entity_type, aggregator_error) in [
+            (SignedEntityType::MithrilStakeDistribution(Epoch(5)), None),
+            (
+                SignedEntityType::CardanoImmutableFilesFull(fake_data::beacon()),
+                Some("already registered single signatures".to_string()),
+            ),
+        ] {
+            let record = SingleSignatureHistoryRecord::new(
+                signed_entity_type,
+                &fake_data::single_signatures(vec![2, 5, 8]),
+                aggregator_error,
+            );
+            let inserted_record = connection
+                .fetch_first(InsertSingleSignatureHistoryQuery::one(record.clone()).unwrap())
+                .unwrap();
+
+            assert_eq!(Some(record), inserted_record);
+        }
+    }
+}
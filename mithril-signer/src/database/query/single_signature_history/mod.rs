@@ -0,0 +1,7 @@
+mod delete_single_signature_history;
+mod get_single_signature_history;
+mod insert_single_signature_history;
+
+pub use delete_single_signature_history::*;
+pub use get_single_signature_history::*;
+pub use insert_single_signature_history::*;
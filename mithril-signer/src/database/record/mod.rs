@@ -0,0 +1,7 @@
+//! Signer related database records
+
+mod signed_beacon_record;
+mod single_signature_history_record;
+
+pub use signed_beacon_record::*;
+pub use single_signature_history_record::*;
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use sqlite::Row;
+
+use mithril_common::entities::{Epoch, SignedEntityType};
+use mithril_persistence::database::Hydrator;
+use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// ## SignedBeaconRecord
+///
+/// A signed beacon is a signed entity type for which the signer has already issued and sent its
+/// single signature, or found that it did not win any lottery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedBeaconRecord {
+    /// Epoch of the signed entity type
+    pub epoch: Epoch,
+
+    /// Signed entity type that has been signed
+    pub signed_entity_type: SignedEntityType,
+
+    /// Datetime when the signed entity type was signed
+    pub signed_at: DateTime<Utc>,
+}
+
+impl SignedBeaconRecord {
+    /// Create a new record for the given signed entity type, signed now
+    pub fn new(signed_entity_type: SignedEntityType) -> Self {
+        Self {
+            epoch: signed_entity_type.get_epoch(),
+            signed_entity_type,
+            signed_at: Utc::now(),
+        }
+    }
+}
+
+impl SqLiteEntity for SignedBeaconRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let epoch = row.read::<i64, _>(0);
+        let beacon_str = Hydrator::read_signed_entity_beacon_column(&row, 1);
+        let signed_entity_type_id = usize::try_from(row.read::<i64, _>(2)).map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Integer field signed_beacon.signed_entity_type_id cannot be turned into usize: {e}"
+            ))
+        })?;
+        let datetime = &row.read::<&str, _>(3);
+        let signed_at = DateTime::parse_from_rfc3339(datetime)
+            .map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not turn signed_beacon.signed_at field value '{datetime}' to rfc3339 Datetime. Error: {e}"
+                ))
+            })?
+            .with_timezone(&Utc);
+
+        Ok(Self {
+            epoch: Epoch(u64::try_from(epoch).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Integer field signed_beacon.epoch (value={epoch}) is incompatible with u64 Epoch representation. Error = {e}"
+                ))
+            })?),
+            signed_entity_type: Hydrator::hydrate_signed_entity_type(
+                signed_entity_type_id,
+                &beacon_str,
+            )?,
+            signed_at,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            ("epoch", "{:signed_beacon:}.epoch", "int"),
+            ("beacon", "{:signed_beacon:}.beacon", "text"),
+            (
+                "signed_entity_type_id",
+                "{:signed_beacon:}.signed_entity_type_id",
+                "int",
+            ),
+            ("signed_at", "{:signed_beacon:}.signed_at", "text"),
+        ])
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlite::Row;
+
+use mithril_common::entities::{Epoch, LotteryIndex, SignedEntityType, SingleSignatures};
+use mithril_persistence::database::Hydrator;
+use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// ## SingleSignatureHistoryRecord
+///
+/// Single signatures emitted by the signer and sent to the aggregator, along with the error
+/// returned by the aggregator if it refused them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SingleSignatureHistoryRecord {
+    /// Epoch of the signed entity type
+    pub epoch: Epoch,
+
+    /// Signed entity type that has been signed
+    pub signed_entity_type: SignedEntityType,
+
+    /// Lottery indexes won by the signer
+    pub won_indexes: Vec<LotteryIndex>,
+
+    /// Datetime when the single signatures were sent to the aggregator
+    pub sent_at: DateTime<Utc>,
+
+    /// Error returned by the aggregator, `None` if it accepted the single signatures
+    pub aggregator_error: Option<String>,
+}
+
+impl SingleSignatureHistoryRecord {
+    /// Create a new record for the given single signatures, sent now
+    pub fn new(
+        signed_entity_type: SignedEntityType,
+        single_signatures: &SingleSignatures,
+        aggregator_error: Option<String>,
+    ) -> Self {
+        Self {
+            epoch: signed_entity_type.get_epoch(),
+            signed_entity_type,
+            won_indexes: single_signatures.won_indexes.clone(),
+            sent_at: Utc::now(),
+            aggregator_error,
+        }
+    }
+}
+
+impl SqLiteEntity for SingleSignatureHistoryRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let epoch = row.read::<i64, _>(0);
+        let beacon_str = Hydrator::read_signed_entity_beacon_column(&row, 1);
+        let signed_entity_type_id = usize::try_from(row.read::<i64, _>(2)).map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Integer field single_signature_history.signed_entity_type_id cannot be turned into usize: {e}"
+            ))
+        })?;
+        let won_indexes_str = row.read::<&str, _>(3);
+        let won_indexes = serde_json::from_str(won_indexes_str).map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Could not turn single_signature_history.won_indexes field value '{won_indexes_str}' to a list of lottery indexes. Error: {e}"
+            ))
+        })?;
+        let datetime = &row.read::<&str, _>(4);
+        let sent_at = DateTime::parse_from_rfc3339(datetime)
+            .map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not turn single_signature_history.sent_at field value '{datetime}' to rfc3339 Datetime. Error: {e}"
+                ))
+            })?
+            .with_timezone(&Utc);
+        let aggregator_error = row.read::<Option<&str>, _>(5).map(|e| e.to_string());
+
+        Ok(Self {
+            epoch: Epoch(u64::try_from(epoch).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Integer field single_signature_history.epoch (value={epoch}) is incompatible with u64 Epoch representation. Error = {e}"
+                ))
+            })?),
+            signed_entity_type: Hydrator::hydrate_signed_entity_type(
+                signed_entity_type_id,
+                &beacon_str,
+            )?,
+            won_indexes,
+            sent_at,
+            aggregator_error,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            ("epoch", "{:single_signature_history:}.epoch", "int"),
+            ("beacon", "{:single_signature_history:}.beacon", "text"),
+            (
+                "signed_entity_type_id",
+                "{:single_signature_history:}.signed_entity_type_id",
+                "int",
+            ),
+            (
+                "won_indexes",
+                "{:single_signature_history:}.won_indexes",
+                "text",
+            ),
+            ("sent_at", "{:single_signature_history:}.sent_at", "text"),
+            (
+                "aggregator_error",
+                "{:single_signature_history:}.aggregator_error",
+                "text",
+            ),
+        ])
+    }
+}
@@ -1,3 +1,8 @@
 //! Signer related database repositories
 
 mod cardano_transaction_repository;
+mod signed_beacon_repository;
+mod single_signature_history_repository;
+
+pub use signed_beacon_repository::*;
+pub use single_signature_history_repository::*;
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use mithril_common::entities::{Epoch, SignedEntityType};
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+use crate::database::query::{
+    DeleteSignedBeaconRecordQuery, GetSignedBeaconQuery, InsertSignedBeaconQuery,
+};
+use crate::database::record::SignedBeaconRecord;
+use crate::SignedBeaconStore;
+
+/// A [SignedBeaconStore] implementation using SQLite.
+pub struct SignedBeaconRepository {
+    connection: Arc<SqliteConnection>,
+    max_number_of_epochs_to_store: Option<u64>,
+}
+
+impl SignedBeaconRepository {
+    /// Create a new instance of the `SignedBeaconRepository`, if set the signed beacons older
+    /// than `max_number_of_epochs_to_store` epochs are pruned each time a beacon is signed.
+    pub fn new(
+        connection: Arc<SqliteConnection>,
+        max_number_of_epochs_to_store: Option<u64>,
+    ) -> Self {
+        Self {
+            connection,
+            max_number_of_epochs_to_store,
+        }
+    }
+
+    fn prune_below_epoch_threshold(&self, record: &SignedBeaconRecord) -> StdResult<()> {
+        if let Some(threshold) = self
+            .max_number_of_epochs_to_store
+            .and_then(|max_epochs| record.epoch.checked_sub(max_epochs))
+        {
+            let _ = self
+                .connection
+                .fetch(DeleteSignedBeaconRecordQuery::below_epoch_threshold(
+                    Epoch(threshold),
+                )?)?
+                .count();
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SignedBeaconStore for SignedBeaconRepository {
+    async fn has_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<bool> {
+        let record = self
+            .connection
+            .fetch_first(GetSignedBeaconQuery::by_signed_entity_type(
+                signed_entity_type,
+            )?)?;
+
+        Ok(record.is_some())
+    }
+
+    async fn mark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()> {
+        let record = SignedBeaconRecord::new(signed_entity_type.clone());
+        self.connection
+            .fetch_first(InsertSignedBeaconQuery::one(record.clone())?)?;
+        self.prune_below_epoch_threshold(&record)?;
+
+        Ok(())
+    }
+
+    async fn unmark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()> {
+        let _ = self
+            .connection
+            .fetch(DeleteSignedBeaconRecordQuery::by_signed_entity_type(
+                signed_entity_type,
+            )?)?
+            .count();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mark_as_signed_then_has_signed() {
+        let repository = SignedBeaconRepository::new(Arc::new(main_db_connection().unwrap()), None);
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(3));
+
+        assert!(!repository.has_signed(&signed_entity_type).await.unwrap());
+
+        repository
+            .mark_as_signed(&signed_entity_type)
+            .await
+            .unwrap();
+
+        assert!(repository.has_signed(&signed_entity_type).await.unwrap());
+        assert!(!repository
+            .has_signed(&SignedEntityType::CardanoStakeDistribution(Epoch(3)))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn unmark_as_signed_then_has_not_signed() {
+        let repository = SignedBeaconRepository::new(Arc::new(main_db_connection().unwrap()), None);
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(3));
+        repository
+            .mark_as_signed(&signed_entity_type)
+            .await
+            .unwrap();
+
+        repository
+            .unmark_as_signed(&signed_entity_type)
+            .await
+            .unwrap();
+
+        assert!(!repository.has_signed(&signed_entity_type).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn mark_as_signed_prune_signed_beacons_older_than_the_retention_limit() {
+        let repository =
+            SignedBeaconRepository::new(Arc::new(main_db_connection().unwrap()), Some(2));
+        for epoch in [1, 2, 3, 4] {
+            repository
+                .mark_as_signed(&SignedEntityType::MithrilStakeDistribution(Epoch(epoch)))
+                .await
+                .unwrap();
+        }
+
+        for (epoch, expected) in [(1, false), (2, true), (3, true), (4, true)] {
+            assert_eq!(
+                expected,
+                repository
+                    .has_signed(&SignedEntityType::MithrilStakeDistribution(Epoch(epoch)))
+                    .await
+                    .unwrap(),
+                "unexpected signed status for epoch {epoch}"
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use mithril_common::entities::{Epoch, SignedEntityType, SingleSignatures};
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+use crate::database::query::{
+    DeleteSingleSignatureHistoryQuery, GetSingleSignatureHistoryQuery,
+    InsertSingleSignatureHistoryQuery,
+};
+use crate::database::record::SingleSignatureHistoryRecord;
+
+/// ## Single signature history repository
+///
+/// Keep track of the single signatures sent to the aggregator so operators can audit the
+/// participation of their signer.
+pub struct SingleSignatureHistoryRepository {
+    connection: Arc<SqliteConnection>,
+}
+
+impl SingleSignatureHistoryRepository {
+    /// Create a new instance of the `SingleSignatureHistoryRepository`
+    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+        Self { connection }
+    }
+
+    /// Record the single signatures sent for the given signed entity type, along with the error
+    /// returned by the aggregator if it refused them.
+    pub fn record_single_signatures(
+        &self,
+        signed_entity_type: &SignedEntityType,
+        single_signatures: &SingleSignatures,
+        aggregator_error: Option<String>,
+    ) -> StdResult<()> {
+        let record = SingleSignatureHistoryRecord::new(
+            signed_entity_type.clone(),
+            single_signatures,
+            aggregator_error,
+        );
+        self.connection
+            .fetch_first(InsertSingleSignatureHistoryQuery::one(record)?)?;
+
+        Ok(())
+    }
+
+    /// Return the last `limit` single signatures sent, most recent first.
+    pub fn get_latest_single_signatures(
+        &self,
+        limit: usize,
+    ) -> StdResult<Vec<SingleSignatureHistoryRecord>> {
+        self.connection
+            .fetch_collect(GetSingleSignatureHistoryQuery::latest(limit))
+    }
+
+    /// Prune the single signatures older than `max_number_of_epochs_to_store` epochs before the
+    /// latest epoch recorded, returns the number of records removed.
+    pub fn prune(&self, max_number_of_epochs_to_store: u64) -> StdResult<usize> {
+        let latest_epoch = self
+            .connection
+            .fetch_first(GetSingleSignatureHistoryQuery::latest(1))?
+            .map(|record| record.epoch);
+
+        match latest_epoch.and_then(|epoch| epoch.checked_sub(max_number_of_epochs_to_store)) {
+            Some(threshold) => {
+                let cursor = self.connection.fetch(
+                    DeleteSingleSignatureHistoryQuery::below_epoch_threshold(Epoch(threshold))?,
+                )?;
+
+                Ok(cursor.count())
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::fake_data;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    fn record_for_epochs(repository: &SingleSignatureHistoryRepository, epochs: &[u64]) {
+        for epoch in epochs {
+            repository
+                .record_single_signatures(
+                    &SignedEntityType::MithrilStakeDistribution(Epoch(*epoch)),
+                    &fake_data::single_signatures(vec![1, 4]),
+                    None,
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn record_then_get_latest_single_signatures() {
+        let repository =
+            SingleSignatureHistoryRepository::new(Arc::new(main_db_connection().unwrap()));
+        let signed_entity_type = SignedEntityType::CardanoStakeDistribution(Epoch(4));
+
+        repository
+            .record_single_signatures(
+                &signed_entity_type,
+                &fake_data::single_signatures(vec![3, 7]),
+                Some("an error".to_string()),
+            )
+            .unwrap();
+
+        let history = repository.get_latest_single_signatures(10).unwrap();
+        assert_eq!(1, history.len());
+        assert_eq!(signed_entity_type, history[0].signed_entity_type);
+        assert_eq!(vec![3, 7], history[0].won_indexes);
+        assert_eq!(Some("an error".to_string()), history[0].aggregator_error);
+    }
+
+    #[test]
+    fn prune_single_signatures_older_than_the_retention_limit() {
+        let repository =
+            SingleSignatureHistoryRepository::new(Arc::new(main_db_connection().unwrap()));
+        record_for_epochs(&repository, &[1, 2, 3, 4, 5]);
+
+        let pruned = repository.prune(2).unwrap();
+
+        assert_eq!(2, pruned);
+        let epochs: Vec<Epoch> = repository
+            .get_latest_single_signatures(10)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.epoch)
+            .collect();
+        assert_eq!(vec![Epoch(5), Epoch(4), Epoch(3)], epochs);
+    }
+
+    #[test]
+    fn prune_does_nothing_when_there_are_less_epochs_than_the_retention_limit() {
+        let repository =
+            SingleSignatureHistoryRepository::new(Arc::new(main_db_connection().unwrap()));
+        record_for_epochs(&repository, &[1, 2]);
+
+        let pruned = repository.prune(5).unwrap();
+
+        assert_eq!(0, pruned);
+        assert_eq!(
+            2,
+            repository.get_latest_single_signatures(10).unwrap().len()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use slog_scope::{crit, info, warn};
+
+use mithril_common::crypto_helper::KESPeriod;
+
+/// Thresholds, in number of remaining KES periods, below which the signer warns that its KES
+/// key and operational certificate are close to expiry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KesExpiryThresholds {
+    /// Number of remaining KES periods below which a warning is logged.
+    pub warning: u64,
+
+    /// Number of remaining KES periods below which a critical warning is logged.
+    pub critical: u64,
+}
+
+/// Expiry status of the KES key and operational certificate of the signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KesExpiryStatus {
+    /// Enough KES periods remain before expiry.
+    Valid,
+
+    /// The number of remaining KES periods is below the warning threshold.
+    Warning,
+
+    /// The number of remaining KES periods is below the critical threshold.
+    Critical,
+
+    /// The operational certificate has expired, the signer can no longer sign.
+    Expired,
+}
+
+/// Remaining validity of the KES key and operational certificate of the signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KesExpiry {
+    /// Number of KES periods, including the current one, before the operational certificate
+    /// expires.
+    pub remaining_kes_periods: u64,
+
+    /// Expiry status derived from the thresholds.
+    pub status: KesExpiryStatus,
+}
+
+impl KesExpiry {
+    /// Compute the remaining validity of an operational certificate starting at the given KES
+    /// period, which is valid for `max_kes_evolutions` periods.
+    pub fn compute(
+        start_kes_period: u64,
+        current_kes_period: KESPeriod,
+        max_kes_evolutions: u64,
+        thresholds: &KesExpiryThresholds,
+    ) -> Self {
+        let remaining_kes_periods =
+            (start_kes_period + max_kes_evolutions).saturating_sub(current_kes_period as u64);
+        let status = if remaining_kes_periods == 0 {
+            KesExpiryStatus::Expired
+        } else if remaining_kes_periods < thresholds.critical {
+            KesExpiryStatus::Critical
+        } else if remaining_kes_periods < thresholds.warning {
+            KesExpiryStatus::Warning
+        } else {
+            KesExpiryStatus::Valid
+        };
+
+        Self {
+            remaining_kes_periods,
+            status,
+        }
+    }
+
+    /// Log the expiry with a level matching its status.
+    pub fn log(&self) {
+        let remaining_kes_periods = self.remaining_kes_periods;
+        match self.status {
+            KesExpiryStatus::Valid => {
+                info!("KES key and operational certificate are valid for {remaining_kes_periods} more KES period(s)");
+            }
+            KesExpiryStatus::Warning => {
+                warn!("KES key and operational certificate will expire in {remaining_kes_periods} KES period(s), they should be rotated soon");
+            }
+            KesExpiryStatus::Critical => {
+                crit!("KES key and operational certificate will expire in {remaining_kes_periods} KES period(s), they must be rotated now or the signer will stop signing");
+            }
+            KesExpiryStatus::Expired => {
+                crit!("KES key and operational certificate have expired, the signer can not sign until they are rotated");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: KesExpiryThresholds = KesExpiryThresholds {
+        warning: 10,
+        critical: 3,
+    };
+
+    #[test]
+    fn compute_remaining_kes_periods_from_start_of_operational_certificate() {
+        let expiry = KesExpiry::compute(100, 120, 62, &THRESHOLDS);
+
+        assert_eq!(42, expiry.remaining_kes_periods);
+        assert_eq!(KesExpiryStatus::Valid, expiry.status);
+    }
+
+    #[test]
+    fn status_escalates_when_crossing_thresholds() {
+        let status_at = |current_kes_period| {
+            KesExpiry::compute(100, current_kes_period, 62, &THRESHOLDS).status
+        };
+
+        assert_eq!(KesExpiryStatus::Valid, status_at(152));
+        assert_eq!(KesExpiryStatus::Warning, status_at(153));
+        assert_eq!(KesExpiryStatus::Warning, status_at(159));
+        assert_eq!(KesExpiryStatus::Critical, status_at(160));
+        assert_eq!(KesExpiryStatus::Critical, status_at(161));
+        assert_eq!(KesExpiryStatus::Expired, status_at(162));
+    }
+
+    #[test]
+    fn remaining_kes_periods_does_not_underflow_after_expiry() {
+        let expiry = KesExpiry::compute(100, 200, 62, &THRESHOLDS);
+
+        assert_eq!(0, expiry.remaining_kes_periods);
+        assert_eq!(KesExpiryStatus::Expired, expiry.status);
+    }
+}
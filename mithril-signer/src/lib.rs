@@ -9,12 +9,16 @@
 mod aggregator_client;
 mod cardano_transactions_importer;
 mod cardano_transactions_preloader_checker;
+mod commands;
 mod configuration;
 pub mod database;
+mod kes_expiry;
 mod message_adapters;
 pub mod metrics;
 mod protocol_initializer_store;
 mod runtime;
+mod signed_beacon_store;
+mod signer_keys_watcher;
 mod single_signer;
 mod transactions_importer_by_chunk;
 mod transactions_importer_with_pruner;
@@ -26,13 +30,17 @@ pub use aggregator_client::dumb::DumbAggregatorClient;
 pub use aggregator_client::*;
 pub use cardano_transactions_importer::*;
 pub use cardano_transactions_preloader_checker::*;
+pub use commands::*;
 pub use configuration::{Configuration, DefaultConfiguration};
+pub use kes_expiry::*;
 pub use message_adapters::{
     FromEpochSettingsAdapter, FromPendingCertificateMessageAdapter, ToRegisterSignerMessageAdapter,
 };
 pub use metrics::*;
 pub use protocol_initializer_store::{ProtocolInitializerStore, ProtocolInitializerStorer};
 pub use runtime::*;
+pub use signed_beacon_store::*;
+pub use signer_keys_watcher::*;
 pub use single_signer::*;
 pub use transactions_importer_by_chunk::*;
 pub use transactions_importer_with_pruner::*;
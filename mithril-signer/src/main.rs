@@ -3,7 +3,7 @@ use clap::{CommandFactory, Parser, Subcommand};
 use config::{Map, Value};
 
 use slog::{o, Drain, Level, Logger};
-use slog_scope::{crit, debug};
+use slog_scope::{crit, debug, info};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -16,8 +16,8 @@ use tokio::{
 use mithril_common::StdResult;
 use mithril_doc::{Documenter, DocumenterDefault, GenerateDocCommands, StructDoc};
 use mithril_signer::{
-    Configuration, DefaultConfiguration, MetricsServer, ProductionServiceBuilder, ServiceBuilder,
-    SignerRunner, SignerState, StateMachine,
+    Configuration, DefaultConfiguration, DoctorCommand, MetricsServer, ProductionServiceBuilder,
+    ServiceBuilder, SignatureHistoryCommand, SignerRunner, SignerState, StateMachine,
 };
 
 /// CLI args
@@ -109,6 +109,12 @@ fn build_logger(min_level: Level) -> Logger {
 enum SignerCommands {
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
+
+    /// List the single signatures sent to the aggregator
+    SignatureHistory(SignatureHistoryCommand),
+
+    /// Check the signer setup and print a pass/fail report
+    Doctor(DoctorCommand),
 }
 
 #[tokio::main]
@@ -158,12 +164,19 @@ async fn main() -> StdResult<()> {
         .try_deserialize()
         .with_context(|| "configuration deserialize error")?;
 
+    match &args.command {
+        Some(SignerCommands::SignatureHistory(cmd)) => return cmd.execute(&config).await,
+        Some(SignerCommands::Doctor(cmd)) => return cmd.execute(&config).await,
+        _ => {}
+    }
+
     let services = ProductionServiceBuilder::new(&config)
         .build()
         .await
         .with_context(|| "services initialization error")?;
 
     let metrics_service = services.metrics_service.clone();
+    let signer_keys_watcher = services.signer_keys_watcher.clone();
     let cardano_transaction_preloader = services.cardano_transactions_preloader.clone();
 
     debug!("Started"; "run_mode" => &args.run_mode, "config" => format!("{config:?}"));
@@ -226,6 +239,16 @@ async fn main() -> StdResult<()> {
             .map(|_| Some("Received SIGQUIT".to_string()))
     });
 
+    join_set.spawn(async move {
+        let mut sighup = signal(SignalKind::hangup()).expect("Failed to create SIGHUP signal");
+        while sighup.recv().await.is_some() {
+            info!("Received SIGHUP, signer keys will be reloaded");
+            signer_keys_watcher.request_reload();
+        }
+
+        Err(anyhow!("Failed to receive SIGHUP"))
+    });
+
     let shutdown_reason = match join_set.join_next().await {
         Some(Err(e)) => {
             crit!("A critical error occurred: {e:?}");
@@ -62,3 +62,9 @@ pub const RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_NAME: &str =
 /// 'runtime_cycle_total_since_startup' metric help
 pub const RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_HELP: &str =
     "Number of runtime cycles since startup on a Mithril signer node";
+
+/// 'kes_remaining_periods' metric name
+pub const KES_REMAINING_PERIODS_METRIC_NAME: &str = "mithril_signer_kes_remaining_periods";
+/// 'kes_remaining_periods' metric help
+pub const KES_REMAINING_PERIODS_METRIC_HELP: &str =
+    "Number of KES periods remaining before the KES key and operational certificate expire on a Mithril signer node";
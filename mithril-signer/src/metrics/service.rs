@@ -3,6 +3,7 @@ use prometheus::{Counter, Encoder, Gauge, Opts, Registry, TextEncoder};
 use slog_scope::debug;
 
 use super::{
+    KES_REMAINING_PERIODS_METRIC_HELP, KES_REMAINING_PERIODS_METRIC_NAME,
     RUNTIME_CYCLE_SUCCESS_SINCE_STARTUP_METRIC_HELP,
     RUNTIME_CYCLE_SUCCESS_SINCE_STARTUP_METRIC_NAME, RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_HELP,
     RUNTIME_CYCLE_TOTAL_SINCE_STARTUP_METRIC_NAME,
@@ -37,6 +38,7 @@ pub struct MetricsService {
     signature_registration_success_last_epoch_gauge: Box<Gauge>,
     runtime_cycle_success_since_startup_counter: Box<Counter>,
     runtime_cycle_total_since_startup_counter: Box<Counter>,
+    kes_remaining_periods_gauge: Box<Gauge>,
 }
 
 impl MetricsService {
@@ -99,6 +101,13 @@ impl MetricsService {
         )?);
         registry.register(runtime_cycle_total_since_startup_counter.clone())?;
 
+        // KES metrics
+        let kes_remaining_periods_gauge = Box::new(Self::create_metric_gauge(
+            KES_REMAINING_PERIODS_METRIC_NAME,
+            KES_REMAINING_PERIODS_METRIC_HELP,
+        )?);
+        registry.register(kes_remaining_periods_gauge.clone())?;
+
         Ok(Self {
             registry,
             signer_registration_success_since_startup_counter,
@@ -109,6 +118,7 @@ impl MetricsService {
             signature_registration_success_last_epoch_gauge,
             runtime_cycle_success_since_startup_counter,
             runtime_cycle_total_since_startup_counter,
+            kes_remaining_periods_gauge,
         })
     }
 
@@ -248,6 +258,17 @@ impl MetricsService {
             .get()
             .round() as CounterValue
     }
+
+    /// Set the `kes_remaining_periods` gauge value.
+    pub fn kes_remaining_periods_gauge_set(&self, value: u64) {
+        debug!("MetricsService: set 'kes_remaining_periods' gauge value to {value}");
+        self.kes_remaining_periods_gauge.set(value as f64);
+    }
+
+    /// Get the `kes_remaining_periods` gauge value.
+    pub fn kes_remaining_periods_gauge_get(&self) -> u64 {
+        self.kes_remaining_periods_gauge.get().round() as u64
+    }
 }
 
 #[cfg(test)]
@@ -275,6 +296,10 @@ mod tests {
         let parsed_metrics = parse_metrics(&exported_metrics).unwrap();
 
         let parsed_metrics_expected = BTreeMap::from([
+            (
+                KES_REMAINING_PERIODS_METRIC_NAME.to_string(),
+                Value::Gauge(0.0),
+            ),
             (
                 RUNTIME_CYCLE_SUCCESS_SINCE_STARTUP_METRIC_NAME.to_string(),
                 Value::Counter(0.0),
@@ -430,4 +455,13 @@ mod tests {
             metrics_service.runtime_cycle_total_since_startup_counter_get(),
         );
     }
+
+    #[test]
+    fn test_kes_remaining_periods_gauge_set() {
+        let metrics_service = MetricsService::new().unwrap();
+        assert_eq!(0, metrics_service.kes_remaining_periods_gauge_get());
+
+        metrics_service.kes_remaining_periods_gauge_set(42);
+        assert_eq!(42, metrics_service.kes_remaining_periods_gauge_get());
+    }
 }
@@ -14,7 +14,7 @@ use mithril_common::entities::{
 use mithril_common::StdResult;
 use mithril_persistence::store::StakeStorer;
 
-use crate::{Configuration, MithrilProtocolInitializerBuilder};
+use crate::{Configuration, KesExpiry, MithrilProtocolInitializerBuilder};
 
 use super::signer_services::SignerServices;
 
@@ -24,8 +24,18 @@ pub trait Runner: Send + Sync {
     /// Fetch the current epoch settings if any.
     async fn get_epoch_settings(&self) -> StdResult<Option<EpochSettings>>;
 
-    /// Fetch the current pending certificate if any.
-    async fn get_pending_certificate(&self) -> StdResult<Option<CertificatePending>>;
+    /// Fetch the signing opportunities currently open on the aggregator, as one pending
+    /// certificate per signed entity type.
+    async fn get_signing_opportunities(&self) -> StdResult<Vec<CertificatePending>>;
+
+    /// Check if the given signed entity type has already been signed.
+    async fn has_already_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<bool>;
+
+    /// Record that the given signed entity type has been signed, so it is never signed twice.
+    async fn mark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()>;
+
+    /// Remove the signed mark of the given signed entity type, so it can be signed again.
+    async fn unmark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()>;
 
     /// Fetch the current time point from the Cardano node.
     async fn get_current_time_point(&self) -> StdResult<TimePoint>;
@@ -37,6 +47,10 @@ pub trait Runner: Send + Sync {
         protocol_parameters: &ProtocolParameters,
     ) -> StdResult<()>;
 
+    /// Check if the KES secret key or the operational certificate have been rotated since the
+    /// last registration, the rotated keys being valid.
+    async fn has_signer_keys_rotated(&self) -> StdResult<bool>;
+
     /// Read the stake distribution and store it.
     async fn update_stake_distribution(&self, epoch: Epoch) -> StdResult<()>;
 
@@ -108,6 +122,21 @@ impl SignerRunner {
     pub fn new(config: Configuration, services: SignerServices) -> Self {
         Self { services, config }
     }
+
+    /// Log the remaining validity of the KES key and operational certificate and expose it as
+    /// a metric, so that they can be rotated before the signer stops signing.
+    fn check_kes_expiry(&self, operational_certificate: &OpCert, current_kes_period: KESPeriod) {
+        let kes_expiry = KesExpiry::compute(
+            operational_certificate.start_kes_period,
+            current_kes_period,
+            self.config.kes_max_evolutions,
+            &self.config.kes_expiry_thresholds(),
+        );
+        kes_expiry.log();
+        self.services
+            .metrics_service
+            .kes_remaining_periods_gauge_set(kes_expiry.remaining_kes_periods);
+    }
 }
 
 #[cfg_attr(test, automock)]
@@ -123,14 +152,68 @@ impl Runner for SignerRunner {
             .map_err(|e| e.into())
     }
 
-    async fn get_pending_certificate(&self) -> StdResult<Option<CertificatePending>> {
-        debug!("RUNNER: get_pending_certificate");
+    async fn get_signing_opportunities(&self) -> StdResult<Vec<CertificatePending>> {
+        debug!("RUNNER: get_signing_opportunities");
 
-        self.services
+        let Some(pending_certificate) = self
+            .services
             .certificate_handler
             .retrieve_pending_certificate()
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+
+        match self
+            .services
+            .certificate_handler
+            .retrieve_signing_opportunities()
+            .await?
+        {
+            // The signers of the pending certificate are the ones of all the messages opened
+            // during its epoch
+            Some(signing_opportunities) => Ok(signing_opportunities
+                .into_iter()
+                .filter(|opportunity| opportunity.epoch == pending_certificate.epoch)
+                .map(|opportunity| CertificatePending {
+                    signed_entity_type: opportunity.signed_entity_type,
+                    ..pending_certificate.clone()
+                })
+                .collect()),
+            None => {
+                debug!(
+                    "RUNNER: signing opportunities not supported, using the pending certificate"
+                );
+                Ok(vec![pending_certificate])
+            }
+        }
+    }
+
+    async fn has_already_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<bool> {
+        debug!("RUNNER: has_already_signed"; "signed_entity_type" => ?signed_entity_type);
+
+        self.services
+            .signed_beacon_store
+            .has_signed(signed_entity_type)
+            .await
+    }
+
+    async fn mark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()> {
+        debug!("RUNNER: mark_as_signed"; "signed_entity_type" => ?signed_entity_type);
+
+        self.services
+            .signed_beacon_store
+            .mark_as_signed(signed_entity_type)
+            .await
+    }
+
+    async fn unmark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()> {
+        debug!("RUNNER: unmark_as_signed"; "signed_entity_type" => ?signed_entity_type);
+
+        self.services
+            .signed_beacon_store
+            .unmark_as_signed(signed_entity_type)
             .await
-            .map_err(|e| e.into())
     }
 
     async fn get_current_time_point(&self) -> StdResult<TimePoint> {
@@ -150,6 +233,9 @@ impl Runner for SignerRunner {
     ) -> StdResult<()> {
         debug!("RUNNER: register_signer_to_aggregator");
 
+        // Computed before reading the key files so that a rotation happening while registering
+        // is detected afterward.
+        let signer_keys_fingerprint = self.services.signer_keys_watcher.compute_fingerprint()?;
         let epoch_offset_to_recording_epoch = epoch.offset_to_recording_epoch();
         let stake_distribution = self
             .services
@@ -180,14 +266,21 @@ impl Runner for SignerRunner {
         };
 
         let kes_period = match operational_certificate {
-            Some(operational_certificate) => Some(
-                self.services
+            Some(operational_certificate) => {
+                let current_kes_period = self
+                    .services
                     .chain_observer
                     .get_current_kes_period(&operational_certificate)
-                    .await?
-                    .unwrap_or_default()
-                    - operational_certificate.start_kes_period as KESPeriod,
-            ),
+                    .await?;
+                if let Some(current_kes_period) = current_kes_period {
+                    self.check_kes_expiry(&operational_certificate, current_kes_period);
+                }
+
+                Some(
+                    current_kes_period.unwrap_or_default()
+                        - operational_certificate.start_kes_period as KESPeriod,
+                )
+            }
             None => None,
         };
         let protocol_initializer = MithrilProtocolInitializerBuilder::build(
@@ -211,10 +304,21 @@ impl Runner for SignerRunner {
             .protocol_initializer_store
             .save_protocol_initializer(epoch_offset_to_recording_epoch, protocol_initializer)
             .await?;
+        self.services
+            .signer_keys_watcher
+            .mark_as_registered(signer_keys_fingerprint);
 
         Ok(())
     }
 
+    async fn has_signer_keys_rotated(&self) -> StdResult<bool> {
+        debug!("RUNNER: has_signer_keys_rotated");
+
+        self.services
+            .signer_keys_watcher
+            .detect_rotation(&self.services.single_signer.get_party_id())
+    }
+
     async fn update_stake_distribution(&self, epoch: Epoch) -> StdResult<()> {
         debug!("RUNNER: update_stake_distribution");
 
@@ -416,10 +520,26 @@ impl Runner for SignerRunner {
         if let Some(single_signatures) = maybe_signature {
             debug!(" > there is a single signature to send");
 
-            self.services
+            let register_result = self
+                .services
                 .certificate_handler
                 .register_signatures(signed_entity_type, &single_signatures)
-                .await?;
+                .await
+                .map_err(anyhow::Error::from);
+
+            if let Err(error) = self
+                .services
+                .single_signature_history_repository
+                .record_single_signatures(
+                    signed_entity_type,
+                    &single_signatures,
+                    register_result.as_ref().err().map(|e| format!("{e:#}")),
+                )
+            {
+                warn!("Could not record the single signatures in the history"; "error" => ?error);
+            }
+
+            register_result?;
 
             Ok(())
         } else {
@@ -476,6 +596,7 @@ mod tests {
         digesters::{DumbImmutableDigester, DumbImmutableFileObserver},
         entities::{BlockNumber, BlockRange, CardanoDbBeacon, Epoch, StakeDistribution},
         era::{adapters::EraReaderBootstrapAdapter, EraChecker, EraReader},
+        messages::SigningOpportunityListItemMessage,
         signable_builder::{
             BlockRangeRootRetriever, CardanoImmutableFilesFullSignableBuilder,
             CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
@@ -490,10 +611,13 @@ mod tests {
     use mockall::mock;
     use std::{path::Path, sync::Arc};
 
+    use crate::database::repository::{SignedBeaconRepository, SingleSignatureHistoryRepository};
+    use crate::database::test_helper::main_db_connection;
     use crate::{
-        metrics::MetricsService, AggregatorClient, CardanoTransactionsImporter,
-        DumbAggregatorClient, MithrilSingleSigner, MockAggregatorClient, MockTransactionStore,
-        MockUpkeepService, ProtocolInitializerStore, SingleSigner,
+        metrics::MetricsService, AggregatorClient, AggregatorClientError,
+        CardanoTransactionsImporter, DumbAggregatorClient, MithrilSingleSigner,
+        MockAggregatorClient, MockTransactionStore, MockUpkeepService, ProtocolInitializerStore,
+        SignerKeysWatcher, SingleSigner,
     };
 
     use super::*;
@@ -604,6 +728,13 @@ mod tests {
                 Box::new(adapter),
                 None,
             )),
+            signed_beacon_store: Arc::new(SignedBeaconRepository::new(
+                Arc::new(main_db_connection().unwrap()),
+                None,
+            )),
+            single_signature_history_repository: Arc::new(SingleSignatureHistoryRepository::new(
+                Arc::new(main_db_connection().unwrap()),
+            )),
             era_checker,
             era_reader,
             api_version_provider,
@@ -612,6 +743,7 @@ mod tests {
             signed_entity_type_lock,
             cardano_transactions_preloader,
             upkeep_service,
+            signer_keys_watcher: Arc::new(SignerKeysWatcher::new(None, None)),
         }
     }
 
@@ -646,6 +778,97 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_signing_opportunities_when_not_supported_by_aggregator() {
+        let mut services = init_services().await;
+        let certificate_handler = Arc::new(DumbAggregatorClient::default());
+        services.certificate_handler = certificate_handler.clone();
+        let runner = init_runner(Some(services), None).await;
+
+        certificate_handler.set_certificate_pending(None).await;
+        assert!(runner.get_signing_opportunities().await.unwrap().is_empty());
+
+        let pending_certificate = fake_data::certificate_pending();
+        certificate_handler
+            .set_certificate_pending(Some(pending_certificate.clone()))
+            .await;
+        assert_eq!(
+            vec![pending_certificate],
+            runner.get_signing_opportunities().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_signing_opportunities() {
+        let mut services = init_services().await;
+        let certificate_handler = Arc::new(DumbAggregatorClient::default());
+        services.certificate_handler = certificate_handler.clone();
+        let runner = init_runner(Some(services), None).await;
+        let pending_certificate = fake_data::certificate_pending();
+        let epoch = pending_certificate.epoch;
+        certificate_handler
+            .set_certificate_pending(Some(pending_certificate.clone()))
+            .await;
+        certificate_handler
+            .set_signing_opportunities(Some(vec![
+                SigningOpportunityListItemMessage {
+                    epoch,
+                    signed_entity_type: SignedEntityType::MithrilStakeDistribution(epoch),
+                    ..SigningOpportunityListItemMessage::dummy()
+                },
+                SigningOpportunityListItemMessage {
+                    epoch,
+                    signed_entity_type: SignedEntityType::CardanoStakeDistribution(epoch),
+                    ..SigningOpportunityListItemMessage::dummy()
+                },
+                SigningOpportunityListItemMessage {
+                    epoch: epoch - 1,
+                    signed_entity_type: SignedEntityType::CardanoStakeDistribution(epoch - 1),
+                    ..SigningOpportunityListItemMessage::dummy()
+                },
+            ]))
+            .await;
+
+        assert_eq!(
+            vec![
+                CertificatePending {
+                    signed_entity_type: SignedEntityType::MithrilStakeDistribution(epoch),
+                    ..pending_certificate.clone()
+                },
+                CertificatePending {
+                    signed_entity_type: SignedEntityType::CardanoStakeDistribution(epoch),
+                    ..pending_certificate
+                },
+            ],
+            runner.get_signing_opportunities().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_signed_then_has_already_signed() {
+        let runner = init_runner(None, None).await;
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(7));
+
+        assert!(!runner
+            .has_already_signed(&signed_entity_type)
+            .await
+            .unwrap());
+
+        runner.mark_as_signed(&signed_entity_type).await.unwrap();
+
+        assert!(runner
+            .has_already_signed(&signed_entity_type)
+            .await
+            .unwrap());
+
+        runner.unmark_as_signed(&signed_entity_type).await.unwrap();
+
+        assert!(!runner
+            .has_already_signed(&signed_entity_type)
+            .await
+            .unwrap());
+    }
+
     #[tokio::test]
     async fn test_update_stake_distribution() {
         let services = init_services().await;
@@ -734,6 +957,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_check_kes_expiry_sets_remaining_kes_periods_metric() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let operational_certificate = fixture.signers_fixture()[0]
+            .signer_with_stake
+            .operational_certificate
+            .clone()
+            .expect("fixture signer should have an operational certificate");
+        let config = Configuration {
+            kes_max_evolutions: 62,
+            ..Configuration::new_sample("1")
+        };
+        let runner = init_runner(None, Some(config)).await;
+
+        runner.check_kes_expiry(
+            &operational_certificate,
+            operational_certificate.start_kes_period as KESPeriod + 50,
+        );
+
+        assert_eq!(
+            12,
+            runner
+                .services
+                .metrics_service
+                .kes_remaining_periods_gauge_get()
+        );
+    }
+
     #[tokio::test]
     async fn test_can_i_sign() {
         let mut pending_certificate = fake_data::certificate_pending();
@@ -922,6 +1173,8 @@ mod tests {
             .once()
             .returning(|_, _| Ok(()));
         services.certificate_handler = Arc::new(certificate_handler);
+        let single_signature_history_repository =
+            services.single_signature_history_repository.clone();
         let runner = init_runner(Some(services), None).await;
 
         runner
@@ -931,6 +1184,49 @@ mod tests {
             )
             .await
             .expect("send_single_signature should not fail");
+
+        let history = single_signature_history_repository
+            .get_latest_single_signatures(10)
+            .unwrap();
+        assert_eq!(1, history.len());
+        assert_eq!(SignedEntityType::dummy(), history[0].signed_entity_type);
+        assert_eq!(vec![2, 5, 12], history[0].won_indexes);
+        assert_eq!(None, history[0].aggregator_error);
+    }
+
+    #[tokio::test]
+    async fn test_send_single_signature_records_aggregator_error_in_history() {
+        let mut services = init_services().await;
+        let mut certificate_handler = MockAggregatorClient::new();
+        certificate_handler
+            .expect_register_signatures()
+            .once()
+            .returning(|_, _| {
+                Err(AggregatorClientError::RemoteServerLogical(anyhow::anyhow!(
+                    "signatures refused"
+                )))
+            });
+        services.certificate_handler = Arc::new(certificate_handler);
+        let single_signature_history_repository =
+            services.single_signature_history_repository.clone();
+        let runner = init_runner(Some(services), None).await;
+
+        runner
+            .send_single_signature(
+                &SignedEntityType::dummy(),
+                Some(fake_data::single_signatures(vec![2, 5, 12])),
+            )
+            .await
+            .expect_err("send_single_signature should fail");
+
+        let history = single_signature_history_repository
+            .get_latest_single_signatures(10)
+            .unwrap();
+        assert_eq!(1, history.len());
+        assert_eq!(
+            Some("remote server logical error: signatures refused".to_string()),
+            history[0].aggregator_error
+        );
     }
 
     #[tokio::test]
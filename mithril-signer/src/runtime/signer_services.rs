@@ -31,12 +31,16 @@ use mithril_persistence::{
 };
 
 use crate::{
-    aggregator_client::AggregatorClient, metrics::MetricsService, single_signer::SingleSigner,
+    aggregator_client::AggregatorClient,
+    database::repository::{SignedBeaconRepository, SingleSignatureHistoryRepository},
+    metrics::MetricsService,
+    single_signer::SingleSigner,
     AggregatorHTTPClient, CardanoTransactionsImporter,
     CardanoTransactionsPreloaderActivationSigner, Configuration, MithrilSingleSigner,
-    ProtocolInitializerStore, ProtocolInitializerStorer, SignerUpkeepService,
-    TransactionsImporterByChunk, TransactionsImporterWithPruner, TransactionsImporterWithVacuum,
-    UpkeepService, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE, SQLITE_FILE_CARDANO_TRANSACTION,
+    ProtocolInitializerStore, ProtocolInitializerStorer, SignedBeaconStore, SignerKeysWatcher,
+    SignerUpkeepService, TransactionsImporterByChunk, TransactionsImporterWithPruner,
+    TransactionsImporterWithVacuum, UpkeepService, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE,
+    SQLITE_FILE_CARDANO_TRANSACTION,
 };
 
 type StakeStoreService = Arc<StakeStore>;
@@ -46,6 +50,7 @@ type DigesterService = Arc<dyn ImmutableDigester>;
 type SingleSignerService = Arc<dyn SingleSigner>;
 type TimePointProviderService = Arc<dyn TickerService>;
 type ProtocolInitializerStoreService = Arc<dyn ProtocolInitializerStorer>;
+type SignedBeaconStoreService = Arc<dyn SignedBeaconStore>;
 
 /// The ServiceBuilder is intended to manage Services instance creation.
 /// The goal of this is to put all this code out of the way of business code.
@@ -128,6 +133,12 @@ impl<'a> ProductionServiceBuilder<'a> {
         self
     }
 
+    /// Build the chain observer used by the signer.
+    pub fn build_chain_observer(&self) -> StdResult<ChainObserverService> {
+        let builder = self.chain_observer_builder;
+        builder(self.config)
+    }
+
     /// Compute protocol party id
     fn compute_protocol_party_id(&self) -> StdResult<ProtocolPartyId> {
         match &self.config.operational_certificate_path {
@@ -220,6 +231,10 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             )?),
             self.config.store_retention_limit,
         ));
+        let signed_beacon_store = Arc::new(SignedBeaconRepository::new(
+            sqlite_connection.clone(),
+            self.config.store_retention_limit.map(|limit| limit as u64),
+        ));
         let single_signer = Arc::new(MithrilSingleSigner::new(self.compute_protocol_party_id()?));
         let digester = Arc::new(CardanoImmutableDigester::new(
             self.build_digester_cache_provider().await?,
@@ -229,10 +244,7 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             Box::new(SQLiteAdapter::new("stake", sqlite_connection.clone())?),
             self.config.store_retention_limit,
         ));
-        let chain_observer = {
-            let builder = self.chain_observer_builder;
-            builder(self.config)?
-        };
+        let chain_observer = self.build_chain_observer()?;
         let ticker_service = {
             let builder = self.immutable_file_observer_builder;
             Arc::new(MithrilTickerService::new(
@@ -340,12 +352,21 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             slog_scope::logger(),
             Arc::new(preloader_activation),
         ));
+        let single_signature_history_repository = Arc::new(SingleSignatureHistoryRepository::new(
+            sqlite_connection.clone(),
+        ));
         let upkeep_service = Arc::new(SignerUpkeepService::new(
             sqlite_connection.clone(),
             sqlite_connection_cardano_transaction_pool,
             signed_entity_type_lock.clone(),
+            single_signature_history_repository.clone(),
+            self.config.store_retention_limit.map(|limit| limit as u64),
             slog_scope::logger(),
         ));
+        let signer_keys_watcher = Arc::new(SignerKeysWatcher::new(
+            self.config.kes_secret_key_path.clone(),
+            self.config.operational_certificate_path.clone(),
+        ));
 
         let services = SignerServices {
             ticker_service,
@@ -355,6 +376,8 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             single_signer,
             stake_store,
             protocol_initializer_store,
+            signed_beacon_store,
+            single_signature_history_repository,
             era_checker,
             era_reader,
             api_version_provider,
@@ -363,6 +386,7 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             signed_entity_type_lock,
             cardano_transactions_preloader,
             upkeep_service,
+            signer_keys_watcher,
         };
 
         Ok(services)
@@ -392,6 +416,12 @@ pub struct SignerServices {
     /// ProtocolInitializer store
     pub protocol_initializer_store: ProtocolInitializerStoreService,
 
+    /// Signed beacon store
+    pub signed_beacon_store: SignedBeaconStoreService,
+
+    /// Single signature history repository
+    pub single_signature_history_repository: Arc<SingleSignatureHistoryRepository>,
+
     /// Era checker service
     pub era_checker: Arc<EraChecker>,
 
@@ -415,6 +445,9 @@ pub struct SignerServices {
 
     /// Upkeep service
     pub upkeep_service: Arc<dyn UpkeepService>,
+
+    /// Signer keys watcher
+    pub signer_keys_watcher: Arc<SignerKeysWatcher>,
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use slog_scope::{crit, debug, error, info};
+use slog_scope::{crit, debug, error, info, warn};
 use std::{fmt::Display, ops::Deref, sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::sleep};
 
@@ -32,13 +32,13 @@ pub enum SignerState {
         epoch: Epoch,
     },
 
-    /// `Signed` state. The Signer has signed the message for the
-    /// current pending certificate.
+    /// `Signed` state. The Signer has signed the messages of all the
+    /// signing opportunities it could sign.
     Signed {
         /// Epoch when Signer signed.
         epoch: Epoch,
 
-        /// Entity type that is signed
+        /// Last entity type that is signed
         signed_entity_type: SignedEntityType,
     },
 }
@@ -189,68 +189,50 @@ impl StateMachine {
                     *state = self
                         .transition_from_registered_to_unregistered(new_epoch)
                         .await?;
-                } else if let Some(pending_certificate) =
-                    self.runner.get_pending_certificate().await.map_err(|e| {
-                        RuntimeError::KeepState {
-                            message: "could not fetch the pending certificate".to_string(),
-                            nested_error: Some(e),
-                        }
-                    })?
-                {
-                    info!(
-                        " ⋅ Epoch has NOT changed but there is a pending certificate";
-                        "pending_certificate" => ?pending_certificate
-                    );
-
-                    if self
-                        .runner
-                        .can_i_sign(&pending_certificate)
-                        .await
-                        .map_err(|e| RuntimeError::KeepState {
-                            message: "could not determine if I can sign certificate".to_string(),
-                            nested_error: Some(e),
-                        })?
-                    {
-                        info!(" → we can sign this certificate, transiting to SIGNED");
-                        *state = self
-                            .transition_from_registered_to_signed(&pending_certificate)
-                            .await?;
+                } else if self.has_signer_keys_rotated().await {
+                    info!("→ Signer keys have been rotated, transiting to UNREGISTERED");
+                    *state = self
+                        .transition_from_registered_to_unregistered(*epoch)
+                        .await?;
+                } else {
+                    let signing_opportunities = self.get_unsigned_signing_opportunities().await?;
+                    if signing_opportunities.is_empty() {
+                        info!(" ⋅ no signing opportunity, waiting…");
                     } else {
-                        info!(" ⋅ cannot sign this pending certificate, waiting…");
+                        info!(
+                            " ⋅ Epoch has NOT changed but there are signing opportunities";
+                            "signed_entity_types" => ?signing_opportunities
+                                .iter()
+                                .map(|p| &p.signed_entity_type)
+                                .collect::<Vec<_>>()
+                        );
+                        let (signed_state, first_error) = self
+                            .sign_signing_opportunities(signing_opportunities)
+                            .await?;
+                        if let Some(signed_state) = signed_state {
+                            info!(" → signing opportunities signed, transiting to SIGNED");
+                            *state = signed_state;
+                        }
+                        if let Some(error) = first_error {
+                            return Err(error);
+                        }
                     }
-                } else {
-                    info!(" ⋅ no pending certificate, waiting…");
                 }
             }
-            SignerState::Signed {
-                epoch,
-                signed_entity_type,
-            } => {
+            SignerState::Signed { epoch, .. } => {
                 if let Some(new_epoch) = self.has_epoch_changed(*epoch).await? {
                     info!(" → new Epoch detected, transiting to UNREGISTERED");
                     *state = self
                         .transition_from_signed_to_unregistered(new_epoch)
                         .await?;
-                } else if let Some(pending_certificate) =
-                    self.runner.get_pending_certificate().await.map_err(|e| {
-                        RuntimeError::KeepState {
-                            message: "could not fetch the pending certificate".to_string(),
-                            nested_error: Some(e),
-                        }
-                    })?
-                {
-                    info!(
-                        " ⋅ Epoch has NOT changed but there is a pending certificate";
-                        "pending_certificate" => ?pending_certificate
-                    );
-                    if pending_certificate.signed_entity_type == *signed_entity_type {
-                        info!(" ⋅ pending certificate has not changed, waiting…");
-                    } else {
-                        info!(" → new pending certificate detected, transiting to REGISTERED");
-                        *state = self.transition_from_signed_to_registered(*epoch).await?;
-                    }
+                } else if self.has_signer_keys_rotated().await {
+                    info!(" → Signer keys have been rotated, transiting to UNREGISTERED");
+                    *state = self.transition_from_signed_to_unregistered(*epoch).await?;
+                } else if self.get_unsigned_signing_opportunities().await?.is_empty() {
+                    info!(" ⋅ no new signing opportunity, waiting…");
                 } else {
-                    info!(" ⋅ no pending certificate, waiting…");
+                    info!(" → new signing opportunity detected, transiting to REGISTERED");
+                    *state = self.transition_from_signed_to_registered(*epoch).await?;
                 }
             }
         };
@@ -261,6 +243,105 @@ impl StateMachine {
         Ok(())
     }
 
+    /// Return the signing opportunities of the aggregator that have not been signed yet.
+    async fn get_unsigned_signing_opportunities(
+        &self,
+    ) -> Result<Vec<CertificatePending>, RuntimeError> {
+        let signing_opportunities =
+            self.runner
+                .get_signing_opportunities()
+                .await
+                .map_err(|e| RuntimeError::KeepState {
+                    message: "could not fetch the signing opportunities".to_string(),
+                    nested_error: Some(e),
+                })?;
+
+        let mut unsigned_signing_opportunities = vec![];
+        for pending_certificate in signing_opportunities {
+            let signed_entity_type = &pending_certificate.signed_entity_type;
+            if self
+                .runner
+                .has_already_signed(signed_entity_type)
+                .await
+                .map_err(|e| RuntimeError::KeepState {
+                    message: format!(
+                        "could not determine if {signed_entity_type:?} has already been signed"
+                    ),
+                    nested_error: Some(e),
+                })?
+            {
+                debug!(" ⋅ {signed_entity_type:?} has already been signed, skipping");
+            } else {
+                unsigned_signing_opportunities.push(pending_certificate);
+            }
+        }
+
+        Ok(unsigned_signing_opportunities)
+    }
+
+    /// Sign all the given signing opportunities that the signer can sign.
+    ///
+    /// A failure to sign one of them does not prevent signing the others: the `Signed` state
+    /// for the last signed opportunity is returned along with the first non critical error.
+    async fn sign_signing_opportunities(
+        &self,
+        signing_opportunities: Vec<CertificatePending>,
+    ) -> Result<(Option<SignerState>, Option<RuntimeError>), RuntimeError> {
+        let mut signed_state = None;
+        let mut first_error = None;
+
+        for pending_certificate in signing_opportunities {
+            let result = match self.runner.can_i_sign(&pending_certificate).await {
+                Ok(true) => {
+                    info!(
+                        " → we can sign {:?}",
+                        pending_certificate.signed_entity_type
+                    );
+                    self.transition_from_registered_to_signed(&pending_certificate)
+                        .await
+                        .map(Some)
+                }
+                Ok(false) => {
+                    info!(
+                        " ⋅ cannot sign {:?}, waiting…",
+                        pending_certificate.signed_entity_type
+                    );
+                    Ok(None)
+                }
+                Err(e) => Err(RuntimeError::KeepState {
+                    message: "could not determine if I can sign certificate".to_string(),
+                    nested_error: Some(e),
+                }),
+            };
+
+            match result {
+                Ok(Some(state)) => signed_state = Some(state),
+                Ok(None) => {}
+                Err(error) if error.is_critical() => return Err(error),
+                Err(error) => {
+                    error!("{error}");
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Ok((signed_state, first_error))
+    }
+
+    /// Check if the signer keys have been rotated, in which case the signer must register again.
+    ///
+    /// Invalid rotated keys are reported but do not prevent the signer from signing with its
+    /// current registration.
+    async fn has_signer_keys_rotated(&self) -> bool {
+        match self.runner.has_signer_keys_rotated().await {
+            Ok(has_rotated) => has_rotated,
+            Err(error) => {
+                warn!("Signer keys rotation ignored, keeping the current registration"; "error" => ?error);
+                false
+            }
+        }
+    }
+
     /// Return the new epoch if the epoch is different than the given one.
     async fn has_epoch_changed(&self, epoch: Epoch) -> Result<Option<Epoch>, RuntimeError> {
         let current_time_point = self
@@ -425,11 +506,34 @@ impl StateMachine {
                 message: format!("Could not compute single signature during 'registered → signed' phase (current epoch {current_epoch:?})"),
                 nested_error: Some(e)
             })?;
-        self.runner.send_single_signature(&pending_certificate.signed_entity_type, single_signatures).await
+        // The signed entity type is marked as signed before sending the signature so it is
+        // never signed twice, the mark is removed if the signature can not be sent.
+        self.runner.mark_as_signed(&pending_certificate.signed_entity_type).await
             .map_err(|e| RuntimeError::KeepState {
-                message: format!("Could not send single signature during 'registered → signed' phase (current epoch {current_epoch:?})"),
+                message: format!("Could not mark signed entity type as signed during 'registered → signed' phase (current epoch {current_epoch:?})"),
                 nested_error: Some(e)
             })?;
+        if let Err(e) = self
+            .runner
+            .send_single_signature(&pending_certificate.signed_entity_type, single_signatures)
+            .await
+        {
+            if let Err(error) = self
+                .runner
+                .unmark_as_signed(&pending_certificate.signed_entity_type)
+                .await
+            {
+                error!(
+                    "Could not unmark signed entity type as signed during 'registered → signed' phase (current epoch {current_epoch:?})";
+                    "signed_entity_type" => ?pending_certificate.signed_entity_type, "error" => ?error
+                );
+            }
+
+            return Err(RuntimeError::KeepState {
+                message: format!("Could not send single signature during 'registered → signed' phase (current epoch {current_epoch:?})"),
+                nested_error: Some(e)
+            });
+        }
 
         self.metrics_service
             .signature_registration_success_since_startup_counter_increment();
@@ -472,6 +576,9 @@ impl StateMachine {
 
 #[cfg(test)]
 mod tests {
+    use anyhow::anyhow;
+    use mockall::predicate;
+
     use mithril_common::entities::{CardanoDbBeacon, ChainPoint, Epoch, ProtocolMessage};
     use mithril_common::test_utils::fake_data;
 
@@ -619,6 +726,78 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn registered_to_unregistered_when_signer_keys_rotated() {
+        let time_point = TimePoint::dummy();
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(true));
+        runner
+            .expect_update_era_checker()
+            .once()
+            .returning(|_e: Epoch| Ok(()));
+
+        let state_machine = init_state_machine(
+            SignerState::Registered {
+                epoch: time_point.epoch,
+            },
+            runner,
+        );
+
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+        assert_eq!(
+            SignerState::Unregistered {
+                epoch: time_point.epoch
+            },
+            state_machine.get_state().await
+        );
+    }
+
+    #[tokio::test]
+    async fn registered_keeps_signing_when_rotated_signer_keys_are_invalid() {
+        let time_point = TimePoint::dummy();
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Err(anyhow!("invalid operational certificate")));
+        runner
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(|| Ok(vec![]));
+
+        let state_machine = init_state_machine(
+            SignerState::Registered {
+                epoch: time_point.epoch,
+            },
+            runner,
+        );
+
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+        assert_eq!(
+            SignerState::Registered {
+                epoch: time_point.epoch
+            },
+            state_machine.get_state().await
+        );
+    }
+
     #[tokio::test]
     async fn registered_to_registered() {
         let time_point = TimePoint {
@@ -635,14 +814,22 @@ mod tests {
             ..fake_data::certificate_pending()
         };
         let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
         runner
             .expect_get_current_time_point()
             .once()
             .returning(move || Ok(time_point.to_owned()));
         runner
-            .expect_get_pending_certificate()
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(move || Ok(vec![certificate_pending.to_owned()]));
+        runner
+            .expect_has_already_signed()
             .once()
-            .returning(move || Ok(Some(certificate_pending.to_owned())));
+            .returning(|_| Ok(false));
         runner.expect_can_i_sign().once().returning(|_| Ok(false));
 
         let state_machine = init_state_machine(state, runner);
@@ -676,14 +863,22 @@ mod tests {
         };
         let signed_entity_type = certificate_pending.signed_entity_type.to_owned();
         let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
         runner
             .expect_get_current_time_point()
             .once()
             .returning(move || Ok(time_point.to_owned()));
         runner
-            .expect_get_pending_certificate()
+            .expect_get_signing_opportunities()
             .once()
-            .returning(move || Ok(Some(certificate_pending.clone())));
+            .returning(move || Ok(vec![certificate_pending.clone()]));
+        runner
+            .expect_has_already_signed()
+            .once()
+            .returning(|_| Ok(false));
         runner.expect_can_i_sign().once().returning(|_| Ok(true));
         runner
             .expect_associate_signers_with_stake()
@@ -701,6 +896,11 @@ mod tests {
             .expect_send_single_signature()
             .once()
             .returning(|_, _| Ok(()));
+        runner
+            .expect_mark_as_signed()
+            .with(predicate::eq(signed_entity_type.clone()))
+            .once()
+            .returning(|_| Ok(()));
 
         let state_machine = init_state_machine(state, runner);
         state_machine
@@ -719,6 +919,257 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn registered_does_not_send_signature_if_signed_entity_type_can_not_be_marked_as_signed()
+    {
+        let time_point = TimePoint {
+            immutable_file_number: 99,
+            epoch: Epoch(9),
+            chain_point: ChainPoint::dummy(),
+        };
+        let state = SignerState::Registered {
+            epoch: time_point.epoch,
+        };
+
+        let certificate_pending = CertificatePending {
+            epoch: time_point.epoch,
+            ..fake_data::certificate_pending()
+        };
+        let signed_entity_type = certificate_pending.signed_entity_type.to_owned();
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(move || Ok(vec![certificate_pending.clone()]));
+        runner
+            .expect_has_already_signed()
+            .once()
+            .returning(|_| Ok(false));
+        runner.expect_can_i_sign().once().returning(|_| Ok(true));
+        runner
+            .expect_associate_signers_with_stake()
+            .times(2)
+            .returning(|_, _| Ok(fake_data::signers_with_stakes(4)));
+        runner
+            .expect_compute_single_signature()
+            .once()
+            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+        runner
+            .expect_compute_message()
+            .once()
+            .returning(|_, _| Ok(ProtocolMessage::new()));
+        runner.expect_send_single_signature().never();
+        runner
+            .expect_mark_as_signed()
+            .with(predicate::eq(signed_entity_type.clone()))
+            .once()
+            .returning(|_| Err(anyhow!("an error")));
+
+        let state_machine = init_state_machine(state.clone(), runner);
+        state_machine
+            .cycle()
+            .await
+            .expect_err("Cycling the state machine should return the marking error");
+
+        assert_eq!(state, state_machine.get_state().await);
+    }
+
+    #[tokio::test]
+    async fn registered_to_signed_signs_every_unsigned_signing_opportunity() {
+        let time_point = TimePoint {
+            immutable_file_number: 99,
+            epoch: Epoch(9),
+            chain_point: ChainPoint::dummy(),
+        };
+        let state = SignerState::Registered {
+            epoch: time_point.epoch,
+        };
+
+        let already_signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(9));
+        let signed_entity_types = vec![
+            SignedEntityType::CardanoStakeDistribution(Epoch(9)),
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new("whatever", 9, 99)),
+        ];
+        let signing_opportunities: Vec<CertificatePending> = [
+            vec![already_signed_entity_type.clone()],
+            signed_entity_types.clone(),
+        ]
+        .concat()
+        .into_iter()
+        .map(|signed_entity_type| CertificatePending {
+            epoch: time_point.epoch,
+            signed_entity_type,
+            ..fake_data::certificate_pending()
+        })
+        .collect();
+
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(move || Ok(signing_opportunities.clone()));
+        runner
+            .expect_has_already_signed()
+            .times(3)
+            .returning(move |signed_entity_type| {
+                Ok(signed_entity_type == &already_signed_entity_type)
+            });
+        runner.expect_can_i_sign().times(2).returning(|_| Ok(true));
+        runner
+            .expect_associate_signers_with_stake()
+            .times(4)
+            .returning(|_, _| Ok(fake_data::signers_with_stakes(4)));
+        runner
+            .expect_compute_single_signature()
+            .times(2)
+            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+        runner
+            .expect_compute_message()
+            .times(2)
+            .returning(|_, _| Ok(ProtocolMessage::new()));
+        for signed_entity_type in &signed_entity_types {
+            runner
+                .expect_send_single_signature()
+                .with(
+                    predicate::eq(signed_entity_type.clone()),
+                    predicate::always(),
+                )
+                .once()
+                .returning(|_, _| Ok(()));
+            runner
+                .expect_mark_as_signed()
+                .with(predicate::eq(signed_entity_type.clone()))
+                .once()
+                .returning(|_| Ok(()));
+        }
+
+        let state_machine = init_state_machine(state, runner);
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+
+        assert_eq!(
+            SignerState::Signed {
+                epoch: Epoch(9),
+                signed_entity_type: signed_entity_types.last().unwrap().clone(),
+            },
+            state_machine.get_state().await
+        );
+    }
+
+    #[tokio::test]
+    async fn registered_keeps_signing_other_signing_opportunities_when_one_fails() {
+        let time_point = TimePoint {
+            immutable_file_number: 99,
+            epoch: Epoch(9),
+            chain_point: ChainPoint::dummy(),
+        };
+        let state = SignerState::Registered {
+            epoch: time_point.epoch,
+        };
+
+        let failing_signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(9));
+        let signed_entity_type = SignedEntityType::CardanoStakeDistribution(Epoch(9));
+        let signing_opportunities: Vec<CertificatePending> = [
+            failing_signed_entity_type.clone(),
+            signed_entity_type.clone(),
+        ]
+        .into_iter()
+        .map(|signed_entity_type| CertificatePending {
+            epoch: time_point.epoch,
+            signed_entity_type,
+            ..fake_data::certificate_pending()
+        })
+        .collect();
+
+        let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
+        runner
+            .expect_get_current_time_point()
+            .once()
+            .returning(move || Ok(time_point.to_owned()));
+        runner
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(move || Ok(signing_opportunities.clone()));
+        runner
+            .expect_has_already_signed()
+            .times(2)
+            .returning(|_| Ok(false));
+        runner.expect_can_i_sign().times(2).returning(|_| Ok(true));
+        runner
+            .expect_associate_signers_with_stake()
+            .times(4)
+            .returning(|_, _| Ok(fake_data::signers_with_stakes(4)));
+        runner
+            .expect_compute_single_signature()
+            .times(2)
+            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+        runner
+            .expect_compute_message()
+            .times(2)
+            .returning(|_, _| Ok(ProtocolMessage::new()));
+        runner
+            .expect_send_single_signature()
+            .with(
+                predicate::eq(failing_signed_entity_type.clone()),
+                predicate::always(),
+            )
+            .once()
+            .returning(|_, _| Err(anyhow!("send error")));
+        runner
+            .expect_send_single_signature()
+            .with(
+                predicate::eq(signed_entity_type.clone()),
+                predicate::always(),
+            )
+            .once()
+            .returning(|_, _| Ok(()));
+        runner
+            .expect_mark_as_signed()
+            .times(2)
+            .returning(|_| Ok(()));
+        runner
+            .expect_unmark_as_signed()
+            .with(predicate::eq(failing_signed_entity_type.clone()))
+            .once()
+            .returning(|_| Ok(()));
+
+        let state_machine = init_state_machine(state, runner);
+        state_machine
+            .cycle()
+            .await
+            .expect_err("Cycling the state machine should return the signing error");
+
+        assert_eq!(
+            SignerState::Signed {
+                epoch: Epoch(9),
+                signed_entity_type,
+            },
+            state_machine.get_state().await
+        );
+    }
+
     #[tokio::test]
     async fn signed_to_registered() {
         let time_point = TimePoint {
@@ -744,14 +1195,22 @@ mod tests {
         };
 
         let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
         runner
             .expect_get_current_time_point()
             .once()
             .returning(move || Ok(time_point_clone.to_owned()));
         runner
-            .expect_get_pending_certificate()
+            .expect_get_signing_opportunities()
             .once()
-            .returning(move || Ok(Some(certificate_pending.clone())));
+            .returning(move || Ok(vec![certificate_pending.clone()]));
+        runner
+            .expect_has_already_signed()
+            .once()
+            .returning(|_| Ok(false));
 
         let state_machine = init_state_machine(state, runner);
         state_machine
@@ -806,7 +1265,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn signed_to_signed_no_pending_certificate() {
+    async fn signed_to_signed_no_signing_opportunity() {
         let time_point = TimePoint {
             immutable_file_number: 99,
             epoch: Epoch(9),
@@ -819,14 +1278,18 @@ mod tests {
         };
 
         let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
         runner
             .expect_get_current_time_point()
             .once()
             .returning(move || Ok(time_point_clone.to_owned()));
         runner
-            .expect_get_pending_certificate()
+            .expect_get_signing_opportunities()
             .once()
-            .returning(move || Ok(None));
+            .returning(move || Ok(vec![]));
 
         let state_machine = init_state_machine(state, runner);
         state_machine
@@ -844,7 +1307,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn signed_to_signed_unsigned_pending_certificate() {
+    async fn signed_to_signed_already_signed_signing_opportunity() {
         let time_point = TimePoint {
             immutable_file_number: 99,
             epoch: Epoch(9),
@@ -862,14 +1325,22 @@ mod tests {
         };
 
         let mut runner = MockSignerRunner::new();
+        runner
+            .expect_has_signer_keys_rotated()
+            .once()
+            .returning(|| Ok(false));
         runner
             .expect_get_current_time_point()
             .once()
             .returning(move || Ok(time_point_clone.to_owned()));
         runner
-            .expect_get_pending_certificate()
+            .expect_get_signing_opportunities()
+            .once()
+            .returning(move || Ok(vec![certificate_pending.clone()]));
+        runner
+            .expect_has_already_signed()
             .once()
-            .returning(move || Ok(Some(certificate_pending.clone())));
+            .returning(|_| Ok(true));
 
         let state_machine = init_state_machine(state, runner);
         state_machine
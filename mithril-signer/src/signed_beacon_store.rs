@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use mithril_common::entities::SignedEntityType;
+use mithril_common::StdResult;
+
+/// Store of the signed entity types already signed by the signer, so they are never signed
+/// twice, even after a restart.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait SignedBeaconStore: Sync + Send {
+    /// Check if the given signed entity type has already been signed
+    async fn has_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<bool>;
+
+    /// Mark the given signed entity type as signed
+    async fn mark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()>;
+
+    /// Remove the signed mark of the given signed entity type
+    async fn unmark_as_signed(&self, signed_entity_type: &SignedEntityType) -> StdResult<()>;
+}
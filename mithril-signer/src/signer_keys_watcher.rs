@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Context};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use mithril_common::crypto_helper::{OpCert, SerDeShelleyFileFormat, Sum6KesBytes};
+use mithril_common::entities::PartyId;
+use mithril_common::StdResult;
+
+/// Fingerprint of the content of the KES secret key and operational certificate files.
+pub type SignerKeysFingerprint = u64;
+
+/// Watch the KES secret key and operational certificate files of the signer, so that they can be
+/// rotated without restarting it.
+///
+/// A rotation is detected when the content of the files differs from the content used for the
+/// last registration, or when a reload is explicitly requested (i.e. on `SIGHUP`).
+pub struct SignerKeysWatcher {
+    kes_secret_key_path: Option<PathBuf>,
+    operational_certificate_path: Option<PathBuf>,
+    registered_fingerprint: RwLock<Option<SignerKeysFingerprint>>,
+    reload_requested: AtomicBool,
+}
+
+impl SignerKeysWatcher {
+    /// Create a new `SignerKeysWatcher` instance.
+    pub fn new(
+        kes_secret_key_path: Option<PathBuf>,
+        operational_certificate_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            kes_secret_key_path,
+            operational_certificate_path,
+            registered_fingerprint: RwLock::new(None),
+            reload_requested: AtomicBool::new(false),
+        }
+    }
+
+    /// Compute the fingerprint of the current content of the key files.
+    pub fn compute_fingerprint(&self) -> StdResult<SignerKeysFingerprint> {
+        let mut hasher = DefaultHasher::new();
+        for path in [
+            &self.kes_secret_key_path,
+            &self.operational_certificate_path,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            std::fs::read(path)
+                .with_context(|| format!("Could not read file '{}'", path.display()))?
+                .hash(&mut hasher);
+        }
+
+        Ok(hasher.finish())
+    }
+
+    /// Record the fingerprint of the key files used for the last successful registration.
+    pub fn mark_as_registered(&self, fingerprint: SignerKeysFingerprint) {
+        let mut registered_fingerprint = self.registered_fingerprint.write().unwrap();
+        *registered_fingerprint = Some(fingerprint);
+    }
+
+    /// Request a reload of the key files, even if their content did not change.
+    pub fn request_reload(&self) {
+        self.reload_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Check if the key files have been rotated since the last registration.
+    ///
+    /// The new key files are validated before reporting a rotation: an error is returned if
+    /// they are not usable by the signer with the given party id. In that case a requested
+    /// reload is kept pending until valid key files are found.
+    pub fn detect_rotation(&self, party_id: &PartyId) -> StdResult<bool> {
+        let registered_fingerprint = *self.registered_fingerprint.read().unwrap();
+        if registered_fingerprint.is_none() {
+            return Ok(false);
+        }
+
+        let reload_requested = self.reload_requested.load(Ordering::SeqCst);
+        let has_changed = Some(self.compute_fingerprint()?) != registered_fingerprint;
+        if !reload_requested && !has_changed {
+            return Ok(false);
+        }
+
+        self.validate(party_id)
+            .with_context(|| "Rotated signer keys are not valid")?;
+        self.reload_requested.store(false, Ordering::SeqCst);
+
+        Ok(true)
+    }
+
+    fn validate(&self, party_id: &PartyId) -> StdResult<()> {
+        let Some(operational_certificate_path) = &self.operational_certificate_path else {
+            return Ok(());
+        };
+        let opcert = read_file::<OpCert>(operational_certificate_path)?;
+        opcert
+            .validate()
+            .with_context(|| "Invalid operational certificate signature")?;
+        let opcert_party_id = opcert
+            .compute_protocol_party_id()
+            .with_context(|| "Could not compute party id from operational certificate")?;
+        if &opcert_party_id != party_id {
+            return Err(anyhow!(
+                "party id '{opcert_party_id}' of the operational certificate does not match the party id '{party_id}' of the signer"
+            ));
+        }
+
+        if let Some(kes_secret_key_path) = &self.kes_secret_key_path {
+            let kes_secret_key = read_file::<Sum6KesBytes>(kes_secret_key_path)?;
+            opcert.check_kes_secret_key(kes_secret_key)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn read_file<T: SerDeShelleyFileFormat>(path: &Path) -> StdResult<T> {
+    T::from_file(path)
+        .map_err(|e| anyhow!(e))
+        .with_context(|| format!("Could not decode file '{}'", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::{MithrilFixtureBuilder, SignerFixture, TempDir};
+
+    use super::*;
+
+    fn write_opcert(signer: &SignerFixture, path: &Path) {
+        signer
+            .signer_with_stake
+            .operational_certificate
+            .clone()
+            .unwrap()
+            .to_file(path)
+            .unwrap();
+    }
+
+    fn setup_watcher(test_name: &str, signer: &SignerFixture) -> (SignerKeysWatcher, PathBuf) {
+        let opcert_path = TempDir::create("signer_keys_watcher", test_name).join("opcert.cert");
+        write_opcert(signer, &opcert_path);
+        let watcher = SignerKeysWatcher::new(
+            signer.kes_secret_key_path.clone(),
+            Some(opcert_path.clone()),
+        );
+
+        (watcher, opcert_path)
+    }
+
+    #[test]
+    fn no_rotation_detected_before_first_registration() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let signers = fixture.signers_fixture();
+        let (watcher, opcert_path) = setup_watcher(
+            "no_rotation_detected_before_first_registration",
+            &signers[0],
+        );
+
+        write_opcert(&signers[1], &opcert_path);
+
+        assert!(!watcher.detect_rotation(&signers[0].party_id()).unwrap());
+    }
+
+    #[test]
+    fn no_rotation_detected_when_key_files_are_unchanged() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = &fixture.signers_fixture()[0];
+        let (watcher, _) =
+            setup_watcher("no_rotation_detected_when_key_files_are_unchanged", signer);
+        watcher.mark_as_registered(watcher.compute_fingerprint().unwrap());
+
+        assert!(!watcher.detect_rotation(&signer.party_id()).unwrap());
+    }
+
+    #[test]
+    fn rotation_detected_when_reload_is_requested() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = &fixture.signers_fixture()[0];
+        let (watcher, _) = setup_watcher("rotation_detected_when_reload_is_requested", signer);
+        watcher.mark_as_registered(watcher.compute_fingerprint().unwrap());
+
+        watcher.request_reload();
+
+        assert!(watcher.detect_rotation(&signer.party_id()).unwrap());
+        assert!(
+            !watcher.detect_rotation(&signer.party_id()).unwrap(),
+            "reload request should be consumed once a rotation is detected"
+        );
+    }
+
+    #[test]
+    fn rotation_detected_when_key_files_are_changed() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = &fixture.signers_fixture()[0];
+        let (watcher, _) = setup_watcher("rotation_detected_when_key_files_are_changed", signer);
+        watcher.mark_as_registered(watcher.compute_fingerprint().unwrap().wrapping_add(1));
+
+        assert!(watcher.detect_rotation(&signer.party_id()).unwrap());
+    }
+
+    #[test]
+    fn rotation_fails_when_new_operational_certificate_belongs_to_another_party() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let signers = fixture.signers_fixture();
+        let (watcher, opcert_path) = setup_watcher(
+            "rotation_fails_when_new_operational_certificate_belongs_to_another_party",
+            &signers[0],
+        );
+        watcher.mark_as_registered(watcher.compute_fingerprint().unwrap());
+
+        write_opcert(&signers[1], &opcert_path);
+
+        watcher
+            .detect_rotation(&signers[0].party_id())
+            .expect_err("rotation to the keys of another party should fail");
+    }
+
+    #[test]
+    fn reload_request_is_kept_when_rotated_keys_are_not_valid() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(2).build();
+        let signers = fixture.signers_fixture();
+        let (watcher, opcert_path) = setup_watcher(
+            "reload_request_is_kept_when_rotated_keys_are_not_valid",
+            &signers[0],
+        );
+        watcher.mark_as_registered(watcher.compute_fingerprint().unwrap());
+
+        watcher.request_reload();
+        write_opcert(&signers[1], &opcert_path);
+        watcher
+            .detect_rotation(&signers[0].party_id())
+            .expect_err("rotation to the keys of another party should fail");
+
+        write_opcert(&signers[0], &opcert_path);
+        assert!(
+            watcher.detect_rotation(&signers[0].party_id()).unwrap(),
+            "reload request should be kept until valid keys are found"
+        );
+        assert!(!watcher.detect_rotation(&signers[0].party_id()).unwrap());
+    }
+}
@@ -4,12 +4,13 @@
 //!
 //! It is in charge of the following tasks:
 //! * free up space by executing vacuum and WAL checkpoint on the database
+//! * prune the single signature history according to the store retention limit
 
 use std::sync::Arc;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use slog::{info, Logger};
+use slog::{debug, info, Logger};
 
 use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
 use mithril_common::StdResult;
@@ -17,6 +18,8 @@ use mithril_persistence::sqlite::{
     SqliteCleaner, SqliteCleaningTask, SqliteConnection, SqliteConnectionPool,
 };
 
+use crate::database::repository::SingleSignatureHistoryRepository;
+
 /// Define the service responsible for the upkeep of the application.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -33,6 +36,8 @@ pub struct SignerUpkeepService {
     main_db_connection: Arc<SqliteConnection>,
     cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
     signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+    single_signature_history_repository: Arc<SingleSignatureHistoryRepository>,
+    store_retention_limit: Option<u64>,
     logger: Logger,
 }
 
@@ -42,16 +47,48 @@ impl SignerUpkeepService {
         main_db_connection: Arc<SqliteConnection>,
         cardano_tx_connection_pool: Arc<SqliteConnectionPool>,
         signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+        single_signature_history_repository: Arc<SingleSignatureHistoryRepository>,
+        store_retention_limit: Option<u64>,
         logger: Logger,
     ) -> Self {
         Self {
             main_db_connection,
             cardano_tx_connection_pool,
             signed_entity_type_lock,
+            single_signature_history_repository,
+            store_retention_limit,
             logger,
         }
     }
 
+    async fn prune_single_signature_history(&self) -> StdResult<()> {
+        let Some(retention_limit) = self.store_retention_limit else {
+            debug!(
+                self.logger,
+                "UpkeepService::No store retention limit - Skipping single signature history pruning"
+            );
+            return Ok(());
+        };
+
+        let repository = self.single_signature_history_repository.clone();
+        let pruning_logger = self.logger.clone();
+
+        // Run the pruning in another thread to avoid blocking the tokio runtime
+        let pruning_thread = tokio::task::spawn_blocking(move || -> StdResult<()> {
+            let deleted_records = repository.prune(retention_limit)?;
+            info!(
+                pruning_logger,
+                "UpkeepService::Pruned {deleted_records} records from the single signature history"
+            );
+
+            Ok(())
+        });
+
+        pruning_thread
+            .await
+            .with_context(|| "Single signature history pruning thread crashed")?
+    }
+
     async fn upkeep_all_databases(&self) -> StdResult<()> {
         if self.signed_entity_type_lock.has_locked_entities().await {
             info!(
@@ -100,6 +137,10 @@ impl UpkeepService for SignerUpkeepService {
     async fn run(&self) -> StdResult<()> {
         info!(self.logger, "UpkeepService::start");
 
+        self.prune_single_signature_history()
+            .await
+            .with_context(|| "Single signature history pruning failed")?;
+
         self.upkeep_all_databases()
             .await
             .with_context(|| "Database upkeep failed")?;
@@ -111,8 +152,8 @@ impl UpkeepService for SignerUpkeepService {
 
 #[cfg(test)]
 mod tests {
-    use mithril_common::entities::SignedEntityTypeDiscriminants;
-    use mithril_common::test_utils::TempDir;
+    use mithril_common::entities::{Epoch, SignedEntityType, SignedEntityTypeDiscriminants};
+    use mithril_common::test_utils::{fake_data, TempDir};
 
     use crate::database::test_helper::{
         cardano_tx_db_connection, cardano_tx_db_file_connection, main_db_connection,
@@ -133,17 +174,19 @@ mod tests {
             )
         };
 
-        let main_db_connection = main_db_file_connection(&main_db_path).unwrap();
+        let main_db_connection = Arc::new(main_db_file_connection(&main_db_path).unwrap());
         let cardano_tx_connection = cardano_tx_db_file_connection(&ctx_db_path).unwrap();
 
         // Separate block to force log flushing by dropping the service that owns the logger
         {
             let service = SignerUpkeepService::new(
-                Arc::new(main_db_connection),
+                main_db_connection.clone(),
                 Arc::new(SqliteConnectionPool::build_from_connection(
                     cardano_tx_connection,
                 )),
                 Arc::new(SignedEntityTypeLock::default()),
+                Arc::new(SingleSignatureHistoryRepository::new(main_db_connection)),
+                None,
                 TestLogger::file(&log_path),
             );
 
@@ -185,6 +228,10 @@ mod tests {
                 Arc::new(main_db_connection().unwrap()),
                 Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
                 signed_entity_type_lock.clone(),
+                Arc::new(SingleSignatureHistoryRepository::new(Arc::new(
+                    main_db_connection().unwrap(),
+                ))),
+                None,
                 TestLogger::file(&log_path),
             );
 
@@ -204,4 +251,38 @@ mod tests {
             0,
         );
     }
+
+    #[tokio::test]
+    async fn test_prune_single_signature_history_with_store_retention_limit() {
+        let repository = Arc::new(SingleSignatureHistoryRepository::new(Arc::new(
+            main_db_connection().unwrap(),
+        )));
+        for epoch in [1, 2, 3, 4] {
+            repository
+                .record_single_signatures(
+                    &SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+                    &fake_data::single_signatures(vec![1]),
+                    None,
+                )
+                .unwrap();
+        }
+
+        let service = SignerUpkeepService::new(
+            Arc::new(main_db_connection().unwrap()),
+            Arc::new(SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap()),
+            Arc::new(SignedEntityTypeLock::default()),
+            repository.clone(),
+            Some(1),
+            TestLogger::stdout(),
+        );
+        service.run().await.expect("Upkeep service failed");
+
+        let epochs: Vec<Epoch> = repository
+            .get_latest_single_signatures(10)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.epoch)
+            .collect();
+        assert_eq!(vec![Epoch(4), Epoch(3)], epochs);
+    }
 }
@@ -7,7 +7,9 @@ use mithril_common::{
         CertificatePending, Epoch, EpochSettings, SignedEntityConfig, SignedEntityType,
         SignedEntityTypeDiscriminants, Signer, SingleSignatures, TimePoint,
     },
-    messages::AggregatorFeaturesMessage,
+    messages::{
+        AggregatorFeaturesMessage, SigningOpportunityListItemMessage, SigningOpportunityListMessage,
+    },
     test_utils::fake_data,
     MithrilTickerService, TickerService,
 };
@@ -117,6 +119,23 @@ impl AggregatorClient for FakeAggregator {
         Ok(Some(certificate_pending))
     }
 
+    async fn retrieve_signing_opportunities(
+        &self,
+    ) -> Result<Option<SigningOpportunityListMessage>, AggregatorClientError> {
+        let signing_opportunities = self
+            .retrieve_pending_certificate()
+            .await?
+            .into_iter()
+            .map(|certificate_pending| SigningOpportunityListItemMessage {
+                epoch: certificate_pending.epoch,
+                signed_entity_type: certificate_pending.signed_entity_type,
+                ..SigningOpportunityListItemMessage::dummy()
+            })
+            .collect();
+
+        Ok(Some(signing_opportunities))
+    }
+
     /// Registers signer with the aggregator
     async fn register_signer(
         &self,
@@ -34,10 +34,12 @@ use mithril_persistence::{
     store::{StakeStore, StakeStorer},
 };
 use mithril_signer::{
-    metrics::*, AggregatorClient, CardanoTransactionsImporter, Configuration, MetricsService,
+    database::repository::{SignedBeaconRepository, SingleSignatureHistoryRepository},
+    metrics::*,
+    AggregatorClient, CardanoTransactionsImporter, Configuration, MetricsService,
     MithrilSingleSigner, ProductionServiceBuilder, ProtocolInitializerStore,
-    ProtocolInitializerStorer, RuntimeError, SignerRunner, SignerServices, SignerState,
-    SignerUpkeepService, StateMachine,
+    ProtocolInitializerStorer, RuntimeError, SignerKeysWatcher, SignerRunner, SignerServices,
+    SignerState, SignerUpkeepService, StateMachine,
 };
 
 use super::FakeAggregator;
@@ -217,10 +219,15 @@ impl StateMachineTester {
             slog_scope::logger(),
             Arc::new(CardanoTransactionsPreloaderActivation::new(true)),
         ));
+        let single_signature_history_repository = Arc::new(SingleSignatureHistoryRepository::new(
+            sqlite_connection.clone(),
+        ));
         let upkeep_service = Arc::new(SignerUpkeepService::new(
             sqlite_connection.clone(),
             sqlite_connection_cardano_transaction_pool,
             signed_entity_type_lock.clone(),
+            single_signature_history_repository.clone(),
+            None,
             slog_scope::logger(),
         ));
 
@@ -240,6 +247,15 @@ impl StateMachineTester {
             signed_entity_type_lock: Arc::new(SignedEntityTypeLock::default()),
             cardano_transactions_preloader,
             upkeep_service,
+            signed_beacon_store: Arc::new(SignedBeaconRepository::new(
+                sqlite_connection.clone(),
+                None,
+            )),
+            single_signature_history_repository,
+            signer_keys_watcher: Arc::new(SignerKeysWatcher::new(
+                config.kes_secret_key_path.clone(),
+                config.operational_certificate_path.clone(),
+            )),
         };
         // set up stake distribution
         chain_observer
@@ -618,6 +634,12 @@ impl StateMachineTester {
             SIGNER_REGISTRATION_TOTAL_SINCE_STARTUP_METRIC_NAME.to_string(),
             Value::Counter(total_signer_registrations_expected as f64),
         );
+        // The fake chain observer KES period and the operational certificate start KES period
+        // are both 0, so all the KES evolutions of the sample configuration remain.
+        expected_metrics.insert(
+            KES_REMAINING_PERIODS_METRIC_NAME.to_string(),
+            Value::Gauge(62.0),
+        );
         self.assert(
             expected_metrics == metrics,
             format!("Metrics service should export expected metrics: given {metrics:?}, expected {expected_metrics:?}"),